@@ -25,18 +25,32 @@
 
 //! Executor.
 
+use crossbeam::channel::{bounded, Receiver};
+use std::cell::RefCell;
+use std::panic::{self, AssertUnwindSafe, UnwindSafe};
 use std::rc::Rc;
+use std::sync::Arc;
 use std::thread;
 use std::time::Duration;
 
+mod builder;
+mod cancellation;
 mod error;
+mod local;
+mod scope;
 mod signal;
 pub mod strategy;
 pub mod task;
+mod tracker;
 
+pub use builder::Builder;
+pub use cancellation::{CancellationToken, TaskHandle};
 pub use error::{Error, Result};
+pub use local::with_local;
+pub use scope::Scope;
 use strategy::{Strategy, WorkSharing};
 use task::Task;
+use tracker::Tracker;
 
 // ----------------------------------------------------------------------------
 // Structs
@@ -56,10 +70,10 @@ use task::Task;
 ///
 /// Note that executors are not responsible for managing the lifetime of tasks,
 /// as it is assumed that tasks are self-contained and can be run independently.
-/// If a [`Task`] is submitted to an executor, it can't be cancelled or stopped,
-/// as the executor is not aware of the task's internal state. However, callers
-/// can implement fine-grained execution strategies on top of the executor to
-/// gain fine-grained control over task execution.
+/// If a [`Task`] is submitted to an executor, it can't be forcibly stopped, as
+/// the executor is not aware of the task's internal state. However, tasks can
+/// cooperate by polling their [`CancellationToken`], which is cancelled when
+/// calling [`Executor::cancel_all`] or [`TaskHandle::cancel`].
 ///
 /// This is an opinionated implementation that specifically targets the needs of
 /// our execution model. It is not meant to be a general-purpose executor.
@@ -96,12 +110,43 @@ where
 {
     // Execution strategy.
     strategy: Rc<S>,
+    /// Tracker for outstanding tasks.
+    tracker: Arc<Tracker>,
+    /// Cancellation token for submitted tasks.
+    token: Rc<RefCell<CancellationToken>>,
 }
 
 // ----------------------------------------------------------------------------
 // Implementations
 // ----------------------------------------------------------------------------
 
+impl Executor<WorkSharing> {
+    /// Creates an executor builder.
+    ///
+    /// The builder allows to configure the queue capacity and [`Overflow`][]
+    /// policy of an executor using the default work-sharing strategy.
+    ///
+    /// [`Overflow`]: crate::executor::strategy::Overflow
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zrx_executor::strategy::Overflow;
+    /// use zrx_executor::Executor;
+    ///
+    /// // Create executor from builder
+    /// let executor = Executor::builder()
+    ///     .capacity(64)
+    ///     .overflow(Overflow::Block)
+    ///     .build();
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn builder() -> Builder {
+        Builder::new()
+    }
+}
+
 impl<S> Executor<S>
 where
     S: Strategy,
@@ -119,7 +164,11 @@ where
     /// ```
     #[must_use]
     pub fn new(strategy: S) -> Self {
-        Self { strategy: Rc::new(strategy) }
+        Self {
+            strategy: Rc::new(strategy),
+            tracker: Arc::default(),
+            token: Rc::default(),
+        }
     }
 
     /// Submits a task.
@@ -212,7 +261,179 @@ where
     where
         T: Into<Box<dyn Task>>,
     {
-        self.strategy.submit(task.into())
+        let token = self.token.borrow().clone();
+        let task = self.tracker.track(task.into(), Some(token));
+        self.strategy.submit(Box::new(task))
+    }
+
+    /// Submits a task, returning a handle to cancel it.
+    ///
+    /// This method is equivalent to [`Executor::submit`], but associates the
+    /// task with its own [`CancellationToken`], which is also cancelled when
+    /// calling [`Executor::cancel_all`], and returns a [`TaskHandle`] which
+    /// allows to request cancellation of the task and all of its subtasks.
+    ///
+    /// # Errors
+    ///
+    /// If the executor encounters a problem during task submission, it will
+    /// forward the encountered error to the caller, returning the task.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::error::Error;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use std::sync::mpsc;
+    /// use std::thread;
+    /// use std::time::Duration;
+    /// use zrx_executor::{CancellationToken, Executor};
+    ///
+    /// // Create executor and submit long task, polling its token
+    /// let (sender, receiver) = mpsc::channel();
+    /// let executor = Executor::default();
+    /// let handle = executor.submit_with_handle(move || {
+    ///     let token = CancellationToken::current().unwrap();
+    ///     let mut steps = 0;
+    ///     while steps < 1000 && !token.is_cancelled() {
+    ///         thread::sleep(Duration::from_millis(1));
+    ///         steps += 1;
+    ///     }
+    ///     sender.send(steps).unwrap();
+    /// })?;
+    ///
+    /// // Cancel task and ensure it exited before completing its work
+    /// handle.cancel();
+    /// executor.wait();
+    /// assert!(receiver.recv()? < 1000);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn submit_with_handle<T>(&self, task: T) -> Result<TaskHandle>
+    where
+        T: Into<Box<dyn Task>>,
+    {
+        let token = self.token.borrow().child();
+        let handle = TaskHandle::new(token.clone());
+        let task = self.tracker.track(task.into(), Some(token));
+        self.strategy.submit(Box::new(task)).map(|()| handle)
+    }
+
+    /// Submits a task with the given affinity key.
+    ///
+    /// Tasks with the same affinity key are routed to the same worker, which
+    /// is determined by the key modulo the number of workers, e.g., to improve
+    /// cache locality for tasks touching the same keyed state. Note that this
+    /// trades load balancing for locality, so keys should be well distributed.
+    ///
+    /// # Errors
+    ///
+    /// If the executor encounters a problem during task submission, it will
+    /// forward the encountered error to the caller, returning the task.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::error::Error;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use std::collections::HashSet;
+    /// use std::sync::mpsc;
+    /// use std::thread;
+    /// use zrx_executor::strategy::{WorkSharing, WorkStealing};
+    /// use zrx_executor::Executor;
+    ///
+    /// // Create executor and submit tasks with the same affinity key
+    /// let (sender, receiver) = mpsc::channel();
+    /// let executor = Executor::new(WorkSharing::with_capacity(4, 128));
+    /// for _ in 0..16 {
+    ///     let sender = sender.clone();
+    ///     executor.submit_with_affinity(
+    ///         move || sender.send(thread::current().id()).unwrap(),
+    ///         7,
+    ///     )?;
+    /// }
+    /// executor.wait();
+    ///
+    /// // Create executor with different strategy and submit tasks again
+    /// let executor = Executor::new(WorkStealing::new(4));
+    /// for _ in 0..16 {
+    ///     let sender = sender.clone();
+    ///     executor.submit_with_affinity(
+    ///         move || sender.send(thread::current().id()).unwrap(),
+    ///         7,
+    ///     )?;
+    /// }
+    ///
+    /// // Wait for all tasks to finish and ensure each ran on one worker
+    /// executor.wait();
+    /// drop(sender);
+    /// let ids = receiver.iter().collect::<Vec<_>>();
+    /// assert_eq!(ids.len(), 32);
+    /// assert_eq!(ids[..16].iter().collect::<HashSet<_>>().len(), 1);
+    /// assert_eq!(ids[16..].iter().collect::<HashSet<_>>().len(), 1);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn submit_with_affinity<T>(&self, task: T, key: u64) -> Result
+    where
+        T: Into<Box<dyn Task>>,
+    {
+        let token = self.token.borrow().clone();
+        let task = self.tracker.track(task.into(), Some(token));
+        let workers = self.num_workers().max(1) as u64;
+        #[allow(clippy::cast_possible_truncation)]
+        self.strategy
+            .submit_to(Box::new(task), (key % workers) as usize)
+    }
+
+    /// Submits a task that computes an output.
+    ///
+    /// This method wraps the given function in a [`Task`], which sends the
+    /// computed output to the returned receiver once the task has finished.
+    /// If the task panics or is cancelled, the receiver is disconnected, so
+    /// the output can be awaited with [`Receiver::recv`], e.g., to implement
+    /// map-reduce style workloads on top of the executor.
+    ///
+    /// # Errors
+    ///
+    /// If the executor encounters a problem during task submission, it will
+    /// forward the encountered error to the caller, returning the task.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::error::Error;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use zrx_executor::Executor;
+    ///
+    /// // Create executor and submit tasks computing outputs
+    /// let executor = Executor::default();
+    /// let mut receivers = Vec::new();
+    /// for n in 1..=4 {
+    ///     receivers.push(executor.submit_with_output(move || n * n)?);
+    /// }
+    ///
+    /// // Wait for all tasks to finish and sum outputs
+    /// let mut sum = 0;
+    /// for receiver in receivers {
+    ///     sum += receiver.recv()?;
+    /// }
+    /// assert_eq!(sum, 30);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn submit_with_output<F, R>(&self, f: F) -> Result<Receiver<R>>
+    where
+        F: FnOnce() -> R + Send + UnwindSafe + 'static,
+        R: Send + 'static,
+    {
+        let (sender, receiver) = bounded(1);
+        self.submit(move || {
+            // The receiver is owned by the caller, so sending can only fail
+            // when it was dropped, in which case we discard the output
+            let _ = sender.send(f());
+        })?;
+        Ok(receiver)
     }
 
     /// Waits for all tasks to finish.
@@ -255,6 +476,173 @@ where
             thread::sleep(duration);
         }
     }
+
+    /// Waits for all outstanding tasks to finish, acting as a barrier.
+    ///
+    /// This method blocks the current thread until all tasks that have been
+    /// submitted before the call, including all of their subtasks, have been
+    /// completed, and then returns, leaving the executor usable, which is why
+    /// it's a good fit for separating phases of work. In contrast to polling
+    /// with [`Executor::wait`], the thread is woken up as soon as the last task
+    /// finishes. Note that tasks that were rejected on submission are counted
+    /// as outstanding until they're either dropped or executed.
+    ///
+    /// This method must not be called from within a task, as the calling task
+    /// is outstanding itself, so the barrier would never be reached, which
+    /// deadlocks the worker thread.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::error::Error;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use std::sync::atomic::{AtomicUsize, Ordering};
+    /// use std::sync::Arc;
+    /// use std::thread;
+    /// use std::time::Duration;
+    /// use zrx_executor::strategy::WorkStealing;
+    /// use zrx_executor::task::Tasks;
+    /// use zrx_executor::Executor;
+    ///
+    /// // Create executor with strategy
+    /// let executor = Executor::new(WorkStealing::default());
+    /// let counter = Arc::new(AtomicUsize::new(0));
+    ///
+    /// // Create tasks taking 10ms each, which create a subtask
+    /// for _ in 0..16 {
+    ///     let counter = Arc::clone(&counter);
+    ///     executor.submit(move || {
+    ///         thread::sleep(Duration::from_millis(10));
+    ///         counter.fetch_add(1, Ordering::Relaxed);
+    ///         Tasks::from(move || {
+    ///             counter.fetch_add(1, Ordering::Relaxed);
+    ///         })
+    ///     })?;
+    /// }
+    ///
+    /// // Wait for all tasks and subtasks to finish
+    /// executor.barrier();
+    /// assert_eq!(counter.load(Ordering::Relaxed), 32);
+    ///
+    /// // Submit further tasks, as the executor is still usable
+    /// for _ in 0..16 {
+    ///     let counter = Arc::clone(&counter);
+    ///     executor.submit(move || {
+    ///         counter.fetch_add(1, Ordering::Relaxed);
+    ///     })?;
+    /// }
+    ///
+    /// // Wait for all tasks to finish
+    /// executor.barrier();
+    /// assert_eq!(counter.load(Ordering::Relaxed), 48);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn barrier(&self) {
+        self.tracker.wait();
+    }
+
+    /// Requests cancellation of all submitted tasks.
+    ///
+    /// This method cancels the [`CancellationToken`] of all tasks that have
+    /// been submitted before the call, including all of their subtasks, while
+    /// tasks submitted afterwards receive a fresh token, so the executor stays
+    /// usable. Cancellation is cooperative, so only tasks that poll their token
+    /// will stop early, and this method doesn't wait for tasks to finish.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::error::Error;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use std::sync::atomic::{AtomicUsize, Ordering};
+    /// use std::sync::Arc;
+    /// use std::thread;
+    /// use zrx_executor::{CancellationToken, Executor};
+    ///
+    /// // Create executor and submit tasks, waiting for cancellation
+    /// let executor = Executor::default();
+    /// let counter = Arc::new(AtomicUsize::new(0));
+    /// for _ in 0..4 {
+    ///     let counter = Arc::clone(&counter);
+    ///     executor.submit(move || {
+    ///         let token = CancellationToken::current().unwrap();
+    ///         while !token.is_cancelled() {
+    ///             thread::yield_now();
+    ///         }
+    ///         counter.fetch_add(1, Ordering::Relaxed);
+    ///     })?;
+    /// }
+    ///
+    /// // Cancel all tasks and wait for them to finish
+    /// executor.cancel_all();
+    /// executor.barrier();
+    /// assert_eq!(counter.load(Ordering::Relaxed), 4);
+    ///
+    /// // Ensure tasks submitted afterwards aren't cancelled
+    /// executor.submit(|| {
+    ///     assert!(!CancellationToken::current().unwrap().is_cancelled());
+    /// })?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn cancel_all(&self) {
+        self.token.replace(CancellationToken::new()).cancel();
+    }
+
+    /// Creates a scope for tasks borrowing from the enclosing stack frame.
+    ///
+    /// Tasks submitted with [`Executor::submit`] must be `'static`, which often
+    /// forces callers to clone data, or wrap it in an [`Arc`], even though it
+    /// is only needed for a single phase of work. Tasks spawned via [`Scope`]
+    /// may borrow data instead, as this method joins all tasks of the scope,
+    /// including their subtasks, before it returns. If the given function
+    /// panics, the tasks are joined before the panic is propagated.
+    ///
+    /// __Warning__: This method must not be called from within a task running
+    /// on the same executor, as the calling task occupies a worker while it
+    /// blocks until the tasks of the scope have finished. If all workers are
+    /// occupied this way, the tasks of the scope are never executed, which
+    /// deadlocks the worker threads.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::sync::atomic::{AtomicUsize, Ordering};
+    /// use zrx_executor::strategy::WorkStealing;
+    /// use zrx_executor::Executor;
+    ///
+    /// // Create executor and borrowed data
+    /// let executor = Executor::new(WorkStealing::default());
+    /// let values: Vec<usize> = (1..=100).collect();
+    /// let sum = AtomicUsize::new(0);
+    ///
+    /// // Spawn scoped tasks summing chunks of the borrowed slice
+    /// executor.scope(|scope| {
+    ///     for chunk in values.chunks(10) {
+    ///         let sum = &sum;
+    ///         scope.spawn(move || {
+    ///             sum.fetch_add(chunk.iter().sum(), Ordering::Relaxed);
+    ///         });
+    ///     }
+    /// });
+    ///
+    /// // Ensure all tasks have finished
+    /// assert_eq!(sum.load(Ordering::Relaxed), 5050);
+    /// ```
+    pub fn scope<'env, F, R>(&self, f: F) -> R
+    where
+        F: for<'scope> FnOnce(&'scope Scope<'scope, 'env, S>) -> R,
+    {
+        let scope = Scope::new(self);
+
+        // Join all tasks before returning or propagating a panic, since tasks
+        // may borrow data that is owned by the caller's stack frame
+        let result = panic::catch_unwind(AssertUnwindSafe(|| f(&scope)));
+        scope.wait();
+        result.unwrap_or_else(|err| panic::resume_unwind(err))
+    }
 }
 
 #[allow(clippy::must_use_candidate)]
@@ -423,6 +811,8 @@ where
     fn clone(&self) -> Self {
         Self {
             strategy: Rc::clone(&self.strategy),
+            tracker: Arc::clone(&self.tracker),
+            token: Rc::clone(&self.token),
         }
     }
 }
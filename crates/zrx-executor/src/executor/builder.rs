@@ -0,0 +1,171 @@
+// Copyright (c) Zensical LLC <https://zensical.org>
+
+// SPDX-License-Identifier: MIT
+// Third-party contributions licensed under CLA
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+
+// ----------------------------------------------------------------------------
+
+//! Executor builder.
+
+use std::{cmp, thread};
+
+use super::strategy::{Overflow, WorkSharing};
+use super::Executor;
+
+// ----------------------------------------------------------------------------
+// Structs
+// ----------------------------------------------------------------------------
+
+/// Executor builder.
+///
+/// The builder creates an [`Executor`] with a [`WorkSharing`] strategy, and
+/// allows to configure its queue capacity and [`Overflow`] policy, i.e., how
+/// task submission behaves when the executor is at capacity.
+#[derive(Clone, Debug, Default)]
+pub struct Builder {
+    /// Number of workers.
+    num_workers: Option<usize>,
+    /// Queue capacity.
+    capacity: Option<usize>,
+    /// Overflow policy.
+    overflow: Overflow,
+}
+
+// ----------------------------------------------------------------------------
+// Implementations
+// ----------------------------------------------------------------------------
+
+impl Builder {
+    /// Creates an executor builder.
+    ///
+    /// Note that the canonical way to create an [`Executor`] with a custom
+    /// configuration is to invoke the [`Executor::builder`] method.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zrx_executor::Builder;
+    ///
+    /// // Create executor builder
+    /// let builder = Builder::new();
+    /// ```
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the number of workers.
+    ///
+    /// If not set, the number of logical CPUs minus one is used, just like
+    /// for [`WorkSharing::default`], but at least one worker.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zrx_executor::Executor;
+    ///
+    /// // Create executor with number of workers
+    /// let executor = Executor::builder().num_workers(2).build();
+    /// assert_eq!(executor.num_workers(), 2);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn num_workers(mut self, num_workers: usize) -> Self {
+        self.num_workers = Some(num_workers);
+        self
+    }
+
+    /// Sets the queue capacity.
+    ///
+    /// If not set, the capacity is 8 tasks per worker, just like for
+    /// [`WorkSharing::new`]. The capacity is ignored for [`Overflow::Grow`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zrx_executor::Executor;
+    ///
+    /// // Create executor with capacity
+    /// let executor = Executor::builder().capacity(64).build();
+    /// assert_eq!(executor.capacity(), Some(64));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn capacity(mut self, capacity: usize) -> Self {
+        self.capacity = Some(capacity);
+        self
+    }
+
+    /// Sets the overflow policy.
+    ///
+    /// See [`Overflow`] for the available policies and examples of each. The
+    /// default is [`Overflow::Reject`], which is the behavior of executors that
+    /// are created with [`Executor::default`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zrx_executor::strategy::Overflow;
+    /// use zrx_executor::Executor;
+    ///
+    /// // Create executor with overflow policy
+    /// let executor = Executor::builder().overflow(Overflow::Block).build();
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn overflow(mut self, overflow: Overflow) -> Self {
+        self.overflow = overflow;
+        self
+    }
+
+    /// Builds the executor.
+    ///
+    /// # Panics
+    ///
+    /// Panics if thread creation fails.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zrx_executor::Executor;
+    ///
+    /// // Create executor from builder
+    /// let executor = Executor::builder().build();
+    /// ```
+    #[must_use]
+    pub fn build(self) -> Executor<WorkSharing> {
+        let num_workers = self.num_workers.unwrap_or_else(|| {
+            cmp::max(
+                thread::available_parallelism()
+                    .map_or(1, |num| num.get().saturating_sub(1)),
+                1,
+            )
+        });
+
+        // Create strategy with capacity and overflow policy
+        let capacity = self.capacity.unwrap_or(8 * num_workers);
+        Executor::new(WorkSharing::with_overflow(
+            num_workers,
+            capacity,
+            self.overflow,
+        ))
+    }
+}
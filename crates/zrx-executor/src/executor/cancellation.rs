@@ -0,0 +1,278 @@
+// Copyright (c) Zensical LLC <https://zensical.org>
+
+// SPDX-License-Identifier: MIT
+// Third-party contributions licensed under CLA
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+
+// ----------------------------------------------------------------------------
+
+//! Cooperative cancellation.
+
+use std::cell::RefCell;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+// ----------------------------------------------------------------------------
+// Structs
+// ----------------------------------------------------------------------------
+
+/// Cancellation token.
+///
+/// Tasks can't be stopped from the outside, as the executor is not aware of
+/// their internal state, but long-running tasks can cooperate by polling the
+/// token of the task that is currently executed on the worker thread, which is
+/// obtained via [`CancellationToken::current`], and return early once it has
+/// been cancelled. Tokens can be nested, so a child token is also considered
+/// cancelled when its parent is cancelled, but not the other way round.
+///
+/// Cloning is very cheap, since all clones share the same cancellation state.
+///
+/// # Examples
+///
+/// ```
+/// use zrx_executor::CancellationToken;
+///
+/// // Create token and child token
+/// let token = CancellationToken::new();
+/// let child = token.child();
+///
+/// // Cancel token, which also cancels the child token
+/// token.cancel();
+/// assert!(token.is_cancelled());
+/// assert!(child.is_cancelled());
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct CancellationToken {
+    /// Whether the token was cancelled.
+    cancelled: Arc<AtomicBool>,
+    /// Parent token, if any.
+    parent: Option<Box<CancellationToken>>,
+}
+
+/// Task handle.
+///
+/// Handles are returned by [`Executor::submit_with_handle`][], and allow to
+/// request cancellation of a single task, including all of its subtasks.
+///
+/// [`Executor::submit_with_handle`]: crate::executor::Executor::submit_with_handle
+#[derive(Clone, Debug)]
+pub struct TaskHandle {
+    /// Cancellation token of the task.
+    token: CancellationToken,
+}
+
+/// Guard restoring the previous token of the worker thread when dropped.
+pub(crate) struct Guard {
+    /// Previous token.
+    prior: Option<CancellationToken>,
+}
+
+// ----------------------------------------------------------------------------
+// Implementations
+// ----------------------------------------------------------------------------
+
+impl CancellationToken {
+    /// Creates a cancellation token.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zrx_executor::CancellationToken;
+    ///
+    /// // Create token
+    /// let token = CancellationToken::new();
+    /// assert!(!token.is_cancelled());
+    /// ```
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a child token, which is cancelled along with this token.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zrx_executor::CancellationToken;
+    ///
+    /// // Create token and child token
+    /// let token = CancellationToken::new();
+    /// let child = token.child();
+    ///
+    /// // Cancel child token, which doesn't cancel the token
+    /// child.cancel();
+    /// assert!(!token.is_cancelled());
+    /// assert!(child.is_cancelled());
+    /// ```
+    #[must_use]
+    pub fn child(&self) -> Self {
+        Self {
+            cancelled: Arc::default(),
+            parent: Some(Box::new(self.clone())),
+        }
+    }
+
+    /// Returns the token of the task executed on the current thread.
+    ///
+    /// This method returns [`None`] if it's not called from within a task that
+    /// was submitted to an [`Executor`][], e.g., on the main thread.
+    ///
+    /// [`Executor`]: crate::executor::Executor
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::error::Error;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use zrx_executor::{CancellationToken, Executor};
+    ///
+    /// // Create executor and submit task
+    /// let executor = Executor::default();
+    /// executor.submit(|| {
+    ///     assert!(CancellationToken::current().is_some());
+    /// })?;
+    ///
+    /// // Ensure there's no token outside of tasks
+    /// assert!(CancellationToken::current().is_none());
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn current() -> Option<Self> {
+        CURRENT.with_borrow(Clone::clone)
+    }
+
+    /// Cancels the token.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zrx_executor::CancellationToken;
+    ///
+    /// // Create and cancel token
+    /// let token = CancellationToken::new();
+    /// token.cancel();
+    /// assert!(token.is_cancelled());
+    /// ```
+    #[inline]
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Release);
+    }
+
+    /// Returns whether the token or any of its parents was cancelled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zrx_executor::CancellationToken;
+    ///
+    /// // Create token and check cancellation
+    /// let token = CancellationToken::new();
+    /// assert!(!token.is_cancelled());
+    /// ```
+    #[must_use]
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Acquire)
+            || self
+                .parent
+                .as_ref()
+                .is_some_and(|parent| parent.is_cancelled())
+    }
+
+    /// Installs the token as the token of the task executed on this thread.
+    ///
+    /// The previous token is restored when the returned guard is dropped, even
+    /// if the task panics, since tasks might be executed inline, e.g., when
+    /// using the immediate execution strategy.
+    #[must_use]
+    pub(crate) fn enter(&self) -> Guard {
+        Guard {
+            prior: CURRENT.replace(Some(self.clone())),
+        }
+    }
+}
+
+impl TaskHandle {
+    /// Creates a task handle.
+    pub(crate) fn new(token: CancellationToken) -> Self {
+        Self { token }
+    }
+
+    /// Requests cancellation of the task.
+    ///
+    /// Cancellation is cooperative, which means that the task will only stop
+    /// early if it polls its [`CancellationToken`]. Tasks that haven't started
+    /// yet are still executed, but observe the cancellation immediately.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::error::Error;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use zrx_executor::{CancellationToken, Executor};
+    ///
+    /// // Create executor and submit task
+    /// let executor = Executor::default();
+    /// let handle = executor.submit_with_handle(|| {
+    ///     let token = CancellationToken::current().unwrap();
+    ///     while !token.is_cancelled() {
+    ///         std::thread::yield_now();
+    ///     }
+    /// })?;
+    ///
+    /// // Cancel task
+    /// handle.cancel();
+    /// executor.wait();
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn cancel(&self) {
+        self.token.cancel();
+    }
+
+    /// Returns whether cancellation of the task was requested.
+    #[inline]
+    #[must_use]
+    pub fn is_cancelled(&self) -> bool {
+        self.token.is_cancelled()
+    }
+}
+
+// ----------------------------------------------------------------------------
+// Trait implementations
+// ----------------------------------------------------------------------------
+
+impl Drop for Guard {
+    /// Restores the previous token.
+    fn drop(&mut self) {
+        CURRENT.set(self.prior.take());
+    }
+}
+
+// ----------------------------------------------------------------------------
+// Constants
+// ----------------------------------------------------------------------------
+
+thread_local! {
+    /// Token of the task executed on the current thread.
+    static CURRENT: RefCell<Option<CancellationToken>> =
+        const { RefCell::new(None) };
+}
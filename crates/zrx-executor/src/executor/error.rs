@@ -25,7 +25,7 @@
 
 //! Executor error.
 
-use crossbeam::channel::TrySendError;
+use crossbeam::channel::{SendError, TrySendError};
 use std::result;
 use thiserror::Error;
 
@@ -64,6 +64,17 @@ impl From<TrySendError<Box<dyn Task>>> for Error {
     }
 }
 
+impl From<SendError<Box<dyn Task>>> for Error {
+    /// Creates an error from a crossbeam channel error.
+    ///
+    /// Blocking sends can only fail if the channel is disconnected, in which
+    /// case the [`Task`] is wrapped in an [`Error::Submit`] variant as well.
+    #[inline]
+    fn from(err: SendError<Box<dyn Task>>) -> Self {
+        Error::Submit(err.into_inner())
+    }
+}
+
 // ----------------------------------------------------------------------------
 // Type aliases
 // ----------------------------------------------------------------------------
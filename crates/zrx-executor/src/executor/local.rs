@@ -0,0 +1,111 @@
+// Copyright (c) Zensical LLC <https://zensical.org>
+
+// SPDX-License-Identifier: MIT
+// Third-party contributions licensed under CLA
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+
+// ----------------------------------------------------------------------------
+
+//! Worker-local storage.
+
+use std::any::{Any, TypeId};
+use std::cell::RefCell;
+use std::collections::HashMap;
+
+// ----------------------------------------------------------------------------
+// Functions
+// ----------------------------------------------------------------------------
+
+/// Calls the given function with the worker-local buffer of type `B`.
+///
+/// Tasks that need scratch space, e.g., for collecting intermediate results,
+/// would otherwise allocate on every run. This function hands out a buffer
+/// that is created once per worker thread and type, and is reused by all tasks
+/// subsequently executed on the same worker thread, so allocated capacity is
+/// retained. The buffer is not cleared between tasks, which is the caller's
+/// responsibility. Nested calls for the same type obtain a fresh buffer, as
+/// the outer buffer is taken out of the storage until the function returns.
+///
+/// Note that tasks can't access the [`Executor`][] they're running on, which
+/// is why this is a function, and not a method. When called outside of a task,
+/// the buffer is local to the calling thread.
+///
+/// [`Executor`]: crate::executor::Executor
+///
+/// # Examples
+///
+/// ```
+/// # use std::error::Error;
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// use std::sync::mpsc;
+/// use zrx_executor::strategy::WorkSharing;
+/// use zrx_executor::{with_local, Executor};
+///
+/// // Create executor with a single worker
+/// let executor = Executor::new(WorkSharing::new(1));
+/// let (sender, receiver) = mpsc::channel();
+///
+/// // Create tasks recording the address of the worker-local buffer
+/// for _ in 0..2 {
+///     let sender = sender.clone();
+///     executor.submit(move || {
+///         with_local(|buffer: &mut Vec<u8>| {
+///             buffer.clear();
+///             buffer.reserve(1024);
+///             sender.send(buffer.as_ptr() as usize).unwrap();
+///         });
+///     })?;
+/// }
+///
+/// // Ensure both tasks used the same buffer
+/// executor.wait();
+/// let a = receiver.recv()?;
+/// let b = receiver.recv()?;
+/// assert_eq!(a, b);
+/// # Ok(())
+/// # }
+/// ```
+pub fn with_local<B, F, R>(f: F) -> R
+where
+    B: Default + 'static,
+    F: FnOnce(&mut B) -> R,
+{
+    let id = TypeId::of::<B>();
+    let mut buffer = LOCAL
+        .with_borrow_mut(|local| local.remove(&id))
+        .and_then(|buffer| buffer.downcast::<B>().ok())
+        .unwrap_or_default();
+
+    // Call function with buffer and return it to the storage afterwards, so
+    // it can be reused by the next task executed on the same thread
+    let result = f(&mut buffer);
+    LOCAL.with_borrow_mut(|local| local.insert(id, buffer));
+    result
+}
+
+// ----------------------------------------------------------------------------
+// Constants
+// ----------------------------------------------------------------------------
+
+thread_local! {
+    /// Buffers of the current thread, indexed by type.
+    static LOCAL: RefCell<HashMap<TypeId, Box<dyn Any>>> =
+        RefCell::new(HashMap::new());
+}
@@ -0,0 +1,154 @@
+// Copyright (c) Zensical LLC <https://zensical.org>
+
+// SPDX-License-Identifier: MIT
+// Third-party contributions licensed under CLA
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+
+// ----------------------------------------------------------------------------
+
+//! Scope for tasks borrowing from the stack.
+
+use std::marker::PhantomData;
+use std::mem;
+use std::panic::{self, UnwindSafe};
+use std::sync::Arc;
+
+use super::error::Error;
+use super::strategy::Strategy;
+use super::tracker::Tracker;
+use super::Executor;
+
+// ----------------------------------------------------------------------------
+// Structs
+// ----------------------------------------------------------------------------
+
+/// Scope for tasks borrowing from the stack.
+///
+/// Scopes are created with [`Executor::scope`], and allow to spawn tasks that
+/// borrow non-`'static` data from the enclosing stack frame, which is safe,
+/// since the scope waits for all of its tasks to finish before it returns.
+/// Tasks are executed by the execution [`Strategy`] of the [`Executor`], so
+/// they're also taken into account by [`Executor::barrier`].
+///
+/// # Examples
+///
+/// ```
+/// use std::sync::atomic::{AtomicUsize, Ordering};
+/// use zrx_executor::Executor;
+///
+/// // Create executor and spawn scoped tasks incrementing a counter
+/// let executor = Executor::default();
+/// let counter = AtomicUsize::new(0);
+/// executor.scope(|scope| {
+///     for _ in 0..16 {
+///         scope.spawn(|| {
+///             counter.fetch_add(1, Ordering::Relaxed);
+///         });
+///     }
+/// });
+///
+/// // Ensure all tasks have finished
+/// assert_eq!(counter.load(Ordering::Relaxed), 16);
+/// ```
+#[derive(Debug)]
+pub struct Scope<'scope, 'env, S>
+where
+    'env: 'scope,
+    S: Strategy,
+{
+    /// Executor.
+    executor: &'scope Executor<S>,
+    /// Tracker for outstanding tasks of the scope.
+    tracker: Arc<Tracker>,
+    /// Invariance over the lifetimes of the scope and environment.
+    marker: PhantomData<(&'scope mut &'scope (), &'env mut &'env ())>,
+}
+
+// ----------------------------------------------------------------------------
+// Implementations
+// ----------------------------------------------------------------------------
+
+impl<'scope, S> Scope<'scope, '_, S>
+where
+    S: Strategy,
+{
+    /// Creates a scope for the given executor.
+    pub(crate) fn new(executor: &'scope Executor<S>) -> Self {
+        Self {
+            executor,
+            tracker: Arc::default(),
+            marker: PhantomData,
+        }
+    }
+
+    /// Spawns a task borrowing from the enclosing stack frame.
+    ///
+    /// The task is submitted to the [`Executor`], and is joined at the end of
+    /// the scope. If the execution strategy is at capacity, the task is run on
+    /// the current thread, as it can't be handed back to the caller, because
+    /// it might outlive the data it borrows. Panics are caught in both cases,
+    /// just like for tasks that are run by worker threads.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::sync::Mutex;
+    /// use zrx_executor::Executor;
+    ///
+    /// // Create executor and spawn scoped tasks collecting values
+    /// let executor = Executor::default();
+    /// let values = Mutex::new(Vec::new());
+    /// executor.scope(|scope| {
+    ///     for n in 0..4 {
+    ///         let values = &values;
+    ///         scope.spawn(move || values.lock().unwrap().push(n));
+    ///     }
+    /// });
+    ///
+    /// // Ensure all values were collected
+    /// let mut values = values.into_inner().unwrap();
+    /// values.sort_unstable();
+    /// assert_eq!(values, [0, 1, 2, 3]);
+    /// ```
+    pub fn spawn<F>(&self, f: F)
+    where
+        F: FnOnce() + Send + UnwindSafe + 'scope,
+    {
+        let task: Box<dyn FnOnce() + Send + UnwindSafe + 'scope> = Box::new(f);
+
+        // SAFETY: The scope waits for all of its tracked tasks to be dropped
+        // before it returns, so the task can't outlive any borrowed data, and
+        // the task is never handed out, even when its submission fails
+        let task: Box<dyn FnOnce() + Send + UnwindSafe + 'static> =
+            unsafe { mem::transmute(task) };
+
+        // Track task within the scope, and run it on the current thread if the
+        // execution strategy rejected it, which only happens at capacity
+        let task = self.tracker.track(Box::new(task), None);
+        if let Err(Error::Submit(task)) = self.executor.submit(task) {
+            let _ = panic::catch_unwind(|| task.execute().execute());
+        }
+    }
+
+    /// Waits for all tasks of the scope to finish.
+    pub(crate) fn wait(&self) {
+        self.tracker.wait();
+    }
+}
@@ -31,10 +31,14 @@ use super::error::Result;
 use super::signal::Signal;
 use super::task::Task;
 
+mod deterministic;
 mod immediate;
+mod overflow;
 mod worker;
 
+pub use deterministic::Deterministic;
 pub use immediate::Immediate;
+pub use overflow::Overflow;
 pub use worker::{WorkSharing, WorkStealing};
 
 // ----------------------------------------------------------------------------
@@ -63,6 +67,23 @@ pub trait Strategy: Debug {
     /// to submit the given task, but not within the task itself.
     fn submit(&self, task: Box<dyn Task>) -> Result;
 
+    /// Submits a task to the worker with the given index.
+    ///
+    /// This method allows to pin related tasks to the same worker thread, e.g.,
+    /// for cache locality. The index is wrapped around the number of workers.
+    /// The default implementation ignores the index and submits the task with
+    /// [`Strategy::submit`], which is suitable for strategies that don't have
+    /// multiple workers, like [`Immediate`].
+    ///
+    /// # Errors
+    ///
+    /// This method should return an error when a problem is encountered trying
+    /// to submit the given task, but not within the task itself.
+    fn submit_to(&self, task: Box<dyn Task>, worker: usize) -> Result {
+        let _ = worker;
+        self.submit(task)
+    }
+
     /// Returns the number of workers.
     fn num_workers(&self) -> usize;
 
@@ -0,0 +1,290 @@
+// Copyright (c) Zensical LLC <https://zensical.org>
+
+// SPDX-License-Identifier: MIT
+// Third-party contributions licensed under CLA
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+
+// ----------------------------------------------------------------------------
+
+//! Deterministic execution strategy.
+
+use std::cell::{Cell, RefCell};
+use std::collections::VecDeque;
+use std::fmt;
+
+use crate::executor::strategy::Strategy;
+use crate::executor::task::Task;
+use crate::executor::Result;
+
+// ----------------------------------------------------------------------------
+// Structs
+// ----------------------------------------------------------------------------
+
+/// Deterministic execution strategy.
+///
+/// This strategy executes tasks on the current thread in strict submission
+/// order, and is primarily intended for reproducible tests, e.g., integration
+/// tests of the scheduler, where even a single worker thread might interleave
+/// submission and execution differently between runs. Tasks are appended to a
+/// queue, which is drained on submission, and subtasks are appended to the
+/// queue when their parent task finishes, so they are executed in the order
+/// in which they were created, i.e., in breadth-first order. This is unlike
+/// the [`Immediate`][] strategy, which executes subtasks in depth-first order,
+/// and executes tasks submitted from within another task right away.
+///
+/// [`Immediate`]: crate::executor::strategy::Immediate
+///
+/// # Examples
+///
+/// ```
+/// # use std::error::Error;
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// use zrx_executor::strategy::{Deterministic, Strategy};
+///
+/// // Create strategy and submit task
+/// let strategy = Deterministic::default();
+/// strategy.submit(Box::new(|| println!("Task")))?;
+/// # Ok(())
+/// # }
+/// ```
+pub struct Deterministic {
+    /// Queue of pending tasks.
+    queue: RefCell<VecDeque<Box<dyn Task>>>,
+    /// Whether a task is running.
+    running: Cell<bool>,
+}
+
+/// Guard resetting the running state when dropped.
+struct Guard<'a> {
+    /// Running state.
+    running: &'a Cell<bool>,
+}
+
+// ----------------------------------------------------------------------------
+// Implementations
+// ----------------------------------------------------------------------------
+
+impl Deterministic {
+    /// Creates a deterministic execution strategy.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zrx_executor::strategy::Deterministic;
+    ///
+    /// // Create strategy
+    /// let strategy = Deterministic::new();
+    /// ```
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            queue: RefCell::default(),
+            running: Cell::new(false),
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------
+// Trait implementations
+// ----------------------------------------------------------------------------
+
+impl Strategy for Deterministic {
+    /// Submits a task.
+    ///
+    /// This method appends the given [`Task`] to the queue, and then executes
+    /// all queued tasks in order, including the subtasks they create, unless
+    /// a task is already running, e.g., when a task submits further tasks. In
+    /// this case, the task is executed after all previously queued tasks.
+    ///
+    /// # Errors
+    ///
+    /// This method is infallible, and will always return [`Ok`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::error::Error;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use std::sync::{Arc, Mutex};
+    /// use zrx_executor::strategy::Deterministic;
+    /// use zrx_executor::task::Tasks;
+    /// use zrx_executor::Executor;
+    ///
+    /// // Create executor with strategy
+    /// let executor = Executor::new(Deterministic::default());
+    /// let order = Arc::new(Mutex::new(Vec::new()));
+    ///
+    /// // Create tasks recording their execution order
+    /// for n in 0..3 {
+    ///     let order = Arc::clone(&order);
+    ///     executor.submit(move || {
+    ///         order.lock().unwrap().push(n);
+    ///         let mut tasks = Tasks::new();
+    ///         for m in 0..2 {
+    ///             let order = Arc::clone(&order);
+    ///             tasks.add(move || {
+    ///                 order.lock().unwrap().push(10 * (n + 1) + m);
+    ///                 let order = Arc::clone(&order);
+    ///                 move || order.lock().unwrap().push(100 * (n + 1) + m)
+    ///             });
+    ///         }
+    ///         tasks
+    ///     })?;
+    /// }
+    ///
+    /// // Ensure tasks were executed in submission order
+    /// executor.wait();
+    /// assert_eq!(*order.lock().unwrap(), [
+    ///     0, 10, 11, 100, 101,
+    ///     1, 20, 21, 200, 201,
+    ///     2, 30, 31, 300, 301,
+    /// ]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn submit(&self, task: Box<dyn Task>) -> Result {
+        self.queue.borrow_mut().push_back(task);
+        if self.running.replace(true) {
+            return Ok(());
+        }
+
+        // Drain the queue, appending subtasks, so they're executed after all
+        // tasks that were queued before them - the borrow of the queue must
+        // be released before executing a task, as it might submit tasks
+        let guard = Guard { running: &self.running };
+        loop {
+            let Some(task) = self.queue.borrow_mut().pop_front() else {
+                break;
+            };
+            let subtasks = task.execute();
+            self.queue.borrow_mut().extend(subtasks);
+        }
+
+        // No errors occurred
+        drop(guard);
+        Ok(())
+    }
+
+    /// Returns the number of workers.
+    ///
+    /// The number of usable workers is always `1` for this strategy, as tasks
+    /// are executed on the thread that submits them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zrx_executor::strategy::{Deterministic, Strategy};
+    ///
+    /// // Get number of workers
+    /// let strategy = Deterministic::new();
+    /// assert_eq!(strategy.num_workers(), 1);
+    /// ```
+    #[inline]
+    fn num_workers(&self) -> usize {
+        1
+    }
+
+    /// Returns the number of running tasks.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zrx_executor::strategy::{Deterministic, Strategy};
+    ///
+    /// // Get number of running tasks
+    /// let strategy = Deterministic::default();
+    /// assert_eq!(strategy.num_tasks_running(), 0);
+    /// ```
+    #[inline]
+    fn num_tasks_running(&self) -> usize {
+        usize::from(self.running.get())
+    }
+
+    /// Returns the number of pending tasks.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zrx_executor::strategy::{Deterministic, Strategy};
+    ///
+    /// // Get number of pending tasks
+    /// let strategy = Deterministic::default();
+    /// assert_eq!(strategy.num_tasks_pending(), 0);
+    /// ```
+    #[inline]
+    fn num_tasks_pending(&self) -> usize {
+        self.queue.borrow().len()
+    }
+
+    /// Returns the capacity, if bounded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zrx_executor::strategy::{Deterministic, Strategy};
+    ///
+    /// // Get capacity
+    /// let strategy = Deterministic::default();
+    /// assert_eq!(strategy.capacity(), None);
+    /// ```
+    #[inline]
+    fn capacity(&self) -> Option<usize> {
+        None
+    }
+}
+
+impl Drop for Guard<'_> {
+    /// Resets the running state, even if a task panics.
+    fn drop(&mut self) {
+        self.running.set(false);
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+impl Default for Deterministic {
+    /// Creates a deterministic execution strategy.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zrx_executor::strategy::Deterministic;
+    ///
+    /// // Create strategy
+    /// let strategy = Deterministic::default();
+    /// ```
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+impl fmt::Debug for Deterministic {
+    /// Formats the execution strategy for debugging.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Deterministic")
+            .field("workers", &self.num_workers())
+            .field("running", &self.num_tasks_running())
+            .field("pending", &self.num_tasks_pending())
+            .finish()
+    }
+}
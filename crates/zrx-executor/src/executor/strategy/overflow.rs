@@ -0,0 +1,159 @@
+// Copyright (c) Zensical LLC <https://zensical.org>
+
+// SPDX-License-Identifier: MIT
+// Third-party contributions licensed under CLA
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+
+// ----------------------------------------------------------------------------
+
+//! Overflow policy.
+
+// ----------------------------------------------------------------------------
+// Enums
+// ----------------------------------------------------------------------------
+
+/// Overflow policy.
+///
+/// The overflow policy determines how a bounded execution strategy behaves if
+/// a task is submitted while the strategy is at capacity, which centralizes
+/// the backpressure tradeoffs of task submission in a single setting.
+///
+/// # Examples
+///
+/// Reject tasks at capacity:
+///
+/// ```
+/// # use std::error::Error;
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// use std::sync::mpsc;
+/// use zrx_executor::strategy::Overflow;
+/// use zrx_executor::{Error as ExecutorError, Executor};
+///
+/// // Create executor with a single worker and capacity of one task
+/// let executor = Executor::builder()
+///     .num_workers(1)
+///     .capacity(1)
+///     .overflow(Overflow::Reject)
+///     .build();
+///
+/// // Occupy worker with a task until released, and fill the queue
+/// let (started, receiver) = mpsc::channel();
+/// let (release, gate) = mpsc::channel::<()>();
+/// executor.submit(move || {
+///     started.send(()).unwrap();
+///     gate.recv().unwrap();
+/// })?;
+/// receiver.recv()?;
+/// executor.submit(|| {})?;
+///
+/// // Ensure further tasks are rejected
+/// let res = executor.submit(|| {});
+/// assert!(matches!(res, Err(ExecutorError::Submit(_))));
+/// release.send(())?;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// Block until capacity is available:
+///
+/// ```
+/// # use std::error::Error;
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// use std::sync::mpsc;
+/// use std::thread;
+/// use std::time::Duration;
+/// use zrx_executor::strategy::Overflow;
+/// use zrx_executor::Executor;
+///
+/// // Create executor with a single worker and capacity of one task
+/// let executor = Executor::builder()
+///     .num_workers(1)
+///     .capacity(1)
+///     .overflow(Overflow::Block)
+///     .build();
+///
+/// // Occupy worker with a task until released, and fill the queue
+/// let (started, receiver) = mpsc::channel();
+/// let (release, gate) = mpsc::channel::<()>();
+/// executor.submit(move || {
+///     started.send(()).unwrap();
+///     gate.recv().unwrap();
+/// })?;
+/// receiver.recv()?;
+/// executor.submit(|| {})?;
+///
+/// // Release worker from another thread, and ensure submission succeeds
+/// thread::spawn(move || {
+///     thread::sleep(Duration::from_millis(20));
+///     release.send(()).unwrap();
+/// });
+/// executor.submit(|| {})?;
+/// executor.barrier();
+/// # Ok(())
+/// # }
+/// ```
+///
+/// Grow the queue at capacity:
+///
+/// ```
+/// # use std::error::Error;
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// use std::sync::mpsc;
+/// use zrx_executor::strategy::Overflow;
+/// use zrx_executor::Executor;
+///
+/// // Create executor with a single worker and capacity of one task
+/// let executor = Executor::builder()
+///     .num_workers(1)
+///     .capacity(1)
+///     .overflow(Overflow::Grow)
+///     .build();
+/// assert_eq!(executor.capacity(), None);
+///
+/// // Occupy worker with a task until released
+/// let (started, receiver) = mpsc::channel();
+/// let (release, gate) = mpsc::channel::<()>();
+/// executor.submit(move || {
+///     started.send(()).unwrap();
+///     gate.recv().unwrap();
+/// })?;
+/// receiver.recv()?;
+///
+/// // Ensure further tasks are accepted
+/// for _ in 0..16 {
+///     executor.submit(|| {})?;
+/// }
+/// assert_eq!(executor.num_tasks_pending(), 16);
+/// release.send(())?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Overflow {
+    /// Block the submitting thread until capacity is available.
+    Block,
+    /// Reject the task with [`Error::Submit`][], returning it to the caller.
+    ///
+    /// [`Error::Submit`]: crate::executor::Error::Submit
+    #[default]
+    Reject,
+    /// Grow the queue, i.e., switch to an unbounded queue.
+    Grow,
+}
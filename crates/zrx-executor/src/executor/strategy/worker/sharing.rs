@@ -25,13 +25,13 @@
 
 //! Work-sharing execution strategy.
 
-use crossbeam::channel::{bounded, Sender};
+use crossbeam::channel::{bounded, select, unbounded, Receiver, Sender};
 use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::Arc;
 use std::thread::{self, Builder, JoinHandle};
 use std::{cmp, fmt, panic};
 
-use crate::executor::strategy::Strategy;
+use crate::executor::strategy::{Overflow, Strategy};
 use crate::executor::task::Task;
 use crate::executor::Result;
 
@@ -73,10 +73,14 @@ use crate::executor::Result;
 pub struct WorkSharing {
     /// Task submission sender.
     sender: Option<Sender<Box<dyn Task>>>,
+    /// Task submission senders for each worker.
+    senders: Vec<Sender<Box<dyn Task>>>,
     /// Join handles of worker threads.
     threads: Vec<JoinHandle<()>>,
     /// Counter for running tasks.
     running: Arc<AtomicUsize>,
+    /// Overflow policy.
+    overflow: Overflow,
 }
 
 // ----------------------------------------------------------------------------
@@ -120,7 +124,12 @@ impl WorkSharing {
     /// submission, ensuring that the executor can accept new tasks. The given
     /// capacity sets the number of tasks the executor accepts before starting
     /// to reject them, which can be used to apply backpressure. Note that the
-    /// capacity is not a per-worker, but a global per-executor limit.
+    /// capacity is not a per-worker, but a global per-executor limit, which is
+    /// split between the shared channel and the channels of the workers, that
+    /// receive tasks submitted to specific workers. As each channel can hold
+    /// at least one task, [`Strategy::capacity`] reports the effective limit,
+    /// which only exceeds the given capacity if it's smaller than the number
+    /// of channels.
     ///
     /// # Panics
     ///
@@ -136,22 +145,65 @@ impl WorkSharing {
     /// ```
     #[must_use]
     pub fn with_capacity(num_workers: usize, capacity: usize) -> Self {
-        let (sender, receiver) = bounded::<Box<dyn Task>>(capacity);
+        Self::with_overflow(num_workers, capacity, Overflow::Reject)
+    }
+
+    /// Creates a work-sharing execution strategy with the given capacity and
+    /// overflow policy.
+    ///
+    /// The overflow policy determines what happens if a task is submitted at
+    /// capacity: [`Overflow::Reject`] returns the task to the caller, which is
+    /// what [`WorkSharing::with_capacity`] does, [`Overflow::Block`] blocks the
+    /// submitting thread until a worker picks up a task, and [`Overflow::Grow`]
+    /// switches to unbounded channels, ignoring the given capacity.
+    ///
+    /// # Panics
+    ///
+    /// Panics if thread creation fails.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zrx_executor::strategy::{Overflow, Strategy, WorkSharing};
+    ///
+    /// // Create strategy with capacity and overflow policy
+    /// let strategy = WorkSharing::with_overflow(4, 64, Overflow::Block);
+    /// assert_eq!(strategy.capacity(), Some(64));
+    /// ```
+    #[must_use]
+    pub fn with_overflow(
+        num_workers: usize, capacity: usize, overflow: Overflow,
+    ) -> Self {
+        let channel = |capacity| match overflow {
+            Overflow::Grow => unbounded::<Box<dyn Task>>(),
+            _ => bounded::<Box<dyn Task>>(capacity),
+        };
+
+        // Split capacity between the shared channel and the channels of each
+        // worker, so the capacity is a global limit, with the shared channel
+        // receiving the remainder, as most tasks are submitted through it
+        let local = cmp::max(capacity / (num_workers + 1), 1);
+        let shared = cmp::max(capacity.saturating_sub(local * num_workers), 1);
+        let (sender, receiver) = channel(shared);
 
         // Keep track of running tasks
         let running = Arc::new(AtomicUsize::new(0));
 
+        // Create a channel for each worker, so tasks can be pinned to workers
+        let (senders, receivers): (Vec<_>, Vec<_>) =
+            (0..num_workers).map(|_| channel(local)).unzip();
+
         // Initialize worker threads
-        let iter = (0..num_workers).map(|index| {
+        let iter = receivers.into_iter().enumerate().map(|(index, local)| {
             let receiver = receiver.clone();
 
-            // Create worker thread and poll the receiver until the sender is
+            // Create worker thread and poll the receivers until the senders are
             // dropped, automatically exiting the loop. Additionally, we keep
             // track of the number of running tasks to provide a simple way to
             // monitor the load of the thread pool.
             let running = Arc::clone(&running);
             let h = move || {
-                while let Ok(task) = receiver.recv() {
+                while let Some(task) = recv(&local, &receiver) {
                     running.fetch_add(1, Ordering::Release);
 
                     // Execute task and immediately execute all subtasks on the
@@ -188,8 +240,10 @@ impl WorkSharing {
         let threads = iter.collect();
         Self {
             sender: Some(sender),
+            senders,
             threads,
             running,
+            overflow,
         }
     }
 }
@@ -236,11 +290,42 @@ impl Strategy for WorkSharing {
     /// ```
     fn submit(&self, task: Box<dyn Task>) -> Result {
         match self.sender.as_ref() {
-            Some(sender) => Ok(sender.try_send(task)?),
+            Some(sender) => send(sender, task, self.overflow),
             None => unreachable!(),
         }
     }
 
+    /// Submits a task to the worker with the given index.
+    ///
+    /// Each worker has its own channel in addition to the shared channel, so
+    /// tasks submitted with the same index are always executed by the same
+    /// worker thread. The index is wrapped around the number of workers.
+    ///
+    /// # Errors
+    ///
+    /// If the task cannot be submitted, [`Error::Submit`][] is returned, which
+    /// can only happen if the channel is disconnected or at capacity.
+    ///
+    /// [`Error::Submit`]: crate::executor::Error::Submit
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::error::Error;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use zrx_executor::strategy::{Strategy, WorkSharing};
+    ///
+    /// // Create strategy and submit task to first worker
+    /// let strategy = WorkSharing::default();
+    /// strategy.submit_to(Box::new(|| println!("Task")), 0)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn submit_to(&self, task: Box<dyn Task>, worker: usize) -> Result {
+        let sender = &self.senders[worker % self.senders.len()];
+        send(sender, task, self.overflow)
+    }
+
     /// Returns the number of workers.
     ///
     /// # Examples
@@ -292,26 +377,57 @@ impl Strategy for WorkSharing {
     /// ```
     #[inline]
     fn num_tasks_pending(&self) -> usize {
-        self.sender.as_ref().map_or(0, Sender::len)
+        let iter = self.senders.iter().map(Sender::len);
+        self.sender.as_ref().map_or(0, Sender::len) + iter.sum::<usize>()
     }
 
     /// Returns the capacity, if bounded.
     ///
     /// This method returns the maximum number of tasks that can be submitted
     /// at once, which can be used by the strategy for applying backpressure.
+    /// The capacity is the sum of the capacities of the shared channel and the
+    /// channels of all workers, so it matches [`Strategy::num_tasks_pending`]
+    /// when the strategy is full.
     ///
     /// # Examples
     ///
     /// ```
+    /// # use std::error::Error;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use std::sync::mpsc;
+    /// use std::thread;
     /// use zrx_executor::strategy::{Strategy, WorkSharing};
     ///
-    /// // Get capacity
-    /// let strategy = WorkSharing::default();
-    /// assert!(strategy.capacity() >= Some(strategy.num_workers()));
+    /// // Create strategy and block its only worker
+    /// let strategy = WorkSharing::with_capacity(1, 4);
+    /// let (sender, receiver) = mpsc::channel::<()>();
+    /// strategy.submit(Box::new(move || receiver.recv().unwrap()))?;
+    /// while strategy.num_tasks_running() == 0 {
+    ///     thread::yield_now();
+    /// }
+    ///
+    /// // Fill shared channel and channel of worker
+    /// let mut count = 0;
+    /// while strategy.submit(Box::new(|| {})).is_ok() {
+    ///     count += 1;
+    /// }
+    /// while strategy.submit_to(Box::new(|| {}), 0).is_ok() {
+    ///     count += 1;
+    /// }
+    ///
+    /// // Ensure strategy is filled to exactly its capacity
+    /// assert_eq!(strategy.capacity(), Some(4));
+    /// assert_eq!(strategy.capacity(), Some(count));
+    /// assert_eq!(strategy.num_tasks_pending(), count);
+    /// sender.send(())?;
+    /// # Ok(())
+    /// # }
     /// ```
     #[inline]
     fn capacity(&self) -> Option<usize> {
-        self.sender.as_ref().and_then(Sender::capacity)
+        let capacity = self.sender.as_ref().and_then(Sender::capacity)?;
+        let iter = self.senders.iter().filter_map(Sender::capacity);
+        Some(capacity + iter.sum::<usize>())
     }
 }
 
@@ -363,6 +479,7 @@ impl Drop for WorkSharing {
         if let Some(sender) = self.sender.take() {
             drop(sender);
         }
+        self.senders.clear();
 
         // Join all worker threads without panicking on errors
         for handle in self.threads.drain(..) {
@@ -383,3 +500,31 @@ impl fmt::Debug for WorkSharing {
             .finish()
     }
 }
+
+// ----------------------------------------------------------------------------
+// Functions
+// ----------------------------------------------------------------------------
+
+/// Sends a task, blocking at capacity if the overflow policy demands it.
+fn send(
+    sender: &Sender<Box<dyn Task>>, task: Box<dyn Task>, overflow: Overflow,
+) -> Result {
+    if overflow == Overflow::Block {
+        Ok(sender.send(task)?)
+    } else {
+        Ok(sender.try_send(task)?)
+    }
+}
+
+/// Receives the next task from the worker's own or the shared receiver, and
+/// drains both receivers once they are disconnected, before returning [`None`].
+fn recv<T>(local: &Receiver<T>, shared: &Receiver<T>) -> Option<T> {
+    let res = select! {
+        recv(local) -> res => res,
+        recv(shared) -> res => res,
+    };
+    res.ok()
+        .or_else(|| local.try_recv().ok())
+        .or_else(|| shared.recv().ok())
+        .or_else(|| local.recv().ok())
+}
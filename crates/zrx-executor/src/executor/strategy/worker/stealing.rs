@@ -82,6 +82,8 @@ use crate::executor::Result;
 pub struct WorkStealing {
     /// Injector for task submission.
     injector: Arc<Injector<Box<dyn Task>>>,
+    /// Injectors for task submission to each worker.
+    injectors: Arc<[Injector<Box<dyn Task>>]>,
     /// Signal for synchronization.
     signal: Arc<Signal>,
     /// Join handles of worker threads.
@@ -123,6 +125,11 @@ impl WorkStealing {
         let injector = Arc::new(Injector::new());
         let signal = Arc::new(Signal::new());
 
+        // Create an injector for each worker, which other workers never steal
+        // from, so tasks can be pinned to a specific worker
+        let injectors: Arc<[Injector<Box<dyn Task>>]> =
+            repeat_with(Injector::new).take(num_workers).collect();
+
         // Create worker queues
         let mut workers = Vec::with_capacity(num_workers);
         for _ in 0..num_workers {
@@ -143,6 +150,7 @@ impl WorkStealing {
         // Initialize worker threads
         let iter = workers.into_iter().enumerate().map(|(index, worker)| {
             let injector = Arc::clone(&injector);
+            let injectors = Arc::clone(&injectors);
             let stealers = Arc::clone(&stealers);
             let signal = Arc::clone(&signal);
 
@@ -152,6 +160,7 @@ impl WorkStealing {
             let pending = Arc::clone(&pending);
             let h = move || {
                 let injector = injector.as_ref();
+                let local = &injectors[index];
                 let stealers = stealers.as_ref();
 
                 // Try to fetch the next task, either from the local queue, or
@@ -159,7 +168,8 @@ impl WorkStealing {
                 // track of the number of running tasks to provide a simple way
                 // to monitor the load of the thread pool.
                 loop {
-                    let Some(task) = get(&worker, injector, stealers) else {
+                    let Some(task) = get(&worker, local, injector, stealers)
+                    else {
                         // No more tasks, so we wait for the executor to signal
                         // if the worker should continue or terminate. This can
                         // fail due to a poisoned lock, in which case we need
@@ -217,6 +227,7 @@ impl WorkStealing {
         let threads = iter.collect();
         Self {
             injector,
+            injectors,
             signal,
             threads,
             running,
@@ -275,6 +286,39 @@ impl Strategy for WorkStealing {
         Ok(())
     }
 
+    /// Submits a task to the worker with the given index.
+    ///
+    /// Each worker has its own injector in addition to the shared injector,
+    /// which other workers never steal from, so tasks submitted with the same
+    /// index are always executed by the same worker thread. The index is
+    /// wrapped around the number of workers.
+    ///
+    /// # Errors
+    ///
+    /// This method is infallible, and will always return [`Ok`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::error::Error;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use zrx_executor::strategy::{Strategy, WorkStealing};
+    ///
+    /// // Create strategy and submit task to first worker
+    /// let strategy = WorkStealing::default();
+    /// strategy.submit_to(Box::new(|| println!("Task")), 0)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn submit_to(&self, task: Box<dyn Task>, worker: usize) -> Result {
+        self.injectors[worker % self.injectors.len()].push(task);
+        self.pending.fetch_add(1, Ordering::Release);
+        self.signal.notify();
+
+        // No errors occurred
+        Ok(())
+    }
+
     /// Returns the number of workers.
     ///
     /// # Examples
@@ -422,14 +466,21 @@ impl fmt::Debug for WorkStealing {
 // ----------------------------------------------------------------------------
 
 /// Attempts to get the next available task, either from the worker's own queue
-/// or by stealing from the injector or other stealers if needed. Note that this
-/// code was taken almost verbatim from the [`crossbeam`] docs, specifically
-/// from [`crossbeam::deque`](crossbeam::deque#examples), but cut smaller.
+/// or injector, or by stealing from the injector or other stealers if needed.
+/// Note that this code was taken almost verbatim from the [`crossbeam`] docs,
+/// specifically from [`crossbeam::deque`](crossbeam::deque#examples), but cut
+/// smaller, and extended with the worker's own injector for pinned tasks.
 fn get<T>(
-    worker: &Worker<T>, injector: &Injector<T>, stealers: &[Stealer<T>],
+    worker: &Worker<T>, local: &Injector<T>, injector: &Injector<T>,
+    stealers: &[Stealer<T>],
 ) -> Option<T> {
     worker
         .pop()
+        .or_else(|| {
+            repeat_with(|| local.steal())
+                .find(|steal| !steal.is_retry())
+                .and_then(Steal::success)
+        })
         .or_else(|| steal_or_retry(worker, injector, stealers))
 }
 
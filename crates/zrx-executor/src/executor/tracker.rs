@@ -0,0 +1,146 @@
+// Copyright (c) Zensical LLC <https://zensical.org>
+
+// SPDX-License-Identifier: MIT
+// Third-party contributions licensed under CLA
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+
+// ----------------------------------------------------------------------------
+
+//! Tracker for outstanding tasks.
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Condvar, Mutex, PoisonError};
+
+use super::cancellation::CancellationToken;
+use super::task::{Task, Tasks};
+
+// ----------------------------------------------------------------------------
+// Structs
+// ----------------------------------------------------------------------------
+
+/// Tracker for outstanding tasks.
+///
+/// The tracker counts all tasks that were handed to the executor, but haven't
+/// finished yet, including all subtasks they create. This is necessary, since
+/// strategies pop tasks from their queues before marking them as running, so
+/// there's a short window in which a task is neither pending nor running.
+#[derive(Debug, Default)]
+pub struct Tracker {
+    /// Number of outstanding tasks.
+    count: AtomicUsize,
+    /// Mutex for condition variable.
+    mutex: Mutex<()>,
+    /// Condition variable for reaching zero.
+    condvar: Condvar,
+}
+
+/// Tracked task.
+///
+/// The task is counted as outstanding from its creation until it's dropped,
+/// which happens either after it was executed, or when it's discarded. If the
+/// task is associated with a token, the token is installed as the current token
+/// of the worker thread while the task is executed, and is handed down to all
+/// of its subtasks. Both concerns share a single wrapper, so submitting a task
+/// only incurs a single additional allocation.
+pub struct Tracked {
+    /// Underlying task.
+    task: Box<dyn Task>,
+    /// Cancellation token, if any.
+    token: Option<CancellationToken>,
+    /// Guard marking the task as finished when dropped.
+    guard: Guard,
+}
+
+/// Guard marking a task as finished when dropped.
+struct Guard {
+    /// Tracker.
+    tracker: Arc<Tracker>,
+}
+
+// ----------------------------------------------------------------------------
+// Implementations
+// ----------------------------------------------------------------------------
+
+impl Tracker {
+    /// Wraps the given task, counting it as outstanding until it's dropped.
+    pub fn track(
+        self: &Arc<Self>, task: Box<dyn Task>, token: Option<CancellationToken>,
+    ) -> Tracked {
+        self.count.fetch_add(1, Ordering::AcqRel);
+        Tracked {
+            task,
+            token,
+            guard: Guard { tracker: Arc::clone(self) },
+        }
+    }
+
+    /// Blocks the current thread until no tasks are outstanding.
+    pub fn wait(&self) {
+        let mut guard =
+            self.mutex.lock().unwrap_or_else(PoisonError::into_inner);
+        while self.count.load(Ordering::Acquire) > 0 {
+            guard = self
+                .condvar
+                .wait(guard)
+                .unwrap_or_else(PoisonError::into_inner);
+        }
+    }
+
+    /// Marks a task as finished, waking waiting threads if none are left.
+    fn finish(&self) {
+        if self.count.fetch_sub(1, Ordering::AcqRel) == 1 {
+            // Acquire the lock before notifying, so a thread that observed a
+            // non-zero count is guaranteed to be waiting when we notify it
+            drop(self.mutex.lock().unwrap_or_else(PoisonError::into_inner));
+            self.condvar.notify_all();
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------
+// Trait implementations
+// ----------------------------------------------------------------------------
+
+impl Task for Tracked {
+    /// Executes the task, tracking all of its subtasks.
+    ///
+    /// Subtasks are wrapped before the guard of the task itself is dropped, so
+    /// the number of outstanding tasks can't reach zero while subtasks exist.
+    /// If the task panics, the guard is dropped during unwinding.
+    fn execute(self: Box<Self>) -> Tasks {
+        let Tracked { task, token, guard } = *self;
+        let current = token.as_ref().map(CancellationToken::enter);
+
+        // Execute task and wrap subtasks, handing down the token
+        let mut tasks = Tasks::new();
+        for subtask in task.execute() {
+            tasks.add(guard.tracker.track(subtask, token.clone()));
+        }
+        drop(current);
+        tasks
+    }
+}
+
+impl Drop for Guard {
+    /// Marks the task as finished.
+    fn drop(&mut self) {
+        self.tracker.finish();
+    }
+}
@@ -29,4 +29,7 @@ mod executor;
 
 pub use executor::strategy::{self, Strategy};
 pub use executor::task::{self, Task, Tasks};
-pub use executor::{Error, Executor, Result};
+pub use executor::{
+    with_local, Builder, CancellationToken, Error, Executor, Result, Scope,
+    TaskHandle,
+};
@@ -0,0 +1,91 @@
+// Copyright (c) Zensical LLC <https://zensical.org>
+
+// SPDX-License-Identifier: MIT
+// Third-party contributions licensed under CLA
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+
+// ----------------------------------------------------------------------------
+
+//! Benchmarks for edge iteration.
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use std::hint::black_box;
+use zrx_graph::Graph;
+
+// ----------------------------------------------------------------------------
+// Benchmarks
+// ----------------------------------------------------------------------------
+
+/// Benchmarks iteration over all edges in CSR form and over nested vectors,
+/// i.e., one heap allocation per node, on a graph with several edges per node.
+fn edges(c: &mut Criterion) {
+    let mut builder = Graph::builder();
+    let nodes = (0..1024usize)
+        .map(|n| builder.add_node(n))
+        .collect::<Vec<_>>();
+    for &node in &nodes {
+        for offset in [1, 3, 7, 15, 31] {
+            if node + offset < nodes.len() {
+                builder.add_edge(node, node + offset, ()).unwrap();
+            }
+        }
+    }
+
+    // Create nested vectors from topology
+    let graph = builder.build();
+    let topology = graph.topology();
+    let nested = topology
+        .outgoing()
+        .iter()
+        .map(|node| topology.out_neighbors(node).to_vec())
+        .collect::<Vec<_>>();
+
+    // Benchmark iteration over the same edges
+    let mut group = c.benchmark_group("edges");
+    group.bench_function("csr", |b| {
+        b.iter(|| {
+            let (offsets, targets) = topology.csr();
+            let mut sum = 0;
+            for node in 0..offsets.len() - 1 {
+                for &target in &targets[offsets[node]..offsets[node + 1]] {
+                    sum += node ^ target;
+                }
+            }
+            black_box(sum)
+        });
+    });
+    group.bench_function("nested", |b| {
+        b.iter(|| {
+            let mut sum = 0;
+            for (node, targets) in nested.iter().enumerate() {
+                for &target in targets {
+                    sum += node ^ target;
+                }
+            }
+            black_box(sum)
+        });
+    });
+    group.finish();
+}
+
+// ----------------------------------------------------------------------------
+
+criterion_group!(benches, edges);
+criterion_main!(benches);
@@ -0,0 +1,69 @@
+// Copyright (c) Zensical LLC <https://zensical.org>
+
+// SPDX-License-Identifier: MIT
+// Third-party contributions licensed under CLA
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+
+// ----------------------------------------------------------------------------
+
+//! Benchmarks for serial and parallel mapping.
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use std::hint::black_box;
+use zrx_graph::Graph;
+
+// ----------------------------------------------------------------------------
+// Benchmarks
+// ----------------------------------------------------------------------------
+
+/// Benchmarks serial and parallel mapping with an expensive transformation on
+/// a chain of nodes, where the graph is rebuilt for each iteration.
+fn map(c: &mut Criterion) {
+    let mut builder = Graph::builder();
+    let nodes = (0..4096u64)
+        .map(|n| builder.add_node(n))
+        .collect::<Vec<_>>();
+    for window in nodes.windows(2) {
+        builder.add_edge(window[0], window[1], ()).unwrap();
+    }
+
+    // Create expensive transformation
+    let f = |mut n: u64| {
+        for _ in 0..1000 {
+            n = n.wrapping_mul(6_364_136_223_846_793_005).rotate_left(7);
+        }
+        n
+    };
+
+    // Benchmark serial and parallel mapping on the same graph
+    let mut group = c.benchmark_group("map");
+    group.bench_function("serial", |b| {
+        b.iter(|| black_box(builder.clone().build().map(f)));
+    });
+    group.bench_function("parallel", |b| {
+        b.iter(|| black_box(builder.clone().build().par_map(f)));
+    });
+    group.finish();
+}
+
+// ----------------------------------------------------------------------------
+
+criterion_group!(benches, map);
+criterion_main!(benches);
@@ -25,21 +25,42 @@
 
 //! Graph.
 
+use ahash::{HashMap, HashSet};
+#[cfg(feature = "fixedbitset")]
+use fixedbitset::FixedBitSet;
+#[cfg(feature = "rayon")]
+use rayon::iter::{IntoParallelIterator, ParallelIterator};
+use std::collections::HashMap as StdHashMap;
+use std::hash::{BuildHasher, Hash};
 use std::ops::{Index, IndexMut};
 use std::slice::Iter;
+use std::vec;
+use std::{fmt, iter, mem};
 
 pub mod algorithm;
+#[cfg(feature = "serde")]
+mod binary;
 mod builder;
 mod error;
+mod expansion;
+mod node;
 pub mod topology;
 pub mod traversal;
+pub mod validation;
 pub mod visitor;
+mod weighted;
 
 pub use builder::Builder;
 pub use error::{Error, Result};
+pub use expansion::Expansion;
+pub use node::NodeId;
+#[cfg(feature = "fixedbitset")]
+use topology::Adjacency;
 use topology::Topology;
 use traversal::Traversal;
-use visitor::{Ancestors, Descendants, Paths};
+use validation::ValidationReport;
+use visitor::{Ancestors, Bfs, Descendants, Dfs, FilteredDescendants, Paths};
+pub use weighted::WeightedView;
 
 // ----------------------------------------------------------------------------
 // Structs
@@ -182,6 +203,427 @@ impl<T> Graph<T> {
         }
     }
 
+    /// Maps each node to a subgraph, rewriting the topology.
+    ///
+    /// This method expands each node into the [`Expansion`] returned by the
+    /// given function, which receives the index and data of the node, which
+    /// is useful for lowering passes. Internal edges of an expansion are kept,
+    /// and each external edge is rewired from the output port of the source's
+    /// expansion to the input port of the target's expansion. Nodes are added
+    /// in order, so the nodes of an expansion occupy a contiguous index range.
+    ///
+    /// # Errors
+    ///
+    /// In case an expansion is empty, or one of its edges or ports refers to a
+    /// node that doesn't exist, [`Error::NotFound`] is returned with the index
+    /// the node would have had in the new graph.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::error::Error;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use zrx_graph::{Expansion, Graph};
+    ///
+    /// // Create graph builder and add nodes
+    /// let mut builder = Graph::builder();
+    /// let a = builder.add_node("a");
+    /// let b = builder.add_node("b");
+    /// let c = builder.add_node("c");
+    ///
+    /// // Create edges between nodes
+    /// builder.add_edge(a, b, 0)?;
+    /// builder.add_edge(b, c, 0)?;
+    ///
+    /// // Create graph from builder and expand b into two chained nodes
+    /// let graph = builder.build();
+    /// let graph = graph.flat_map_nodes(|node, data| {
+    ///     if node == b {
+    ///         Expansion::chain(["b1", "b2"])
+    ///     } else {
+    ///         Expansion::from(data)
+    ///     }
+    /// })?;
+    ///
+    /// // Ensure external edges are rewired to the ports
+    /// let data = graph.iter().copied().collect::<Vec<_>>();
+    /// assert_eq!(data, ["a", "b1", "b2", "c"]);
+    /// assert_eq!(graph.topology().out_neighbors(0), [1]);
+    /// assert_eq!(graph.topology().out_neighbors(1), [2]);
+    /// assert_eq!(graph.topology().out_neighbors(2), [3]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn flat_map_nodes<F, U>(self, mut f: F) -> Result<Graph<U>>
+    where
+        F: FnMut(usize, T) -> Expansion<U>,
+    {
+        let (data, topology) = self.into_parts();
+        let mut builder = Builder::<U, ()>::new();
+
+        // Add the nodes and internal edges of each expansion, after ensuring
+        // that all edges and ports refer to nodes of the expansion itself
+        let mut ports = Vec::with_capacity(data.len());
+        for (node, data) in data.into_iter().enumerate() {
+            let (nodes, edges, input, output) = f(node, data).into_parts();
+            let offset = builder.len();
+            let iter =
+                edges.iter().flat_map(|&(source, target)| [source, target]);
+            if let Some(index) = iter
+                .chain([input, output])
+                .find(|&index| index >= nodes.len())
+            {
+                return Err(Error::NotFound(offset + index));
+            }
+
+            // Add nodes and internal edges
+            for data in nodes {
+                builder.add_node(data);
+            }
+            for (source, target) in edges {
+                builder.add_edge(offset + source, offset + target, ())?;
+            }
+            ports.push((offset + input, offset + output));
+        }
+
+        // Rewire external edges from output ports to input ports
+        for (source, &(_, output)) in ports.iter().enumerate() {
+            for &target in topology.out_neighbors(source) {
+                builder.add_edge(output, ports[target].0, ())?;
+            }
+        }
+
+        // Return graph
+        Ok(builder.build())
+    }
+
+    /// Maps references to the nodes to a different type.
+    ///
+    /// In contrast to [`Graph::map`], this method doesn't consume the graph,
+    /// so the original graph remains usable. The topology is shared between
+    /// both graphs, which makes this cheap, as only the data is created.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::error::Error;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use zrx_graph::Graph;
+    ///
+    /// // Create graph builder and add nodes
+    /// let mut builder = Graph::builder();
+    /// let a = builder.add_node("a");
+    /// let b = builder.add_node("b");
+    /// let c = builder.add_node("c");
+    ///
+    /// // Create edges between nodes
+    /// builder.add_edge(a, b, 0)?;
+    /// builder.add_edge(b, c, 0)?;
+    ///
+    /// // Create graph from builder and map data
+    /// let graph = builder.build();
+    /// let upper = graph.map_ref(|data| data.to_uppercase());
+    /// assert_eq!(upper[a], "A");
+    ///
+    /// // Original graph remains usable
+    /// assert_eq!(graph[a], "a");
+    /// assert_eq!(graph.len(), upper.len());
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn map_ref<F, U>(&self, f: F) -> Graph<U>
+    where
+        F: FnMut(&T) -> U,
+    {
+        Graph {
+            data: self.data.iter().map(f).collect(),
+            topology: self.topology.clone(),
+        }
+    }
+
+    /// Maps the nodes to a different type in parallel.
+    ///
+    /// This method is equivalent to [`Graph::map`], but transforms the data in
+    /// parallel using [`rayon`], which pays off for expensive transformations.
+    /// The order of nodes is retained, and the topology is reused as is.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::error::Error;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use zrx_graph::Graph;
+    ///
+    /// // Create graph builder and add nodes
+    /// let mut builder = Graph::builder();
+    /// let a = builder.add_node("a");
+    /// let b = builder.add_node("b");
+    /// let c = builder.add_node("c");
+    ///
+    /// // Create edges between nodes
+    /// builder.add_edge(a, b, 0)?;
+    /// builder.add_edge(b, c, 0)?;
+    ///
+    /// // Create graph from builder and map data in parallel
+    /// let graph = builder.build();
+    /// let upper = graph.par_map(str::to_uppercase);
+    /// assert_eq!(upper[c], "C");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "rayon")]
+    #[inline]
+    pub fn par_map<F, U>(self, f: F) -> Graph<U>
+    where
+        T: Send,
+        F: Fn(T) -> U + Sync,
+        U: Send,
+    {
+        Graph {
+            data: self.data.into_par_iter().map(&f).collect(),
+            topology: self.topology,
+        }
+    }
+
+    /// Creates a graph sharing the topology with different data.
+    ///
+    /// The topology is shared between both graphs, so multiple views on the
+    /// same graph structure can carry different data, e.g., names and costs,
+    /// without rebuilding the topology for each of them.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of the data doesn't match the number of nodes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::error::Error;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use std::ptr;
+    /// use zrx_graph::Graph;
+    ///
+    /// // Create graph builder and add nodes
+    /// let mut builder = Graph::builder();
+    /// let a = builder.add_node("a");
+    /// let b = builder.add_node("b");
+    ///
+    /// // Create edges between nodes
+    /// builder.add_edge(a, b, 0)?;
+    ///
+    /// // Create graph from builder and swap in different data
+    /// let graph = builder.build();
+    /// let costs = graph.with_data(vec![1, 2]);
+    /// assert_eq!(costs[b], 2);
+    ///
+    /// // Both graphs share the same topology
+    /// assert!(ptr::eq(
+    ///     graph.topology().outgoing(),
+    ///     costs.topology().outgoing(),
+    /// ));
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn with_data<U>(&self, data: Vec<U>) -> Graph<U> {
+        assert_eq!(data.len(), self.data.len(), "data length mismatch");
+        Graph {
+            data,
+            topology: self.topology.clone(),
+        }
+    }
+
+    /// Creates a graph from the given data and topology.
+    ///
+    /// This is the inverse of [`Graph::into_parts`], and allows to reuse the
+    /// topology of a graph with different data. The number of nodes in the
+    /// topology must match the length of the data, which is only checked in
+    /// debug builds, as indexing would otherwise yield the wrong nodes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::error::Error;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use zrx_graph::Graph;
+    ///
+    /// // Create graph builder and add nodes
+    /// let mut builder = Graph::builder();
+    /// let a = builder.add_node("a");
+    /// let b = builder.add_node("b");
+    ///
+    /// // Create edges between nodes
+    /// builder.add_edge(a, b, 0)?;
+    ///
+    /// // Create graph from builder and decompose it
+    /// let graph = builder.build();
+    /// let (data, topology) = graph.into_parts();
+    /// assert_eq!(data, ["a", "b"]);
+    ///
+    /// // Create graph from parts
+    /// let graph = Graph::from_parts(vec![1, 2], topology);
+    /// assert_eq!(graph[a], 1);
+    /// assert_eq!(graph.descendants(a).collect::<Vec<_>>(), [b]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn from_parts(data: Vec<T>, topology: Topology) -> Self {
+        debug_assert_eq!(data.len(), topology.outgoing().len());
+        Self { data, topology }
+    }
+
+    /// Creates a graph from a named adjacency representation.
+    ///
+    /// Each name is assigned an index on first sight, including names that are
+    /// only referenced as targets, and an edge is added from each name to each
+    /// of its targets. The returned map allows to resolve names to indices.
+    /// Note that indices are assigned in the iteration order of the given map,
+    /// so they're only deterministic if the hasher is deterministic.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use zrx_graph::Graph;
+    ///
+    /// // Create named adjacency
+    /// let adjacency = HashMap::from([
+    ///     ("a", vec!["b", "c"]),
+    ///     ("b", vec!["c"]),
+    /// ]);
+    ///
+    /// // Create graph from named adjacency and resolve names
+    /// let (graph, indices) = Graph::from_named_edges(adjacency);
+    /// assert_eq!(graph.len(), 3);
+    /// assert_eq!(graph[indices["c"]], "c");
+    ///
+    /// // Obtain descendants by name
+    /// let mut nodes = graph
+    ///     .descendants(indices["a"])
+    ///     .map(|node| graph[node])
+    ///     .collect::<Vec<_>>();
+    /// nodes.sort_unstable();
+    /// assert_eq!(nodes, ["b", "c"]);
+    /// ```
+    #[allow(clippy::missing_panics_doc)]
+    #[must_use]
+    pub fn from_named_edges<S>(
+        adjacency: StdHashMap<T, Vec<T>, S>,
+    ) -> (Self, HashMap<T, usize>)
+    where
+        T: Eq + Hash + Clone,
+        S: BuildHasher,
+    {
+        let mut builder = Graph::builder();
+        let mut indices = HashMap::default();
+
+        // Assign indices to all names, including names only used as targets
+        for (source, targets) in &adjacency {
+            for name in iter::once(source).chain(targets) {
+                if !indices.contains_key(name) {
+                    let node = builder.add_node(name.clone());
+                    indices.insert(name.clone(), node);
+                }
+            }
+        }
+
+        // Add edges between nodes - as all names were assigned an index, the
+        // nodes are guaranteed to exist, so adding an edge can't fail
+        for (source, targets) in adjacency {
+            for target in targets {
+                builder
+                    .add_edge(indices[&source], indices[&target], ())
+                    .expect("invariant");
+            }
+        }
+
+        // Return graph and indices
+        (builder.build(), indices)
+    }
+
+    /// Decomposes the graph into its data and topology.
+    ///
+    /// Neither the data nor the topology are cloned, so this is cheap, and
+    /// allows to move the nodes out of the graph. Use [`Graph::from_parts`] to
+    /// create a graph from the parts again.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::error::Error;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use zrx_graph::Graph;
+    ///
+    /// // Create graph builder and add nodes
+    /// let mut builder = Graph::builder();
+    /// let a = builder.add_node("a");
+    /// let b = builder.add_node("b");
+    ///
+    /// // Create edges between nodes
+    /// builder.add_edge(a, b, 0)?;
+    ///
+    /// // Create graph from builder and round-trip through parts
+    /// let graph = builder.build();
+    /// let (data, topology) = graph.into_parts();
+    /// let graph = Graph::from_parts(data, topology);
+    /// assert_eq!(graph[a], "a");
+    /// assert_eq!(graph[b], "b");
+    /// assert_eq!(graph.sinks().collect::<Vec<_>>(), [b]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn into_parts(self) -> (Vec<T>, Topology) {
+        (self.data, self.topology)
+    }
+
+    /// Returns the graph with all edges reversed.
+    ///
+    /// Node indices and data are retained, and only the topology is flipped,
+    /// which allows to walk the graph from sinks towards sources. Note that the
+    /// distance matrix is transposed rather than recomputed, so shortest path
+    /// queries remain correct for the reversed graph.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::error::Error;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use zrx_graph::Graph;
+    ///
+    /// // Create graph builder and add nodes
+    /// let mut builder = Graph::builder();
+    /// let a = builder.add_node("a");
+    /// let b = builder.add_node("b");
+    /// let c = builder.add_node("c");
+    ///
+    /// // Create edges between nodes
+    /// builder.add_edge(a, b, 0)?;
+    /// builder.add_edge(b, c, 0)?;
+    ///
+    /// // Create graph from builder and reverse it
+    /// let graph = builder.build();
+    /// let sinks = graph.sinks().collect::<Vec<_>>();
+    /// let graph = graph.reverse();
+    /// assert_eq!(graph.sources().collect::<Vec<_>>(), sinks);
+    /// assert_eq!(graph[c], "c");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn reverse(self) -> Graph<T> {
+        Graph {
+            data: self.data,
+            topology: self.topology.reverse(),
+        }
+    }
+
     /// Creates a topogical traversal starting from the given initial nodes.
     ///
     /// This method creates a topological traversal of the graph, which allows
@@ -189,8 +631,1027 @@ impl<T> Graph<T> {
     /// all its dependencies have been visited. The traversal is initialized
     /// with the given initial nodes, which are the starting points.
     ///
-    /// Note that an arbitrary number of parallel traversals can be created
-    /// from the same graph, as the underlying topology is shared between them.
+    /// Note that an arbitrary number of parallel traversals can be created
+    /// from the same graph, as the underlying topology is shared between them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::error::Error;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use zrx_graph::Graph;
+    ///
+    /// // Create graph builder and add nodes
+    /// let mut builder = Graph::builder();
+    /// let a = builder.add_node("a");
+    /// let b = builder.add_node("b");
+    /// let c = builder.add_node("c");
+    ///
+    /// // Create edges between nodes
+    /// builder.add_edge(a, b, 0)?;
+    /// builder.add_edge(b, c, 0)?;
+    ///
+    /// // Create graph from builder
+    /// let graph = builder.build();
+    ///
+    /// // Create topological traversal
+    /// let mut traversal = graph.traverse([a]);
+    /// while let Some(node) = traversal.take() {
+    ///     println!("{node:?}");
+    ///     traversal.complete(node)?;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn traverse<I>(&self, initial: I) -> Traversal
+    where
+        I: IntoIterator<Item = usize>,
+    {
+        Traversal::new(&self.topology, initial)
+    }
+
+    /// Creates an iterator over the sources of the graph.
+    ///
+    /// This method returns an iterator over the source node indices of the
+    /// graph, which are the nodes with no incoming edges. Sources are computed
+    /// once when the graph is built, so the iterator knows its length.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::error::Error;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use zrx_graph::Graph;
+    ///
+    /// // Create graph builder and add nodes
+    /// let mut builder = Graph::builder();
+    /// let a = builder.add_node("a");
+    /// let b = builder.add_node("b");
+    /// let c = builder.add_node("c");
+    ///
+    /// // Create edges between nodes
+    /// builder.add_edge(a, b, 0)?;
+    /// builder.add_edge(b, c, 0)?;
+    ///
+    /// // Create graph from builder
+    /// let graph = builder.build();
+    ///
+    /// // Create iterator over sources
+    /// for node in graph.sources() {
+    ///     println!("{node:?}");
+    /// }
+    ///
+    /// // Obtain number of sources
+    /// let incoming = graph.topology().incoming();
+    /// let expected: Vec<_> = incoming
+    ///     .iter()
+    ///     .filter(|&node| incoming[node].is_empty())
+    ///     .collect();
+    /// assert_eq!(graph.sources().len(), expected.len());
+    /// assert_eq!(graph.sources().collect::<Vec<_>>(), expected);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn sources(&self) -> impl ExactSizeIterator<Item = usize> {
+        self.topology.sources().iter().copied()
+    }
+
+    /// Creates an iterator over the sinks of the graph.
+    ///
+    /// This method returns an iterator over the sink node indices of the
+    /// graph, which are the nodes with no outgoing edges. Sinks are computed
+    /// once when the graph is built, so the iterator knows its length.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::error::Error;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use zrx_graph::Graph;
+    ///
+    /// // Create graph builder and add nodes
+    /// let mut builder = Graph::builder();
+    /// let a = builder.add_node("a");
+    /// let b = builder.add_node("b");
+    /// let c = builder.add_node("c");
+    ///
+    /// // Create edges between nodes
+    /// builder.add_edge(a, b, 0)?;
+    /// builder.add_edge(b, c, 0)?;
+    ///
+    /// // Create graph from builder
+    /// let graph = builder.build();
+    ///
+    /// // Create iterator over sinks
+    /// for node in graph.sinks() {
+    ///     println!("{node:?}");
+    /// }
+    ///
+    /// // Obtain number of sinks
+    /// let outgoing = graph.topology().outgoing();
+    /// let expected: Vec<_> = outgoing
+    ///     .iter()
+    ///     .filter(|&node| outgoing[node].is_empty())
+    ///     .collect();
+    /// assert_eq!(graph.sinks().len(), expected.len());
+    /// assert_eq!(graph.sinks().collect::<Vec<_>>(), expected);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn sinks(&self) -> impl ExactSizeIterator<Item = usize> {
+        self.topology.sinks().iter().copied()
+    }
+
+    /// Creates an iterator over the sinks reachable from the given node.
+    ///
+    /// Reachability is determined by the distance matrix, so this is more
+    /// direct than intersecting the descendants of the node with all sinks.
+    /// If the node is a sink itself, it's considered reachable from itself.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the node is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::error::Error;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use zrx_graph::Graph;
+    ///
+    /// // Create graph builder and add nodes
+    /// let mut builder = Graph::builder();
+    /// let a = builder.add_node("a");
+    /// let b = builder.add_node("b");
+    /// let c = builder.add_node("c");
+    /// let d = builder.add_node("d");
+    /// let e = builder.add_node("e");
+    ///
+    /// // Create edges between nodes
+    /// builder.add_edge(a, b, 0)?;
+    /// builder.add_edge(a, c, 0)?;
+    /// builder.add_edge(b, d, 0)?;
+    /// builder.add_edge(b, e, 0)?;
+    ///
+    /// // Create graph from builder
+    /// let graph = builder.build();
+    ///
+    /// // Obtain sinks reachable from nodes
+    /// assert_eq!(graph.sinks().collect::<Vec<_>>(), [c, d, e]);
+    /// assert_eq!(graph.reachable_sinks(a).collect::<Vec<_>>(), [c, d, e]);
+    /// assert_eq!(graph.reachable_sinks(b).collect::<Vec<_>>(), [d, e]);
+    /// assert_eq!(graph.reachable_sinks(c).collect::<Vec<_>>(), [c]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn reachable_sinks(&self, node: usize) -> impl Iterator<Item = usize> {
+        let distance = &self.topology.distance()[node];
+        self.sinks().filter(move |&sink| distance[sink] != u8::MAX)
+    }
+
+    /// Returns the number of nodes that transitively depend on the given node.
+    ///
+    /// The impact of a node is the number of nodes reachable from it, which is
+    /// determined by the distance matrix in O(n), excluding the node itself.
+    /// Nodes with a higher impact unblock more work once completed, so they
+    /// can be prioritized when scheduling.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the node is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::error::Error;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use zrx_graph::Graph;
+    ///
+    /// // Create graph builder and add nodes
+    /// let mut builder = Graph::builder();
+    /// let a = builder.add_node("a");
+    /// let b = builder.add_node("b");
+    /// let c = builder.add_node("c");
+    ///
+    /// // Create edges between nodes
+    /// builder.add_edge(a, b, 0)?;
+    /// builder.add_edge(a, c, 0)?;
+    ///
+    /// // Create graph from builder
+    /// let graph = builder.build();
+    ///
+    /// // Obtain impact of nodes
+    /// assert_eq!(graph.impact(a), 2);
+    /// assert_eq!(graph.impact(b), 0);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn impact(&self, node: usize) -> usize {
+        let distance = &self.topology.distance()[node];
+        let iter = distance.iter().filter(|&&value| value != u8::MAX);
+        iter.count() - 1
+    }
+
+    /// Returns whether there's an edge from the source to the target node.
+    ///
+    /// This method scans the outgoing edges of the source node, so it runs in
+    /// O(d), where d is the out-degree of the source. Note that the builder
+    /// doesn't deduplicate edges, so adding the same edge twice results in a
+    /// graph that contains it twice, which is why the target might appear
+    /// multiple times in [`Topology::out_neighbors`]. This method only checks
+    /// for the presence of at least one edge.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the source node is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::error::Error;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use zrx_graph::Graph;
+    ///
+    /// // Create graph builder and add nodes
+    /// let mut builder = Graph::builder();
+    /// let a = builder.add_node("a");
+    /// let b = builder.add_node("b");
+    ///
+    /// // Create edges between nodes
+    /// builder.add_edge(a, b, 0)?;
+    ///
+    /// // Create graph from builder
+    /// let graph = builder.build();
+    ///
+    /// // Check presence of edges
+    /// assert!(graph.has_edge(a, b));
+    /// assert!(!graph.has_edge(b, a));
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn has_edge(&self, source: usize, target: usize) -> bool {
+        self.topology.out_neighbors(source).contains(&target)
+    }
+
+    /// Creates an iterator over the nodes reachable from the given node in
+    /// breadth-first order, starting with the node itself.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::error::Error;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use zrx_graph::Graph;
+    ///
+    /// // Create graph builder and add nodes
+    /// let mut builder = Graph::builder();
+    /// let a = builder.add_node("a");
+    /// let b = builder.add_node("b");
+    /// let c = builder.add_node("c");
+    /// let d = builder.add_node("d");
+    /// let e = builder.add_node("e");
+    ///
+    /// // Create edges between nodes
+    /// builder.add_edge(a, b, 0)?;
+    /// builder.add_edge(a, c, 0)?;
+    /// builder.add_edge(b, d, 0)?;
+    /// builder.add_edge(c, e, 0)?;
+    ///
+    /// // Create graph from builder
+    /// let graph = builder.build();
+    ///
+    /// // Obtain nodes in breadth-first order
+    /// let nodes: Vec<_> = graph.bfs(a).collect();
+    /// assert_eq!(nodes, [a, b, c, d, e]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn bfs(&self, start: usize) -> Bfs<'_> {
+        Bfs::new(&self.topology, start)
+    }
+
+    /// Creates an iterator over the nodes reachable from the given node in
+    /// depth-first pre-order, starting with the node itself.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::error::Error;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use zrx_graph::Graph;
+    ///
+    /// // Create graph builder and add nodes
+    /// let mut builder = Graph::builder();
+    /// let a = builder.add_node("a");
+    /// let b = builder.add_node("b");
+    /// let c = builder.add_node("c");
+    /// let d = builder.add_node("d");
+    /// let e = builder.add_node("e");
+    ///
+    /// // Create edges between nodes
+    /// builder.add_edge(a, b, 0)?;
+    /// builder.add_edge(a, c, 0)?;
+    /// builder.add_edge(b, d, 0)?;
+    /// builder.add_edge(c, e, 0)?;
+    ///
+    /// // Create graph from builder
+    /// let graph = builder.build();
+    ///
+    /// // Obtain nodes in depth-first order
+    /// let nodes: Vec<_> = graph.dfs(a).collect();
+    /// assert_eq!(nodes, [a, b, d, c, e]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn dfs(&self, start: usize) -> Dfs<'_> {
+        Dfs::new(&self.topology, start)
+    }
+
+    /// Creates an iterator over the ancestors of the given node.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::error::Error;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use zrx_graph::Graph;
+    ///
+    /// // Create graph builder and add nodes
+    /// let mut builder = Graph::builder();
+    /// let a = builder.add_node("a");
+    /// let b = builder.add_node("b");
+    /// let c = builder.add_node("c");
+    ///
+    /// // Create edges between nodes
+    /// builder.add_edge(a, b, 0)?;
+    /// builder.add_edge(b, c, 0)?;
+    ///
+    /// // Create graph from builder
+    /// let graph = builder.build();
+    ///
+    /// // Create iterator over ancestors
+    /// for node in graph.ancestors(c) {
+    ///     println!("{node:?}");
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn ancestors(&self, node: usize) -> Ancestors<'_> {
+        Ancestors::new(&self.topology, node)
+    }
+
+    /// Creates an iterator over the ancestors of the given node, treating the
+    /// nodes of the given stop set as walls.
+    ///
+    /// Nodes in the stop set are yielded, but their ancestors are not explored,
+    /// unless they're reachable via other paths. This is useful in incremental
+    /// analysis, where the ancestors of some nodes are known to be processed,
+    /// as it avoids walking shared regions of the graph again.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::error::Error;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use ahash::HashSet;
+    /// use zrx_graph::Graph;
+    ///
+    /// // Create graph builder and add nodes
+    /// let mut builder = Graph::builder();
+    /// let a = builder.add_node("a");
+    /// let b = builder.add_node("b");
+    /// let c = builder.add_node("c");
+    /// let d = builder.add_node("d");
+    ///
+    /// // Create edges between nodes
+    /// builder.add_edge(a, b, 0)?;
+    /// builder.add_edge(b, d, 0)?;
+    /// builder.add_edge(c, d, 0)?;
+    ///
+    /// // Create graph from builder
+    /// let graph = builder.build();
+    ///
+    /// // Create iterator over ancestors, stopping at b
+    /// let stop = HashSet::from_iter([b]);
+    /// let mut ancestors: Vec<_> = graph.ancestors_until(d, &stop).collect();
+    /// ancestors.sort_unstable();
+    /// assert_eq!(ancestors, [b, c]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[allow(clippy::implicit_hasher)]
+    #[inline]
+    #[must_use]
+    pub fn ancestors_until<'a>(
+        &'a self, node: usize, stop: &'a HashSet<usize>,
+    ) -> Ancestors<'a> {
+        Ancestors::new_until(&self.topology, node, stop)
+    }
+
+    /// Returns the ancestors of the given node in ascending order.
+    ///
+    /// The iterator returned by [`Graph::ancestors`] yields nodes in depth-first
+    /// order, which depends on the order in which edges were added. This method
+    /// collects and sorts them, so the result is deterministic for the same
+    /// set of edges, which is useful for stable diffs and snapshot tests.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::error::Error;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use zrx_graph::Graph;
+    ///
+    /// // Create graph builder and add nodes
+    /// let mut builder = Graph::builder();
+    /// let a = builder.add_node("a");
+    /// let b = builder.add_node("b");
+    /// let c = builder.add_node("c");
+    /// let d = builder.add_node("d");
+    ///
+    /// // Create edges between nodes
+    /// builder.add_edge(a, d, 0)?;
+    /// builder.add_edge(d, b, 0)?;
+    /// builder.add_edge(a, c, 0)?;
+    ///
+    /// // Create graph from builder
+    /// let graph = builder.build();
+    ///
+    /// // Obtain ancestors in ascending order
+    /// assert_eq!(graph.ancestors_sorted(b), [a, d]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn ancestors_sorted(&self, node: usize) -> Vec<usize> {
+        let mut nodes: Vec<_> = self.ancestors(node).collect();
+        nodes.sort_unstable();
+        nodes
+    }
+
+    /// Returns the ancestors of the given node as a bitset.
+    ///
+    /// This method is equivalent to collecting [`Graph::ancestors`] into a set,
+    /// but uses a [`FixedBitSet`], which is far denser than a hash set, and
+    /// allows for fast unions and intersections on large graphs.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::error::Error;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use zrx_graph::Graph;
+    ///
+    /// // Create graph builder and add nodes
+    /// let mut builder = Graph::builder();
+    /// let a = builder.add_node("a");
+    /// let b = builder.add_node("b");
+    /// let c = builder.add_node("c");
+    ///
+    /// // Create edges between nodes
+    /// builder.add_edge(a, b, 0)?;
+    /// builder.add_edge(b, c, 0)?;
+    ///
+    /// // Create graph from builder
+    /// let graph = builder.build();
+    ///
+    /// // Obtain ancestors as bitset
+    /// let bitset = graph.ancestor_bitset(c);
+    /// let mut nodes = graph.ancestors(c).collect::<Vec<_>>();
+    /// nodes.sort_unstable();
+    /// assert_eq!(bitset.ones().collect::<Vec<_>>(), nodes);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "fixedbitset")]
+    #[must_use]
+    pub fn ancestor_bitset(&self, node: usize) -> FixedBitSet {
+        collect_bitset(self.topology.incoming(), node)
+    }
+
+    /// Creates an iterator over the descendants of the given node.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::error::Error;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use zrx_graph::Graph;
+    ///
+    /// // Create graph builder and add nodes
+    /// let mut builder = Graph::builder();
+    /// let a = builder.add_node("a");
+    /// let b = builder.add_node("b");
+    /// let c = builder.add_node("c");
+    ///
+    /// // Create edges between nodes
+    /// builder.add_edge(a, b, 0)?;
+    /// builder.add_edge(b, c, 0)?;
+    ///
+    /// // Create graph from builder
+    /// let graph = builder.build();
+    ///
+    /// // Create iterator over descendants
+    /// for node in graph.descendants(a) {
+    ///     println!("{node:?}");
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn descendants(&self, node: usize) -> Descendants<'_> {
+        Descendants::new(&self.topology, node)
+    }
+
+    /// Returns the descendants of the given node in ascending order.
+    ///
+    /// The iterator returned by [`Graph::descendants`] yields nodes in depth-first
+    /// order, which depends on the order in which edges were added. This method
+    /// collects and sorts them, so the result is deterministic for the same
+    /// set of edges, which is useful for stable diffs and snapshot tests.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::error::Error;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use zrx_graph::Graph;
+    ///
+    /// // Create graph builder and add nodes
+    /// let mut builder = Graph::builder();
+    /// let a = builder.add_node("a");
+    /// let b = builder.add_node("b");
+    /// let c = builder.add_node("c");
+    /// let d = builder.add_node("d");
+    ///
+    /// // Create edges between nodes
+    /// builder.add_edge(a, d, 0)?;
+    /// builder.add_edge(d, b, 0)?;
+    /// builder.add_edge(a, c, 0)?;
+    ///
+    /// // Create graph from builder
+    /// let graph = builder.build();
+    ///
+    /// // Obtain descendants in ascending order
+    /// assert_eq!(graph.descendants_sorted(a), [b, c, d]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn descendants_sorted(&self, node: usize) -> Vec<usize> {
+        let mut nodes: Vec<_> = self.descendants(node).collect();
+        nodes.sort_unstable();
+        nodes
+    }
+
+    /// Creates an iterator over the descendants of the given node, following
+    /// only edges that satisfy the given filter.
+    ///
+    /// The filter receives the source and target of each edge, and edges for
+    /// which it returns `false` are skipped, as if they didn't exist. This
+    /// allows to traverse conditional subgraphs without rebuilding the graph.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::error::Error;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use zrx_graph::Graph;
+    ///
+    /// // Create graph builder and add nodes
+    /// let mut builder = Graph::builder();
+    /// let a = builder.add_node("a");
+    /// let b = builder.add_node("b");
+    /// let c = builder.add_node("c");
+    /// let d = builder.add_node("d");
+    ///
+    /// // Create edges between nodes
+    /// builder.add_edge(a, b, 0)?;
+    /// builder.add_edge(b, c, 0)?;
+    /// builder.add_edge(a, d, 0)?;
+    ///
+    /// // Create graph from builder
+    /// let graph = builder.build();
+    ///
+    /// // Create iterator over descendants, skipping edge from a to b
+    /// let descendants: Vec<_> = graph
+    ///     .descendants_filtered(a, |source, target| (source, target) != (a, b))
+    ///     .collect();
+    /// assert_eq!(descendants, [d]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn descendants_filtered<F>(
+        &self, node: usize, filter: F,
+    ) -> FilteredDescendants<'_, F>
+    where
+        F: Fn(usize, usize) -> bool,
+    {
+        FilteredDescendants::new(&self.topology, node, filter)
+    }
+
+    /// Returns the first descendant of the given node satisfying the predicate.
+    ///
+    /// This method performs the same depth-first search as [`Graph::descendants`],
+    /// but stops as soon as the predicate returns `true` for a descendant, so
+    /// the remaining descendants are never visited. The predicate receives the
+    /// index of the descendant and a reference to its data. Note that the given
+    /// node itself is not considered, since it's not its own descendant.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::error::Error;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use zrx_graph::Graph;
+    ///
+    /// // Create graph builder and add nodes
+    /// let mut builder = Graph::builder();
+    /// let a = builder.add_node("a");
+    /// let b = builder.add_node("b");
+    /// let c = builder.add_node("c");
+    ///
+    /// // Create edges between nodes
+    /// builder.add_edge(a, b, 0)?;
+    /// builder.add_edge(b, c, 0)?;
+    ///
+    /// // Create graph from builder
+    /// let graph = builder.build();
+    ///
+    /// // Find first descendant matching the predicate
+    /// let node = graph.find_descendant(a, |_, &data| data == "c");
+    /// assert_eq!(node, Some(c));
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn find_descendant<F>(&self, node: usize, predicate: F) -> Option<usize>
+    where
+        F: Fn(usize, &T) -> bool,
+    {
+        self.descendants(node)
+            .find(|&descendant| predicate(descendant, &self.data[descendant]))
+    }
+
+    /// Returns the descendants of the given node as a bitset.
+    ///
+    /// This method is equivalent to collecting [`Graph::descendants`] into a set,
+    /// but uses a [`FixedBitSet`], which is far denser than a hash set, and
+    /// allows for fast unions and intersections on large graphs.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::error::Error;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use zrx_graph::Graph;
+    ///
+    /// // Create graph builder and add nodes
+    /// let mut builder = Graph::builder();
+    /// let a = builder.add_node("a");
+    /// let b = builder.add_node("b");
+    /// let c = builder.add_node("c");
+    ///
+    /// // Create edges between nodes
+    /// builder.add_edge(a, b, 0)?;
+    /// builder.add_edge(b, c, 0)?;
+    ///
+    /// // Create graph from builder
+    /// let graph = builder.build();
+    ///
+    /// // Obtain descendants as bitset
+    /// let bitset = graph.descendant_bitset(a);
+    /// let mut nodes = graph.descendants(a).collect::<Vec<_>>();
+    /// nodes.sort_unstable();
+    /// assert_eq!(bitset.ones().collect::<Vec<_>>(), nodes);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "fixedbitset")]
+    #[must_use]
+    pub fn descendant_bitset(&self, node: usize) -> FixedBitSet {
+        collect_bitset(self.topology.outgoing(), node)
+    }
+
+    /// Creates an iterator over all paths between the given nodes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::error::Error;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use zrx_graph::Graph;
+    ///
+    /// // Create graph builder and add nodes
+    /// let mut builder = Graph::builder();
+    /// let a = builder.add_node("a");
+    /// let b = builder.add_node("b");
+    /// let c = builder.add_node("c");
+    ///
+    /// // Create edges between nodes
+    /// builder.add_edge(a, b, 0)?;
+    /// builder.add_edge(b, c, 0)?;
+    ///
+    /// // Create graph from builder
+    /// let graph = builder.build();
+    ///
+    /// // Create iterator over paths
+    /// for path in graph.paths(a, c) {
+    ///     println!("{path:?}");
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn paths(&self, source: usize, target: usize) -> Paths<'_> {
+        Paths::new(&self.topology, source, target)
+    }
+
+    /// Invokes the given function for each path between two nodes.
+    ///
+    /// This method visits the same paths in the same order as [`Graph::paths`],
+    /// but passes a reference to the current path to the given function, which
+    /// avoids allocating a [`Vec`] for each path. This is preferable for dense
+    /// graphs, where paths only need to be counted or inspected.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::error::Error;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use zrx_graph::Graph;
+    ///
+    /// // Create graph builder and add nodes
+    /// let mut builder = Graph::builder();
+    /// let a = builder.add_node("a");
+    /// let b = builder.add_node("b");
+    /// let c = builder.add_node("c");
+    /// let d = builder.add_node("d");
+    ///
+    /// // Create edges between nodes
+    /// builder.add_edge(a, b, 0)?;
+    /// builder.add_edge(a, c, 0)?;
+    /// builder.add_edge(b, d, 0)?;
+    /// builder.add_edge(c, d, 0)?;
+    /// builder.add_edge(a, d, 0)?;
+    ///
+    /// // Create graph from builder
+    /// let graph = builder.build();
+    ///
+    /// // Collect paths and compare with iterator
+    /// let mut paths = Vec::new();
+    /// graph.for_each_path(a, d, |path| paths.push(path.to_vec()));
+    /// assert_eq!(paths, graph.paths(a, d).collect::<Vec<_>>());
+    /// assert_eq!(paths.len(), 3);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn for_each_path<F>(&self, source: usize, target: usize, mut f: F)
+    where
+        F: FnMut(&[usize]),
+    {
+        let outgoing = self.topology.outgoing();
+
+        // Perform a depth-first search to find all paths from the source to
+        // the target, reusing the same path buffer for all of them
+        let mut stack = Vec::from([(source, 0)]);
+        let mut path = Vec::new();
+        while let Some((node, depth)) = stack.pop() {
+            path.truncate(depth);
+            path.push(node);
+
+            // In case we've reached the target, pass the current path
+            if node == target {
+                f(&path);
+                continue;
+            }
+
+            // Add descendants to stack in reverse order for consistent depth-
+            // first ordering, matching the order of the iterator
+            for &descendant in outgoing[node].iter().rev() {
+                stack.push((descendant, depth + 1));
+            }
+        }
+    }
+
+    /// Creates a weighted view of the graph.
+    ///
+    /// Edge weights are dropped when building the graph, so this method allows
+    /// to attach weights computed on demand by the given function, which gets
+    /// the source and target of an edge. The resulting [`WeightedView`] can be
+    /// passed to weighted algorithms without rebuilding the graph.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::error::Error;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use zrx_graph::algorithm::weighted_shortest_path_length;
+    /// use zrx_graph::Graph;
+    ///
+    /// // Create graph builder and add nodes
+    /// let mut builder = Graph::builder();
+    /// let a = builder.add_node("a");
+    /// let b = builder.add_node("b");
+    /// let c = builder.add_node("c");
+    ///
+    /// // Create edges between nodes
+    /// builder.add_edge(a, b, 0)?;
+    /// builder.add_edge(b, c, 0)?;
+    /// builder.add_edge(a, c, 0)?;
+    ///
+    /// // Create graph from builder
+    /// let graph = builder.build();
+    ///
+    /// // Create weighted view, making the direct edge expensive
+    /// let view = graph.with_edge_weights(|source, target| {
+    ///     if (source, target) == (a, c) { 10 } else { 1 }
+    /// });
+    ///
+    /// // Obtain weighted shortest path length
+    /// let len = weighted_shortest_path_length(&view, a, c);
+    /// assert_eq!(len, Some(2));
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn with_edge_weights<'a, W, F>(&'a self, f: F) -> WeightedView<'a, T, W>
+    where
+        F: Fn(usize, usize) -> W + 'a,
+    {
+        WeightedView::new(self, f)
+    }
+
+    /// Creates the adjacency matrix of the graph.
+    ///
+    /// This method returns a dense matrix, where the entry at row `i` and
+    /// column `j` is `true` if and only if there's an edge from node `i` to
+    /// node `j`. Since the matrix is dense, it requires O(n²) memory, so it's
+    /// best used for interop with numeric tooling or structural comparisons.
+    /// Note that multiple edges between the same nodes collapse into a single
+    /// entry, as the matrix only records whether nodes are adjacent.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::error::Error;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use zrx_graph::Graph;
+    ///
+    /// // Create graph builder and add nodes
+    /// let mut builder = Graph::builder();
+    /// let a = builder.add_node("a");
+    /// let b = builder.add_node("b");
+    /// let c = builder.add_node("c");
+    ///
+    /// // Create edges between nodes
+    /// builder.add_edge(a, b, 0)?;
+    /// builder.add_edge(b, c, 0)?;
+    /// builder.add_edge(a, c, 0)?;
+    ///
+    /// // Create graph from builder
+    /// let graph = builder.build();
+    ///
+    /// // Create adjacency matrix
+    /// let matrix = graph.to_adjacency_matrix();
+    /// assert!(matrix[a][b] && matrix[b][c] && matrix[a][c]);
+    /// assert_eq!(matrix.iter().flatten().filter(|&&edge| edge).count(), 3);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn to_adjacency_matrix(&self) -> Vec<Vec<bool>> {
+        let outgoing = self.topology.outgoing();
+
+        // Initialize matrix and mark all outgoing edges of each node
+        let mut matrix = vec![vec![false; self.len()]; self.len()];
+        for source in outgoing {
+            for &target in &outgoing[source] {
+                matrix[source][target] = true;
+            }
+        }
+
+        // Return adjacency matrix
+        matrix
+    }
+
+    /// Formats the graph as an indented tree, starting at the given root.
+    ///
+    /// This method renders all descendants of the root node by following the
+    /// outgoing edges, which is primarily intended for terminal output. Since
+    /// nodes in a DAG can be reached on multiple paths, nodes that have already
+    /// been expanded are marked with `(*)` and not expanded again, preventing
+    /// the output from growing exponentially on shared descendants.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the root node does not exist.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::error::Error;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use zrx_graph::Graph;
+    ///
+    /// // Create graph builder and add nodes
+    /// let mut builder = Graph::builder();
+    /// let a = builder.add_node("a");
+    /// let b = builder.add_node("b");
+    /// let c = builder.add_node("c");
+    /// let d = builder.add_node("d");
+    ///
+    /// // Create edges between nodes
+    /// builder.add_edge(a, b, 0)?;
+    /// builder.add_edge(a, c, 0)?;
+    /// builder.add_edge(b, d, 0)?;
+    /// builder.add_edge(c, d, 0)?;
+    ///
+    /// // Create graph from builder
+    /// let graph = builder.build();
+    ///
+    /// // Format graph as tree
+    /// assert_eq!(graph.format_tree(a), [
+    ///     "a",
+    ///     "├── b",
+    ///     "│   └── d",
+    ///     "└── c",
+    ///     "    └── d (*)",
+    /// ].join("\n"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn format_tree(&self, root: usize) -> String
+    where
+        T: fmt::Display,
+    {
+        let outgoing = self.topology.outgoing();
+
+        // Initialize output with the root node and mark it as visited
+        let mut output = self.data[root].to_string();
+        let mut visited = vec![false; self.len()];
+        visited[root] = true;
+
+        // Perform a depth-first search, tracking the prefix for each node, as
+        // well as whether it's the last child of its parent. Children are put
+        // on the stack in reverse order, so they're rendered in edge order.
+        let mut stack = Vec::new();
+        for (index, &node) in outgoing[root].iter().enumerate().rev() {
+            let last = index + 1 == outgoing[root].len();
+            stack.push((node, String::new(), last));
+        }
+        while let Some((node, prefix, last)) = stack.pop() {
+            let branch = if last { "└── " } else { "├── " };
+            output.push('\n');
+            output.push_str(&prefix);
+            output.push_str(branch);
+            output.push_str(&self.data[node].to_string());
+
+            // If the node has already been expanded, we mark it and continue,
+            // as its descendants have already been rendered before
+            if visited[node] {
+                output.push_str(" (*)");
+                continue;
+            }
+            visited[node] = true;
+
+            // Add children with the extended prefix to the stack
+            let prefix = prefix + if last { "    " } else { "│   " };
+            for (index, &child) in outgoing[node].iter().enumerate().rev() {
+                let last = index + 1 == outgoing[node].len();
+                stack.push((child, prefix.clone(), last));
+            }
+        }
+
+        // Return formatted tree
+        output
+    }
+
+    /// Exports the graph in the DOT format.
+    ///
+    /// Nodes are identified by their indices and labelled with their data, and
+    /// edges are emitted in edge order. This is equivalent to calling
+    /// [`Graph::to_dot_with`] with closures that don't return attributes.
     ///
     /// # Examples
     ///
@@ -203,36 +1664,39 @@ impl<T> Graph<T> {
     /// let mut builder = Graph::builder();
     /// let a = builder.add_node("a");
     /// let b = builder.add_node("b");
-    /// let c = builder.add_node("c");
     ///
     /// // Create edges between nodes
     /// builder.add_edge(a, b, 0)?;
-    /// builder.add_edge(b, c, 0)?;
     ///
     /// // Create graph from builder
     /// let graph = builder.build();
     ///
-    /// // Create topological traversal
-    /// let mut traversal = graph.traverse([a]);
-    /// while let Some(node) = traversal.take() {
-    ///     println!("{node:?}");
-    ///     traversal.complete(node)?;
-    /// }
+    /// // Export graph in DOT format
+    /// assert_eq!(graph.to_dot(), [
+    ///     "digraph {",
+    ///     "    0 [label=\"a\"];",
+    ///     "    1 [label=\"b\"];",
+    ///     "    0 -> 1;",
+    ///     "}",
+    /// ].join("\n"));
     /// # Ok(())
     /// # }
     /// ```
-    #[inline]
-    pub fn traverse<I>(&self, initial: I) -> Traversal
+    #[must_use]
+    pub fn to_dot(&self) -> String
     where
-        I: IntoIterator<Item = usize>,
+        T: fmt::Display,
     {
-        Traversal::new(&self.topology, initial)
+        self.to_dot_with(|_, _| String::new(), |_, _| String::new())
     }
 
-    /// Creates an iterator over the sources of the graph.
+    /// Exports the graph in the DOT format with custom attributes.
     ///
-    /// This method returns an iterator over the source node indices of the
-    /// graph, which are the nodes with no incoming edges.
+    /// The given closures are called for each node with its index and data,
+    /// and for each edge with its source and target, and return attributes
+    /// that are appended verbatim after the label, e.g., `color=red`, which
+    /// allows for rich visualizations driven by node data, like highlighting
+    /// the critical path. Empty strings don't add any attributes.
     ///
     /// # Examples
     ///
@@ -254,23 +1718,67 @@ impl<T> Graph<T> {
     /// // Create graph from builder
     /// let graph = builder.build();
     ///
-    /// // Create iterator over sources
-    /// for node in graph.sources() {
-    ///     println!("{node:?}");
-    /// }
+    /// // Export graph in DOT format, highlighting node and edge
+    /// let dot = graph.to_dot_with(
+    ///     |_, &data| match data {
+    ///         "b" => String::from("color=red"),
+    ///         _ => String::new(),
+    ///     },
+    ///     |source, target| match (source, target) {
+    ///         (1, 2) => String::from("style=bold"),
+    ///         _ => String::new(),
+    ///     },
+    /// );
+    /// assert!(dot.contains("    1 [label=\"b\", color=red];"));
+    /// assert!(dot.contains("    0 [label=\"a\"];"));
+    /// assert!(dot.contains("    1 -> 2 [style=bold];"));
     /// # Ok(())
     /// # }
     /// ```
-    #[inline]
-    pub fn sources(&self) -> impl Iterator<Item = usize> {
-        let incoming = self.topology.incoming();
-        incoming.iter().filter(|&node| incoming[node].is_empty())
+    pub fn to_dot_with<FN, FE>(
+        &self, mut node_attrs: FN, mut edge_attrs: FE,
+    ) -> String
+    where
+        T: fmt::Display,
+        FN: FnMut(usize, &T) -> String,
+        FE: FnMut(usize, usize) -> String,
+    {
+        let mut lines = vec![String::from("digraph {")];
+
+        // Render nodes with their escaped labels and custom attributes
+        for (node, data) in self.data.iter().enumerate() {
+            let label = data.to_string().replace('\\', "\\\\");
+            let label = label.replace('"', "\\\"");
+            let attrs = node_attrs(node, data);
+            lines.push(if attrs.is_empty() {
+                format!("    {node} [label=\"{label}\"];")
+            } else {
+                format!("    {node} [label=\"{label}\", {attrs}];")
+            });
+        }
+
+        // Render edges with their custom attributes
+        for source in 0..self.len() {
+            for &target in self.topology.out_neighbors(source) {
+                let attrs = edge_attrs(source, target);
+                lines.push(if attrs.is_empty() {
+                    format!("    {source} -> {target};")
+                } else {
+                    format!("    {source} -> {target} [{attrs}];")
+                });
+            }
+        }
+
+        // Return DOT output
+        lines.push(String::from("}"));
+        lines.join("\n")
     }
 
-    /// Creates an iterator over the sinks of the graph.
+    /// Validates the graph, collecting all structural issues.
     ///
-    /// This method returns an iterator over the sink node indices of the
-    /// graph, which are the nodes with no incoming edges.
+    /// This method performs a single diagnostic pass over the graph, and
+    /// collects self-loops, duplicate edges, isolated nodes and cycles into a
+    /// [`ValidationReport`], instead of failing on the first problem.
     ///
     /// # Examples
     ///
@@ -284,28 +1792,36 @@ impl<T> Graph<T> {
     /// let a = builder.add_node("a");
     /// let b = builder.add_node("b");
     /// let c = builder.add_node("c");
+    /// let d = builder.add_node("d");
     ///
     /// // Create edges between nodes
     /// builder.add_edge(a, b, 0)?;
-    /// builder.add_edge(b, c, 0)?;
+    /// builder.add_edge(a, b, 0)?;
+    /// builder.add_edge(b, a, 0)?;
+    /// builder.add_edge(c, c, 0)?;
     ///
-    /// // Create graph from builder
+    /// // Create graph from builder and validate it
     /// let graph = builder.build();
-    ///
-    /// // Create iterator over sinks
-    /// for node in graph.sinks() {
-    ///     println!("{node:?}");
-    /// }
+    /// let report = graph.validate();
+    /// assert_eq!(report.self_loops, [c]);
+    /// assert_eq!(report.duplicate_edges, [(a, b)]);
+    /// assert_eq!(report.isolated_nodes, [d]);
+    /// assert_eq!(report.cycles, [vec![a, b]]);
     /// # Ok(())
     /// # }
     /// ```
     #[inline]
-    pub fn sinks(&self) -> impl Iterator<Item = usize> {
-        let outgoing = self.topology.outgoing();
-        outgoing.iter().filter(|&node| outgoing[node].is_empty())
+    #[must_use]
+    pub fn validate(&self) -> ValidationReport {
+        ValidationReport::new(&self.topology)
     }
 
-    /// Creates an iterator over the ancestors of the given node.
+    /// Returns whether the graph is a directed acyclic graph (DAG).
+    ///
+    /// Since [`Builder::add_edge`] doesn't prevent cycles, this method allows
+    /// to check whether a graph is acyclic, which is assumed by traversals and
+    /// visitors like [`Graph::paths`]. Use [`Graph::find_cycle`] to obtain one
+    /// of the offending cycles for diagnostics.
     ///
     /// # Examples
     ///
@@ -324,23 +1840,25 @@ impl<T> Graph<T> {
     /// builder.add_edge(a, b, 0)?;
     /// builder.add_edge(b, c, 0)?;
     ///
-    /// // Create graph from builder
+    /// // Create graph from builder and check for cycles
     /// let graph = builder.build();
-    ///
-    /// // Create iterator over ancestors
-    /// for node in graph.ancestors(c) {
-    ///     println!("{node:?}");
-    /// }
+    /// assert!(graph.is_dag());
     /// # Ok(())
     /// # }
     /// ```
     #[inline]
     #[must_use]
-    pub fn ancestors(&self, node: usize) -> Ancestors<'_> {
-        Ancestors::new(&self.topology, node)
+    pub fn is_dag(&self) -> bool {
+        self.find_cycle().is_none()
     }
 
-    /// Creates an iterator over the descendants of the given node.
+    /// Returns a cycle of the graph, if any.
+    ///
+    /// This method runs an iterative depth-first search over outgoing edges,
+    /// keeping track of the nodes on the current path, so deep graphs can't
+    /// overflow the stack. The first edge pointing back to a node on the path
+    /// closes a cycle, which is returned as the sequence of nodes starting at
+    /// that node, where the last node has an edge to the first one.
     ///
     /// # Examples
     ///
@@ -355,27 +1873,80 @@ impl<T> Graph<T> {
     /// let b = builder.add_node("b");
     /// let c = builder.add_node("c");
     ///
-    /// // Create edges between nodes
+    /// // Create edges between nodes, forming a cycle
     /// builder.add_edge(a, b, 0)?;
     /// builder.add_edge(b, c, 0)?;
+    /// builder.add_edge(c, b, 0)?;
     ///
-    /// // Create graph from builder
+    /// // Create graph from builder and find cycle
     /// let graph = builder.build();
-    ///
-    /// // Create iterator over descendants
-    /// for node in graph.descendants(a) {
-    ///     println!("{node:?}");
-    /// }
+    /// assert_eq!(graph.find_cycle(), Some(vec![b, c]));
     /// # Ok(())
     /// # }
     /// ```
-    #[inline]
     #[must_use]
-    pub fn descendants(&self, node: usize) -> Descendants<'_> {
-        Descendants::new(&self.topology, node)
+    pub fn find_cycle(&self) -> Option<Vec<usize>> {
+        let outgoing = self.topology.outgoing();
+
+        // Initialize visited nodes, and nodes on the current path
+        let mut visited = vec![false; self.len()];
+        let mut active = vec![false; self.len()];
+
+        // Start a depth-first search from each node that wasn't visited yet,
+        // and keep the index of the next neighbor for each node on the stack
+        for root in 0..self.len() {
+            if visited[root] {
+                continue;
+            }
+            visited[root] = true;
+            active[root] = true;
+
+            // The stack always contains the current path, so if we encounter a
+            // node that is part of it, the path from that node forms a cycle
+            let mut stack = vec![(root, 0)];
+            while let Some((node, index)) = stack.last_mut() {
+                let node = *node;
+                if let Some(&next) = outgoing[node].get(*index) {
+                    *index += 1;
+                    if active[next] {
+                        let iter = stack.iter().map(|&(node, _)| node);
+                        return Some(iter.skip_while(|&n| n != next).collect());
+                    } else if !visited[next] {
+                        visited[next] = true;
+                        active[next] = true;
+                        stack.push((next, 0));
+                    }
+                } else {
+                    active[node] = false;
+                    stack.pop();
+                }
+            }
+        }
+
+        // No cycle found
+        None
     }
 
-    /// Creates an iterator over all paths between the given nodes.
+    /// Merges nodes with identical data into a single node.
+    ///
+    /// Graphs that are built from overlapping sources might contain multiple
+    /// nodes with the same data. This method collapses them into the first of
+    /// those nodes, and rebuilds the topology, so the edges of all duplicates
+    /// are unioned. Parallel edges that are only created by merging are added
+    /// once, while parallel edges of the original graph are retained. Edges
+    /// between duplicates are dropped, as they'd form loops.
+    ///
+    /// The returned mapping contains the new index for each old index, and the
+    /// relative order of the retained nodes is preserved.
+    ///
+    /// Note that merging might introduce cycles, e.g., when there's a path from
+    /// a node to one of its duplicates, which can be detected with
+    /// [`Graph::find_cycle`] after merging.
+    ///
+    /// # Errors
+    ///
+    /// If a merged node would exceed the maximum of 255 incoming or outgoing
+    /// edges, [`Error::Degree`] is returned, and the graph is left unchanged.
     ///
     /// # Examples
     ///
@@ -388,26 +1959,91 @@ impl<T> Graph<T> {
     /// let mut builder = Graph::builder();
     /// let a = builder.add_node("a");
     /// let b = builder.add_node("b");
-    /// let c = builder.add_node("c");
+    /// let c = builder.add_node("a");
+    /// let d = builder.add_node("d");
     ///
     /// // Create edges between nodes
     /// builder.add_edge(a, b, 0)?;
-    /// builder.add_edge(b, c, 0)?;
+    /// builder.add_edge(c, b, 0)?;
+    /// builder.add_edge(c, d, 0)?;
     ///
-    /// // Create graph from builder
-    /// let graph = builder.build();
-    ///
-    /// // Create iterator over paths
-    /// for path in graph.paths(a, c) {
-    ///     println!("{path:?}");
-    /// }
+    /// // Create graph from builder and merge duplicates
+    /// let mut graph = builder.build();
+    /// let mapping = graph.dedup_nodes()?;
+    /// assert_eq!(mapping, [0, 1, 0, 2]);
+    /// assert_eq!(graph.topology().out_neighbors(0), [1, 2]);
     /// # Ok(())
     /// # }
     /// ```
-    #[inline]
-    #[must_use]
-    pub fn paths(&self, source: usize, target: usize) -> Paths<'_> {
-        Paths::new(&self.topology, source, target)
+    #[allow(clippy::missing_panics_doc)]
+    pub fn dedup_nodes(&mut self) -> Result<Vec<usize>>
+    where
+        T: Eq + Hash,
+    {
+        let mut mapping = Vec::with_capacity(self.data.len());
+
+        // Assign new indices in order of first occurrence, so each duplicate
+        // is mapped onto the index of the first node with identical data
+        let mut indices = HashMap::default();
+        for data in &self.data {
+            let index = indices.len();
+            mapping.push(*indices.entry(data).or_insert(index));
+        }
+
+        // Compute the union of all edges, where each merged edge is added as
+        // often as it occurs between any pair of original nodes, so merging
+        // doesn't create parallel edges, but retains the existing ones
+        let mut counts = HashMap::<_, usize>::default();
+        let mut added = HashMap::<_, usize>::default();
+        let mut edges = Vec::new();
+        for (source, &node) in mapping.iter().enumerate() {
+            for &target in self.topology.out_neighbors(source) {
+                let edge = (node, mapping[target]);
+                if source != target && edge.0 == edge.1 {
+                    continue;
+                }
+
+                // Add edge if it occurs more often than added so far
+                let count = counts.entry((source, target)).or_default();
+                let added = added.entry(edge).or_default();
+                *count += 1;
+                if *count > *added {
+                    *added = *count;
+                    edges.push(edge);
+                }
+            }
+        }
+
+        // Ensure that no merged node exceeds the maximum number of incoming or
+        // outgoing edges, before making any changes to the graph
+        let mut degrees = vec![(0, 0); indices.len()];
+        for &(source, target) in &edges {
+            degrees[source].1 += 1;
+            degrees[target].0 += 1;
+        }
+        let mut iter = degrees.iter();
+        if let Some(node) = iter.position(|&(i, o)| i > 255 || o > 255) {
+            return Err(Error::Degree(node));
+        }
+
+        // Add the first occurrence of each node to the builder, which is the
+        // node whose new index equals the number of nodes added so far
+        let mut builder = Builder::<T, ()>::new();
+        for (node, data) in mem::take(&mut self.data).into_iter().enumerate() {
+            if mapping[node] == builder.len() {
+                builder.add_node(data);
+            }
+        }
+
+        // Add all edges, which can't fail, since all nodes were added to the
+        // builder before, so the mapping is complete
+        for (source, target) in edges {
+            builder.add_edge(source, target, ()).expect("invariant");
+        }
+
+        // Replace graph and return mapping
+        *self = builder.build();
+        Ok(mapping)
     }
 
     /// Creates an iterator over the graph.
@@ -472,6 +2108,77 @@ impl<T> Graph<T> {
     }
 }
 
+impl<T> Graph<Option<T>> {
+    /// Compacts the graph by removing logically deleted nodes.
+    ///
+    /// Since graphs are immutable, nodes can't be removed in place, but only
+    /// be logically deleted by replacing their data with [`None`], which keeps
+    /// all indices stable. This method removes those tombstones, and rebuilds
+    /// the topology over the live nodes, so they occupy a contiguous index
+    /// space. Edges between live nodes are preserved, while edges from or to
+    /// deleted nodes are dropped.
+    ///
+    /// The returned mapping contains the new index for each old index, or
+    /// [`None`], if the node was deleted. Relative order is retained.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::error::Error;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use zrx_graph::Graph;
+    ///
+    /// // Create graph builder and add nodes
+    /// let mut builder = Graph::builder();
+    /// let a = builder.add_node("a");
+    /// let b = builder.add_node("b");
+    /// let c = builder.add_node("c");
+    ///
+    /// // Create edges between nodes
+    /// builder.add_edge(a, b, 0)?;
+    /// builder.add_edge(a, c, 0)?;
+    ///
+    /// // Create graph from builder and delete node
+    /// let graph = builder.build().map(Some);
+    /// let mut data = graph.iter().cloned().collect::<Vec<_>>();
+    /// data[b] = None;
+    /// let mut graph = graph.with_data(data);
+    ///
+    /// // Compact graph
+    /// let mapping = graph.reindex();
+    /// assert_eq!(mapping, [Some(0), None, Some(1)]);
+    /// assert_eq!(graph.len(), 2);
+    /// assert_eq!(graph.topology().out_neighbors(0), [1]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[allow(clippy::missing_panics_doc)]
+    pub fn reindex(&mut self) -> Vec<Option<usize>> {
+        let mut mapping = Vec::with_capacity(self.data.len());
+        let mut builder = Builder::<Option<T>, ()>::new();
+
+        // Add all live nodes to the builder, recording the new index
+        for data in mem::take(&mut self.data) {
+            mapping.push(data.is_some().then(|| builder.add_node(data)));
+        }
+
+        // Add all edges between live nodes, which can't fail, since all nodes
+        // were added to the builder before, so the mapping is complete
+        for (source, &node) in mapping.iter().enumerate() {
+            let Some(node) = node else { continue };
+            for &target in self.topology.out_neighbors(source) {
+                if let Some(target) = mapping[target] {
+                    builder.add_edge(node, target, ()).expect("invariant");
+                }
+            }
+        }
+
+        // Replace graph and return mapping
+        *self = builder.build();
+        mapping
+    }
+}
+
 // ----------------------------------------------------------------------------
 // Trait implementations
 // ----------------------------------------------------------------------------
@@ -562,6 +2269,48 @@ impl<T> IndexMut<usize> for Graph<T> {
 
 // ----------------------------------------------------------------------------
 
+impl<T> IntoIterator for Graph<T> {
+    type Item = T;
+    type IntoIter = vec::IntoIter<T>;
+
+    /// Creates a consuming iterator over the graph.
+    ///
+    /// This iterator yields the data `T` associated with each node in order of
+    /// the node indices, which allows to move the data out of the graph.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::error::Error;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use zrx_graph::Graph;
+    ///
+    /// // Create graph builder and add nodes
+    /// let mut builder = Graph::builder();
+    /// let a = builder.add_node("a");
+    /// let b = builder.add_node("b");
+    /// let c = builder.add_node("c");
+    ///
+    /// // Create edges between nodes
+    /// builder.add_edge(a, b, 0)?;
+    /// builder.add_edge(b, c, 0)?;
+    ///
+    /// // Create graph from builder
+    /// let graph = builder.build();
+    ///
+    /// // Create consuming iterator over data
+    /// for data in graph {
+    ///     println!("{data:?}");
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.data.into_iter()
+    }
+}
+
 impl<'a, T> IntoIterator for &'a Graph<T> {
     type Item = &'a T;
     type IntoIter = Iter<'a, T>;
@@ -606,3 +2355,411 @@ impl<'a, T> IntoIterator for &'a Graph<T> {
         self.iter()
     }
 }
+
+// ----------------------------------------------------------------------------
+// Functions
+// ----------------------------------------------------------------------------
+
+/// Collects all nodes reachable from the given node into a bitset, following
+/// the edges of the given adjacency list, which excludes the node itself.
+#[cfg(feature = "fixedbitset")]
+fn collect_bitset(adjacency: &Adjacency, node: usize) -> FixedBitSet {
+    let mut bitset = FixedBitSet::with_capacity(adjacency.len());
+
+    // Perform a depth-first search, using the bitset itself to track visited
+    // nodes, so we don't need to maintain a separate set like the visitors
+    let mut stack = Vec::from([node]);
+    while let Some(node) = stack.pop() {
+        for &next in &adjacency[node] {
+            if !bitset.put(next) {
+                stack.push(next);
+            }
+        }
+    }
+
+    // Return bitset
+    bitset
+}
+
+// ----------------------------------------------------------------------------
+// Tests
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+
+    #[cfg(feature = "rayon")]
+    mod par_map {
+        use crate::graph::Graph;
+
+        #[test]
+        fn handles_like_map() {
+            let mut builder = Graph::builder();
+            let nodes: Vec<_> =
+                (0..1024).map(|n| builder.add_node(n)).collect();
+            for window in nodes.windows(2) {
+                builder.add_edge(window[0], window[1], ()).unwrap();
+            }
+            let graph = builder.build();
+
+            // Ensure data and topology are equal to the serial result
+            let f = |n: i32| n.wrapping_mul(31) ^ 7;
+            let serial = graph.map_ref(|&n| f(n));
+            let parallel = graph.clone().par_map(f);
+            assert!(serial.iter().eq(parallel.iter()));
+            for node in 0..graph.len() {
+                assert_eq!(
+                    serial.topology().out_neighbors(node),
+                    parallel.topology().out_neighbors(node)
+                );
+            }
+        }
+    }
+
+    mod ancestors_until {
+        use ahash::HashSet;
+
+        use crate::graph::Graph;
+
+        #[test]
+        fn excludes_ancestors_of_stop_nodes() {
+            let mut builder = Graph::builder();
+            let a = builder.add_node("a");
+            let b = builder.add_node("b");
+            let c = builder.add_node("c");
+            let d = builder.add_node("d");
+            builder.add_edge(a, b, ()).unwrap();
+            builder.add_edge(b, d, ()).unwrap();
+            builder.add_edge(c, d, ()).unwrap();
+            let graph = builder.build();
+
+            // Ensure stop node is yielded, but its ancestors are not
+            let stop = HashSet::from_iter([b]);
+            let mut ancestors: Vec<_> =
+                graph.ancestors_until(d, &stop).collect();
+            ancestors.sort_unstable();
+            assert_eq!(ancestors, [b, c]);
+
+            // Ensure empty stop set yields all ancestors
+            let stop = HashSet::default();
+            let mut ancestors: Vec<_> =
+                graph.ancestors_until(d, &stop).collect();
+            ancestors.sort_unstable();
+            assert_eq!(ancestors, [a, b, c]);
+        }
+    }
+
+    mod dedup_nodes {
+        use crate::graph::Graph;
+
+        #[test]
+        fn unions_edges_of_duplicates() {
+            let mut builder = Graph::builder();
+            let nodes: Vec<_> = ["a", "b", "c", "b", "d"]
+                .into_iter()
+                .map(|data| builder.add_node(data))
+                .collect();
+            for (source, target) in [(0, 1), (1, 2), (0, 3), (3, 4), (1, 3)] {
+                builder.add_edge(nodes[source], nodes[target], ()).unwrap();
+            }
+            let mut graph = builder.build();
+
+            // Merge both nodes with data "b"
+            let mapping = graph.dedup_nodes().unwrap();
+            assert_eq!(mapping, [0, 1, 2, 1, 3]);
+
+            // Ensure data is retained in order of first occurrence
+            let data: Vec<_> = graph.iter().copied().collect();
+            assert_eq!(data, ["a", "b", "c", "d"]);
+
+            // Ensure edges are unioned, without parallel edges or loops
+            let edges: Vec<_> = (0..graph.len())
+                .flat_map(|node| {
+                    let iter = graph.topology().out_neighbors(node).iter();
+                    iter.map(move |&target| (node, target))
+                })
+                .collect();
+            assert_eq!(edges, [(0, 1), (1, 2), (1, 3)]);
+        }
+
+        #[test]
+        fn retains_parallel_edges() {
+            let mut builder = Graph::builder();
+            let nodes: Vec<_> = ["a", "b", "a"]
+                .into_iter()
+                .map(|data| builder.add_node(data))
+                .collect();
+            for (source, target) in [(0, 1), (0, 1), (2, 1)] {
+                builder.add_edge(nodes[source], nodes[target], ()).unwrap();
+            }
+            let mut graph = builder.build();
+
+            // Ensure only edges created by merging are deduplicated
+            graph.dedup_nodes().unwrap();
+            assert_eq!(graph.topology().out_neighbors(0), [1, 1]);
+        }
+
+        #[test]
+        fn rejects_exceeding_degree() {
+            let mut builder = Graph::builder();
+            let a = builder.add_node(0);
+            let b = builder.add_node(0);
+            for data in 1..=400 {
+                let node = builder.add_node(data);
+                let source = if data <= 200 { a } else { b };
+                builder.add_edge(source, node, ()).unwrap();
+            }
+            let mut graph = builder.build();
+
+            // Ensure graph is left unchanged
+            assert!(graph.dedup_nodes().is_err());
+            assert_eq!(graph.len(), 402);
+        }
+    }
+
+    mod impact {
+        use crate::graph::Graph;
+
+        #[test]
+        fn is_highest_for_source_of_chain() {
+            let mut builder = Graph::builder();
+            let nodes = (0..5).map(|n| builder.add_node(n)).collect::<Vec<_>>();
+            for pair in nodes.windows(2) {
+                builder.add_edge(pair[0], pair[1], ()).unwrap();
+            }
+            let graph = builder.build();
+
+            // Ensure impact decreases along the chain
+            let impact = nodes.iter().map(|&node| graph.impact(node));
+            assert_eq!(impact.collect::<Vec<_>>(), [4, 3, 2, 1, 0]);
+        }
+    }
+
+    mod has_edge {
+        use crate::graph::Graph;
+
+        #[test]
+        fn handles_present_absent_and_reversed() {
+            let mut builder = Graph::builder();
+            let a = builder.add_node("a");
+            let b = builder.add_node("b");
+            let c = builder.add_node("c");
+            builder.add_edge(a, b, ()).unwrap();
+            builder.add_edge(a, b, ()).unwrap();
+            builder.add_edge(b, c, ()).unwrap();
+            let graph = builder.build();
+
+            // Ensure present edges are found, including duplicates
+            assert!(graph.has_edge(a, b));
+            assert!(graph.has_edge(b, c));
+            assert_eq!(graph.topology().out_neighbors(a), [b, b]);
+
+            // Ensure absent and reversed edges are not found
+            assert!(!graph.has_edge(a, c));
+            assert!(!graph.has_edge(a, a));
+            assert!(!graph.has_edge(b, a));
+            assert!(!graph.has_edge(c, b));
+        }
+    }
+
+    mod reverse {
+        use crate::graph::Graph;
+
+        #[test]
+        fn flips_topology() {
+            let mut builder = Graph::builder();
+            let a = builder.add_node("a");
+            let b = builder.add_node("b");
+            let c = builder.add_node("c");
+            let d = builder.add_node("d");
+            builder.add_edge(a, b, ()).unwrap();
+            builder.add_edge(a, c, ()).unwrap();
+            builder.add_edge(b, d, ()).unwrap();
+            builder.add_edge(c, d, ()).unwrap();
+            let graph = builder.build();
+            let sources = graph.sources().collect::<Vec<_>>();
+            let sinks = graph.sinks().collect::<Vec<_>>();
+
+            // Ensure sources and sinks are swapped, and data is retained
+            let reversed = graph.clone().reverse();
+            assert_eq!(reversed.sources().collect::<Vec<_>>(), sinks);
+            assert_eq!(reversed.sinks().collect::<Vec<_>>(), sources);
+            assert!(reversed.iter().eq(graph.iter()));
+
+            // Ensure edges and distances are reversed
+            assert_eq!(reversed.topology().out_neighbors(d), [b, c]);
+            assert_eq!(reversed.topology().distance()[d][a], 2);
+            assert_eq!(reversed.topology().distance()[a][d], u8::MAX);
+            assert_eq!(reversed.ancestors_sorted(a), [b, c, d]);
+
+            // Ensure reversing twice restores the topology
+            let restored = reversed.reverse();
+            assert_eq!(
+                restored.topology().checksum(),
+                graph.topology().checksum()
+            );
+        }
+    }
+
+    mod find_cycle {
+        use crate::graph::Graph;
+
+        #[test]
+        fn returns_none_for_dag() {
+            let mut builder = Graph::builder();
+            let a = builder.add_node("a");
+            let b = builder.add_node("b");
+            let c = builder.add_node("c");
+            builder.add_edge(a, b, ()).unwrap();
+            builder.add_edge(a, c, ()).unwrap();
+            builder.add_edge(b, c, ()).unwrap();
+            let graph = builder.build();
+            assert_eq!(graph.find_cycle(), None);
+            assert!(graph.is_dag());
+        }
+
+        #[test]
+        fn returns_offending_cycle() {
+            let mut builder = Graph::builder();
+            let a = builder.add_node("a");
+            let b = builder.add_node("b");
+            let c = builder.add_node("c");
+            let d = builder.add_node("d");
+            builder.add_edge(a, b, ()).unwrap();
+            builder.add_edge(b, c, ()).unwrap();
+            builder.add_edge(c, d, ()).unwrap();
+            builder.add_edge(d, b, ()).unwrap();
+            let graph = builder.build();
+            assert_eq!(graph.find_cycle(), Some(vec![b, c, d]));
+            assert!(!graph.is_dag());
+        }
+
+        #[test]
+        fn returns_self_loop() {
+            let mut builder = Graph::builder();
+            let a = builder.add_node("a");
+            let b = builder.add_node("b");
+            builder.add_edge(a, b, ()).unwrap();
+            builder.add_edge(b, b, ()).unwrap();
+            let graph = builder.build();
+            assert_eq!(graph.find_cycle(), Some(vec![b]));
+        }
+    }
+
+    mod find_descendant {
+        use std::cell::Cell;
+
+        use crate::graph::Graph;
+
+        #[test]
+        fn stops_at_first_match() {
+            let mut builder = Graph::builder();
+            let nodes: Vec<_> = (0..8).map(|n| builder.add_node(n)).collect();
+            for window in nodes.windows(2) {
+                builder.add_edge(window[0], window[1], ()).unwrap();
+            }
+            let graph = builder.build();
+
+            // Ensure only descendants up to the match are visited
+            let calls = Cell::new(0);
+            let node = graph.find_descendant(nodes[0], |_, &data| {
+                calls.set(calls.get() + 1);
+                data == 3
+            });
+            assert_eq!(node, Some(nodes[3]));
+            assert_eq!(calls.get(), 3);
+
+            // Ensure all descendants are visited without a match
+            calls.set(0);
+            let node = graph.find_descendant(nodes[0], |_, _| {
+                calls.set(calls.get() + 1);
+                false
+            });
+            assert_eq!(node, None);
+            assert_eq!(calls.get(), 7);
+        }
+    }
+
+    mod flat_map_nodes {
+        use crate::graph::{Error, Expansion, Graph};
+
+        #[test]
+        fn rewires_external_edges_to_ports() {
+            let mut builder = Graph::builder();
+            let nodes: Vec<_> = (0..4).map(|n| builder.add_node(n)).collect();
+            for (source, target) in [(0, 1), (0, 2), (1, 3), (2, 3)] {
+                builder.add_edge(nodes[source], nodes[target], ()).unwrap();
+            }
+            let graph = builder.build();
+
+            // Expand node 1 into two chained nodes
+            let graph = graph
+                .flat_map_nodes(|node, data| {
+                    if node == 1 {
+                        Expansion::chain([10, 11])
+                    } else {
+                        Expansion::from(data)
+                    }
+                })
+                .unwrap();
+            let data: Vec<_> = graph.iter().copied().collect();
+            assert_eq!(data, [0, 10, 11, 2, 3]);
+
+            // Ensure internal edges are kept and external edges are rewired
+            let edges: Vec<_> = (0..graph.len())
+                .flat_map(|node| {
+                    let iter = graph.topology().out_neighbors(node).iter();
+                    iter.map(move |&target| (node, target))
+                })
+                .collect();
+            assert_eq!(edges, [(0, 1), (0, 3), (1, 2), (2, 4), (3, 4)]);
+        }
+
+        #[test]
+        fn rejects_invalid_ports() {
+            let graph = Graph::builder::<()>().node(0).node(1).build();
+            let result = graph.flat_map_nodes(|_, data| {
+                Expansion::new([data]).with_ports(0, 1)
+            });
+            assert!(matches!(result, Err(Error::NotFound(1))));
+        }
+    }
+
+    mod reindex {
+        use crate::graph::Graph;
+
+        #[test]
+        fn handles_deletions() {
+            let mut builder = Graph::builder();
+            let nodes: Vec<_> = (0..6).map(|n| builder.add_node(n)).collect();
+            for (source, target) in
+                [(0, 1), (0, 2), (1, 3), (2, 3), (3, 4), (4, 5), (2, 5)]
+            {
+                builder.add_edge(nodes[source], nodes[target], ()).unwrap();
+            }
+            let graph = builder.build();
+
+            // Delete nodes 1 and 4, and compact the graph
+            let data = graph.iter().map(|&n| (n != 1 && n != 4).then_some(n));
+            let mut graph = graph.with_data(data.collect());
+            let mapping = graph.reindex();
+            assert_eq!(
+                mapping,
+                [Some(0), None, Some(1), Some(2), None, Some(3)]
+            );
+
+            // Ensure indices are contiguous and data is retained
+            let data: Vec<_> = graph.iter().copied().collect();
+            assert_eq!(data, [Some(0), Some(2), Some(3), Some(5)]);
+
+            // Ensure edges between live nodes are preserved
+            let edges: Vec<_> = (0..graph.len())
+                .flat_map(|node| {
+                    let iter = graph.topology().out_neighbors(node).iter();
+                    iter.map(move |&target| (node, target))
+                })
+                .collect();
+            assert_eq!(edges, [(0, 1), (1, 2), (1, 3)]);
+        }
+    }
+}
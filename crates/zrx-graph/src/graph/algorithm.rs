@@ -26,7 +26,26 @@
 //! Graph algorithms.
 
 mod ancestor;
+mod articulation;
+mod descendant;
+mod dominator;
+mod feedback;
+mod isomorphism;
 mod path;
-
-pub use ancestor::lowest_common_ancestor;
-pub use path::shortest_path_length;
+mod stage;
+mod topological;
+
+pub use ancestor::{
+    common_ancestors, lowest_common_ancestor, lowest_common_ancestor_pair,
+};
+pub use articulation::articulation_points;
+pub use descendant::common_descendants;
+pub use dominator::dominators;
+pub use feedback::feedback_arc_set;
+pub use isomorphism::is_isomorphic;
+pub use path::{
+    longest_path_length, path_count, shortest_path_length,
+    weighted_shortest_path_length,
+};
+pub use stage::{generations, stages};
+pub use topological::topological_sort_stable;
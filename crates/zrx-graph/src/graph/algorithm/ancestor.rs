@@ -36,6 +36,58 @@ use super::path::shortest_path_length;
 // Functions
 // ----------------------------------------------------------------------------
 
+/// Returns the common ancestors of the given nodes in the graph.
+///
+/// The returned set is the intersection of the ancestors of all given nodes,
+/// which is empty if no nodes are given, or if they share no ancestors.
+///
+/// # Examples
+///
+/// ```
+/// # use std::error::Error;
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// use zrx_graph::algorithm::common_ancestors;
+/// use zrx_graph::Graph;
+///
+/// // Create graph builder and add nodes
+/// let mut builder = Graph::builder();
+/// let a = builder.add_node("a");
+/// let b = builder.add_node("b");
+/// let c = builder.add_node("c");
+/// let d = builder.add_node("d");
+/// let e = builder.add_node("e");
+///
+/// // Create edges between nodes
+/// builder.add_edge(a, b, 0)?;
+/// builder.add_edge(b, d, 0)?;
+/// builder.add_edge(b, e, 0)?;
+/// builder.add_edge(c, d, 0)?;
+/// builder.add_edge(c, e, 0)?;
+///
+/// // Create graph from builder
+/// let graph = builder.build();
+///
+/// // Obtain common ancestors
+/// let ancestors = common_ancestors(&graph, [d, e]);
+/// assert_eq!(ancestors.len(), 3);
+/// assert!([a, b, c].iter().all(|node| ancestors.contains(node)));
+/// # Ok(())
+/// # }
+/// ```
+pub fn common_ancestors<T, I>(graph: &Graph<T>, nodes: I) -> HashSet<usize>
+where
+    I: IntoIterator<Item = usize>,
+{
+    let topology = graph.topology();
+
+    // Collect all ancestors for each node, and compute the intersection of all
+    // sets of ancestors, resulting in a set of all common ancestors
+    let iter = nodes.into_iter();
+    iter.map(|node| Ancestors::new(topology, node).collect::<HashSet<_>>())
+        .reduce(|a, b| a.intersection(&b).copied().collect())
+        .unwrap_or_default()
+}
+
 /// Returns the lowest common ancestor of the given nodes in the graph.
 ///
 /// # Examples
@@ -70,20 +122,14 @@ pub fn lowest_common_ancestor<T, I>(graph: &Graph<T>, nodes: I) -> Option<usize>
 where
     I: IntoIterator<Item = usize>,
 {
-    let topology = graph.topology();
-
     // If there are fewer than two nodes, we return immediately
     let nodes = nodes.into_iter().collect::<Vec<_>>();
     if nodes.len() < 2 {
         return None;
     }
 
-    // Collect all ancestors for each node, and compute the intersection of all
-    // sets of ancestors, resulting in a set of all common ancestors
-    let iter = nodes.iter();
-    let ancestors = iter
-        .map(|&node| Ancestors::new(topology, node).collect::<HashSet<_>>())
-        .reduce(|a, b| a.intersection(&b).copied().collect())?;
+    // Compute the set of all common ancestors of the given nodes
+    let ancestors = common_ancestors(graph, nodes.iter().copied());
 
     // Find the ancestor with the shortest path to any of the nodes, or return
     // nothing if the intersection is empty, as there is no common ancestor
@@ -93,3 +139,149 @@ where
             .min()
     })
 }
+
+/// Returns the lowest common ancestor of the given pair of nodes in the graph.
+///
+/// This is a specialization of [`lowest_common_ancestor`] for the common case
+/// of two nodes, which doesn't materialize the full sets of ancestors. Instead,
+/// it walks the ancestors of both nodes simultaneously in breadth-first order,
+/// and checks whether each ancestor can reach the other node, which is an O(1)
+/// lookup in the [`Distance`][] matrix. The first ancestor found that way has
+/// the shortest path to any of both nodes, so the search stops right there.
+///
+/// Both functions agree on the distance of the lowest common ancestor to the
+/// given nodes, but might return different ancestors in case of ties.
+///
+/// [`Distance`]: crate::graph::topology::Distance
+///
+/// # Examples
+///
+/// ```
+/// # use std::error::Error;
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// use zrx_graph::algorithm::lowest_common_ancestor_pair;
+/// use zrx_graph::Graph;
+///
+/// // Create graph builder and add nodes
+/// let mut builder = Graph::builder();
+/// let a = builder.add_node("a");
+/// let b = builder.add_node("b");
+/// let c = builder.add_node("c");
+///
+/// // Create edges between nodes
+/// builder.add_edge(a, b, 0)?;
+/// builder.add_edge(b, c, 0)?;
+/// builder.add_edge(a, c, 0)?;
+///
+/// // Create graph from builder
+/// let graph = builder.build();
+///
+/// // Obtain lowest common ancestor
+/// let ancestor = lowest_common_ancestor_pair(&graph, b, c);
+/// assert_eq!(ancestor, Some(a));
+/// # Ok(())
+/// # }
+/// ```
+#[must_use]
+pub fn lowest_common_ancestor_pair<T>(
+    graph: &Graph<T>, a: usize, b: usize,
+) -> Option<usize> {
+    let incoming = graph.topology().incoming();
+    let distance = graph.topology().distance();
+
+    // Initialize the frontiers and visited sets for both nodes, as well as the
+    // respective other node, which each ancestor must be able to reach
+    let mut frontiers = [vec![a], vec![b]];
+    let mut visited = [HashSet::default(), HashSet::default()];
+    let targets = [b, a];
+
+    // Expand both frontiers level by level, alternating between both nodes, so
+    // the first ancestor that can reach the other node is the lowest one. Note
+    // that the other node itself is not considered to be a common ancestor.
+    while frontiers.iter().any(|frontier| !frontier.is_empty()) {
+        for (side, &target) in targets.iter().enumerate() {
+            let mut next = Vec::new();
+            for &node in &frontiers[side] {
+                for &ancestor in &incoming[node] {
+                    if !visited[side].insert(ancestor) {
+                        continue;
+                    }
+
+                    // Return ancestor if it's also an ancestor of the target
+                    if ancestor != target
+                        && distance[ancestor][target] != u8::MAX
+                    {
+                        return Some(ancestor);
+                    }
+                    next.push(ancestor);
+                }
+            }
+            frontiers[side] = next;
+        }
+    }
+
+    // No common ancestor found
+    None
+}
+
+// ----------------------------------------------------------------------------
+// Tests
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+
+    mod lowest_common_ancestor_pair {
+        use crate::graph::algorithm::{
+            lowest_common_ancestor, lowest_common_ancestor_pair,
+            shortest_path_length,
+        };
+        use crate::graph::Graph;
+
+        /// Creates a random DAG with edges only from lower to higher indices,
+        /// using a linear congruential generator to remain deterministic.
+        fn random_dag(seed: u64, nodes: usize) -> Graph<usize> {
+            let mut state = seed;
+            let mut builder = Graph::builder();
+            for node in 0..nodes {
+                builder.add_node(node);
+            }
+            for source in 0..nodes {
+                for target in source + 1..nodes {
+                    state = state
+                        .wrapping_mul(6_364_136_223_846_793_005)
+                        .wrapping_add(1_442_695_040_888_963_407);
+                    if state >> 62 == 0 {
+                        builder.add_edge(source, target, ()).unwrap();
+                    }
+                }
+            }
+            builder.build()
+        }
+
+        /// Returns the distance of an ancestor to the closest of both nodes.
+        fn rank(graph: &Graph<usize>, x: usize, a: usize, b: usize) -> usize {
+            let a = shortest_path_length(graph, x, a).unwrap();
+            let b = shortest_path_length(graph, x, b).unwrap();
+            a.min(b)
+        }
+
+        #[test]
+        fn agrees_with_lowest_common_ancestor() {
+            for seed in 0..64 {
+                let graph = random_dag(seed, 12);
+                for a in 0..graph.len() {
+                    for b in 0..graph.len() {
+                        let pair = lowest_common_ancestor_pair(&graph, a, b);
+                        let all = lowest_common_ancestor(&graph, [a, b]);
+                        assert_eq!(
+                            pair.map(|x| rank(&graph, x, a, b)),
+                            all.map(|x| rank(&graph, x, a, b)),
+                            "seed {seed}, nodes {a} and {b}",
+                        );
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,153 @@
+// Copyright (c) Zensical LLC <https://zensical.org>
+
+// SPDX-License-Identifier: MIT
+// Third-party contributions licensed under CLA
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+
+// ----------------------------------------------------------------------------
+
+//! Graph algorithms related to articulation points.
+
+use crate::graph::Graph;
+
+// ----------------------------------------------------------------------------
+// Functions
+// ----------------------------------------------------------------------------
+
+/// Returns the articulation points of the graph.
+///
+/// An articulation point is a node whose removal disconnects the graph, i.e.,
+/// increases the number of weakly connected components, which makes it a single
+/// point of failure. Edge directions are ignored, and the articulation points
+/// are computed with an iterative depth-first search using low-link values, so
+/// deep graphs can't overflow the stack. Nodes are returned in ascending order.
+///
+/// # Examples
+///
+/// ```
+/// # use std::error::Error;
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// use zrx_graph::algorithm::articulation_points;
+/// use zrx_graph::Graph;
+///
+/// // Create graph builder and add nodes
+/// let mut builder = Graph::builder();
+/// let a = builder.add_node("a");
+/// let b = builder.add_node("b");
+/// let c = builder.add_node("c");
+/// let d = builder.add_node("d");
+/// let e = builder.add_node("e");
+///
+/// // Create edges between nodes
+/// builder.add_edge(a, b, 0)?;
+/// builder.add_edge(a, c, 0)?;
+/// builder.add_edge(b, c, 0)?;
+/// builder.add_edge(c, d, 0)?;
+/// builder.add_edge(c, e, 0)?;
+/// builder.add_edge(d, e, 0)?;
+///
+/// // Create graph from builder
+/// let graph = builder.build();
+///
+/// // Obtain articulation points
+/// let nodes = articulation_points(&graph);
+/// assert_eq!(nodes, [c]);
+/// # Ok(())
+/// # }
+/// ```
+#[must_use]
+pub fn articulation_points<T>(graph: &Graph<T>) -> Vec<usize> {
+    let outgoing = graph.topology().outgoing();
+    let incoming = graph.topology().incoming();
+
+    // Create the undirected view of the graph, as articulation points are
+    // defined in terms of connectivity, which doesn't consider directions
+    let neighbors = (0..graph.len())
+        .map(|node| {
+            let iter = outgoing[node].iter().chain(&incoming[node]);
+            iter.copied().collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+
+    // Initialize discovery times, low-link values, parents and results
+    let mut discovery = vec![usize::MAX; graph.len()];
+    let mut low = vec![usize::MAX; graph.len()];
+    let mut parent = vec![usize::MAX; graph.len()];
+    let mut points = vec![false; graph.len()];
+    let mut time = 0;
+
+    // Start a depth-first search from each node that wasn't visited yet, and
+    // keep the index of the next neighbor to visit for each node on the stack
+    for root in 0..graph.len() {
+        if discovery[root] != usize::MAX {
+            continue;
+        }
+        discovery[root] = time;
+        low[root] = time;
+        time += 1;
+
+        // Count the children of the root, as it's an articulation point only
+        // if it has more than one child in the depth-first search tree
+        let mut children = 0;
+        let mut stack = vec![(root, 0)];
+        while let Some((node, index)) = stack.last_mut() {
+            let node = *node;
+            if let Some(&next) = neighbors[node].get(*index) {
+                *index += 1;
+
+                // Descend into unvisited neighbors, and update the low-link
+                // value of the node for all back edges to visited neighbors
+                if discovery[next] == usize::MAX {
+                    parent[next] = node;
+                    discovery[next] = time;
+                    low[next] = time;
+                    time += 1;
+                    if node == root {
+                        children += 1;
+                    }
+                    stack.push((next, 0));
+                } else if next != parent[node] {
+                    low[node] = low[node].min(discovery[next]);
+                }
+            } else {
+                stack.pop();
+
+                // Propagate the low-link value to the parent, which is an
+                // articulation point if the node can't reach above it
+                if let Some(&(prior, _)) = stack.last() {
+                    low[prior] = low[prior].min(low[node]);
+                    if prior != root && low[node] >= discovery[prior] {
+                        points[prior] = true;
+                    }
+                }
+            }
+        }
+
+        // Check whether the root is an articulation point
+        if children > 1 {
+            points[root] = true;
+        }
+    }
+
+    // Return articulation points
+    let iter = points.into_iter().enumerate();
+    iter.filter_map(|(node, point)| point.then_some(node))
+        .collect()
+}
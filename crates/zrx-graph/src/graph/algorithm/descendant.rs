@@ -0,0 +1,87 @@
+// Copyright (c) Zensical LLC <https://zensical.org>
+
+// SPDX-License-Identifier: MIT
+// Third-party contributions licensed under CLA
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+
+// ----------------------------------------------------------------------------
+
+//! Graph algorithms related to descendants.
+
+use ahash::HashSet;
+
+use crate::graph::visitor::Descendants;
+use crate::graph::Graph;
+
+// ----------------------------------------------------------------------------
+// Functions
+// ----------------------------------------------------------------------------
+
+/// Returns the common descendants of the given nodes in the graph.
+///
+/// The returned set is the intersection of the descendants of all given nodes,
+/// which is empty if no nodes are given, or if they share no descendants.
+///
+/// # Examples
+///
+/// ```
+/// # use std::error::Error;
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// use zrx_graph::algorithm::common_descendants;
+/// use zrx_graph::Graph;
+///
+/// // Create graph builder and add nodes
+/// let mut builder = Graph::builder();
+/// let a = builder.add_node("a");
+/// let b = builder.add_node("b");
+/// let c = builder.add_node("c");
+/// let d = builder.add_node("d");
+/// let e = builder.add_node("e");
+///
+/// // Create edges between nodes
+/// builder.add_edge(a, c, 0)?;
+/// builder.add_edge(a, d, 0)?;
+/// builder.add_edge(b, c, 0)?;
+/// builder.add_edge(b, d, 0)?;
+/// builder.add_edge(d, e, 0)?;
+///
+/// // Create graph from builder
+/// let graph = builder.build();
+///
+/// // Obtain common descendants
+/// let descendants = common_descendants(&graph, [a, b]);
+/// assert_eq!(descendants.len(), 3);
+/// assert!([c, d, e].iter().all(|node| descendants.contains(node)));
+/// # Ok(())
+/// # }
+/// ```
+pub fn common_descendants<T, I>(graph: &Graph<T>, nodes: I) -> HashSet<usize>
+where
+    I: IntoIterator<Item = usize>,
+{
+    let topology = graph.topology();
+
+    // Collect all descendants for each node, and compute the intersection of
+    // all sets of descendants, resulting in a set of all common descendants
+    let iter = nodes.into_iter();
+    iter.map(|node| Descendants::new(topology, node).collect::<HashSet<_>>())
+        .reduce(|a, b| a.intersection(&b).copied().collect())
+        .unwrap_or_default()
+}
@@ -0,0 +1,147 @@
+// Copyright (c) Zensical LLC <https://zensical.org>
+
+// SPDX-License-Identifier: MIT
+// Third-party contributions licensed under CLA
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+
+// ----------------------------------------------------------------------------
+
+//! Graph algorithms related to dominators.
+
+use crate::graph::Graph;
+
+// ----------------------------------------------------------------------------
+// Functions
+// ----------------------------------------------------------------------------
+
+/// Returns the immediate dominator of each node reachable from the root.
+///
+/// A node dominates another node if every path from the root to the latter
+/// passes through the former, which means it must always run before, no matter
+/// which path is taken. The immediate dominator is the closest of those nodes,
+/// so the result forms the dominator tree of the graph, where the root is its
+/// own immediate dominator, and nodes not reachable from the root are [`None`].
+///
+/// Dominators are computed with the algorithm by Cooper, Harvey and Kennedy,
+/// which iterates over the nodes in reverse postorder, intersecting the paths
+/// of the predecessors in the dominator tree until a fixed point is reached.
+///
+/// # Panics
+///
+/// Panics if the root does not exist.
+///
+/// # Examples
+///
+/// ```
+/// # use std::error::Error;
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// use zrx_graph::algorithm::dominators;
+/// use zrx_graph::Graph;
+///
+/// // Create graph builder and add nodes
+/// let mut builder = Graph::builder();
+/// let a = builder.add_node("a");
+/// let b = builder.add_node("b");
+/// let c = builder.add_node("c");
+/// let d = builder.add_node("d");
+/// let e = builder.add_node("e");
+///
+/// // Create edges between nodes, forming a diamond
+/// builder.add_edge(a, b, 0)?;
+/// builder.add_edge(a, c, 0)?;
+/// builder.add_edge(b, d, 0)?;
+/// builder.add_edge(c, d, 0)?;
+///
+/// // Create graph from builder
+/// let graph = builder.build();
+///
+/// // Obtain immediate dominators
+/// let idom = dominators(&graph, a);
+/// assert_eq!(idom, [Some(a), Some(a), Some(a), Some(a), None]);
+/// # Ok(())
+/// # }
+/// ```
+#[must_use]
+pub fn dominators<T>(graph: &Graph<T>, root: usize) -> Vec<Option<usize>> {
+    let outgoing = graph.topology().outgoing();
+    let incoming = graph.topology().incoming();
+
+    // Compute the postorder of all nodes reachable from the root, using an
+    // iterative depth-first search, so deep graphs can't overflow the stack
+    let mut position = vec![usize::MAX; graph.len()];
+    let mut visited = vec![false; graph.len()];
+    let mut order = Vec::new();
+    let mut stack = vec![(root, 0)];
+    visited[root] = true;
+    while let Some((node, index)) = stack.last_mut() {
+        let node = *node;
+        if let Some(&next) = outgoing[node].get(*index) {
+            *index += 1;
+            if !visited[next] {
+                visited[next] = true;
+                stack.push((next, 0));
+            }
+        } else {
+            stack.pop();
+            position[node] = order.len();
+            order.push(node);
+        }
+    }
+
+    // Iterate over all nodes in reverse postorder, skipping the root, and set
+    // the immediate dominator to the intersection of all processed ancestors,
+    // repeating until no immediate dominator changes anymore
+    let mut idom = vec![None; graph.len()];
+    idom[root] = Some(root);
+    let mut changed = true;
+    while changed {
+        changed = false;
+        for &node in order.iter().rev().skip(1) {
+            let mut iter = incoming[node]
+                .iter()
+                .copied()
+                .filter(|&ancestor| idom[ancestor].is_some());
+
+            // Intersect the paths to the root of all processed ancestors, of
+            // which there's at least one, since the node is reachable
+            let Some(first) = iter.next() else { continue };
+            let next = iter.fold(first, |mut a, mut b| {
+                while a != b {
+                    while position[a] < position[b] {
+                        a = idom[a].unwrap_or(root);
+                    }
+                    while position[b] < position[a] {
+                        b = idom[b].unwrap_or(root);
+                    }
+                }
+                a
+            });
+
+            // Update immediate dominator, if it changed
+            if idom[node] != Some(next) {
+                idom[node] = Some(next);
+                changed = true;
+            }
+        }
+    }
+
+    // Return immediate dominators
+    idom
+}
@@ -0,0 +1,220 @@
+// Copyright (c) Zensical LLC <https://zensical.org>
+
+// SPDX-License-Identifier: MIT
+// Third-party contributions licensed under CLA
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+
+// ----------------------------------------------------------------------------
+
+//! Graph algorithms related to feedback arcs.
+
+use crate::graph::Graph;
+
+// ----------------------------------------------------------------------------
+// Functions
+// ----------------------------------------------------------------------------
+
+/// Returns an approximate minimum feedback arc set of the graph.
+///
+/// A feedback arc set is a set of edges whose removal makes the graph acyclic.
+/// Finding a minimum set is NP-hard, so this function uses the greedy heuristic
+/// by Eades, Lin and Smyth, which orders nodes by repeatedly taking sinks and
+/// sources, and otherwise the node with the largest difference of out-degree
+/// and in-degree. All edges pointing backwards in this order, including self
+/// loops, are returned as `(source, target)` pairs in ascending order. If the
+/// graph is acyclic, the returned set is empty.
+///
+/// # Examples
+///
+/// ```
+/// # use std::error::Error;
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// use zrx_graph::algorithm::feedback_arc_set;
+/// use zrx_graph::Graph;
+///
+/// // Create graph builder and add nodes
+/// let mut builder = Graph::builder();
+/// let a = builder.add_node("a");
+/// let b = builder.add_node("b");
+/// let c = builder.add_node("c");
+///
+/// // Create edges between nodes, forming a cycle
+/// builder.add_edge(a, b, 0)?;
+/// builder.add_edge(b, c, 0)?;
+/// builder.add_edge(c, a, 0)?;
+///
+/// // Create graph from builder
+/// let graph = builder.build();
+///
+/// // Obtain feedback arc set
+/// let edges = feedback_arc_set(&graph);
+/// assert_eq!(edges, [(c, a)]);
+/// # Ok(())
+/// # }
+/// ```
+#[must_use]
+pub fn feedback_arc_set<T>(graph: &Graph<T>) -> Vec<(usize, usize)> {
+    let outgoing = graph.topology().outgoing();
+    let incoming = graph.topology().incoming();
+
+    // Initialize the degrees of all nodes, which are updated as nodes are
+    // removed from the graph, and collect the initial sinks and sources
+    let mut out_degree = (0..graph.len())
+        .map(|node| outgoing[node].len())
+        .collect::<Vec<_>>();
+    let mut in_degree = (0..graph.len())
+        .map(|node| incoming[node].len())
+        .collect::<Vec<_>>();
+    let mut sinks = (0..graph.len())
+        .filter(|&node| out_degree[node] == 0)
+        .collect::<Vec<_>>();
+    let mut sources = (0..graph.len())
+        .filter(|&node| out_degree[node] != 0 && in_degree[node] == 0)
+        .collect::<Vec<_>>();
+
+    // Nodes taken from the front are appended to the head, and nodes taken
+    // from the back are appended to the tail, which is reversed at the end
+    let mut removed = vec![false; graph.len()];
+    let mut head = Vec::with_capacity(graph.len());
+    let mut tail = Vec::new();
+    while head.len() + tail.len() < graph.len() {
+        let node = if let Some(node) = sinks.pop() {
+            if removed[node] {
+                continue;
+            }
+            tail.push(node);
+            node
+        } else if let Some(node) = sources.pop() {
+            if removed[node] {
+                continue;
+            }
+            head.push(node);
+            node
+        } else if let Some(node) = (0..graph.len())
+            .filter(|&node| !removed[node])
+            .max_by(|&a, &b| {
+                // Take the node with the largest difference of out-degree and
+                // in-degree, preferring the smallest index for determinism
+                let x = out_degree[a] + in_degree[b];
+                let y = out_degree[b] + in_degree[a];
+                x.cmp(&y).then(b.cmp(&a))
+            })
+        {
+            head.push(node);
+            node
+        } else {
+            break;
+        };
+
+        // Remove the node, and update the degrees of its neighbors, which
+        // might turn them into sinks or sources
+        removed[node] = true;
+        for &next in &outgoing[node] {
+            if !removed[next] {
+                in_degree[next] -= 1;
+                if in_degree[next] == 0 && out_degree[next] != 0 {
+                    sources.push(next);
+                }
+            }
+        }
+        for &prior in &incoming[node] {
+            if !removed[prior] {
+                out_degree[prior] -= 1;
+                if out_degree[prior] == 0 {
+                    sinks.push(prior);
+                }
+            }
+        }
+    }
+
+    // Compute the position of each node in the resulting order
+    let mut position = vec![0; graph.len()];
+    let iter = head.into_iter().chain(tail.into_iter().rev());
+    for (index, node) in iter.enumerate() {
+        position[node] = index;
+    }
+
+    // Return all edges pointing backwards in the order
+    let mut edges = Vec::new();
+    for source in 0..graph.len() {
+        for &target in &outgoing[source] {
+            if position[source] >= position[target] {
+                edges.push((source, target));
+            }
+        }
+    }
+    edges.sort_unstable();
+    edges.dedup();
+    edges
+}
+
+// ----------------------------------------------------------------------------
+// Tests
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+
+    mod feedback_arc_set {
+        use crate::graph::algorithm::feedback_arc_set;
+        use crate::graph::Graph;
+
+        #[test]
+        fn finds_back_edge() {
+            let mut builder = Graph::builder();
+            for node in 0..6 {
+                builder.add_node(node);
+            }
+            for node in 0..5 {
+                builder.add_edge(node, node + 1, ()).unwrap();
+            }
+            builder.add_edge(0, 2, ()).unwrap();
+            builder.add_edge(1, 4, ()).unwrap();
+            builder.add_edge(4, 1, ()).unwrap();
+            let graph = builder.build();
+            assert_eq!(feedback_arc_set(&graph), [(4, 1)]);
+        }
+
+        #[test]
+        fn handles_self_loop() {
+            let mut builder = Graph::builder();
+            builder.add_node(0);
+            builder.add_node(1);
+            builder.add_edge(0, 1, ()).unwrap();
+            builder.add_edge(1, 1, ()).unwrap();
+            let graph = builder.build();
+            assert_eq!(feedback_arc_set(&graph), [(1, 1)]);
+        }
+
+        #[test]
+        fn returns_empty_set_for_dag() {
+            let mut builder = Graph::builder();
+            for node in 0..4 {
+                builder.add_node(node);
+            }
+            builder.add_edge(0, 1, ()).unwrap();
+            builder.add_edge(0, 2, ()).unwrap();
+            builder.add_edge(1, 3, ()).unwrap();
+            builder.add_edge(2, 3, ()).unwrap();
+            let graph = builder.build();
+            assert!(feedback_arc_set(&graph).is_empty());
+        }
+    }
+}
@@ -0,0 +1,223 @@
+// Copyright (c) Zensical LLC <https://zensical.org>
+
+// SPDX-License-Identifier: MIT
+// Third-party contributions licensed under CLA
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+
+// ----------------------------------------------------------------------------
+
+//! Graph algorithms related to isomorphism.
+
+use crate::graph::Graph;
+
+// ----------------------------------------------------------------------------
+// Structs
+// ----------------------------------------------------------------------------
+
+/// Search state.
+struct State<'a, T> {
+    /// First graph.
+    a: &'a Graph<T>,
+    /// Second graph.
+    b: &'a Graph<T>,
+    /// Incoming and outgoing degrees of nodes in both graphs.
+    degrees: [Vec<(usize, usize)>; 2],
+    /// Mapping from nodes in the first to nodes in the second graph.
+    mapping: Vec<usize>,
+    /// Nodes in the second graph that are already mapped.
+    used: Vec<bool>,
+}
+
+// ----------------------------------------------------------------------------
+// Implementations
+// ----------------------------------------------------------------------------
+
+impl<T> State<'_, T>
+where
+    T: Eq,
+{
+    /// Searches for a mapping of the given and all subsequent nodes.
+    fn search(&mut self, node: usize) -> bool {
+        if node == self.a.len() {
+            return true;
+        }
+
+        // Try all unused candidates, and backtrack if the mapping fails
+        for candidate in 0..self.b.len() {
+            if !self.used[candidate] && self.is_feasible(node, candidate) {
+                self.mapping[node] = candidate;
+                self.used[candidate] = true;
+                if self.search(node + 1) {
+                    return true;
+                }
+                self.used[candidate] = false;
+            }
+        }
+
+        // No mapping found
+        self.mapping[node] = usize::MAX;
+        false
+    }
+
+    /// Returns whether the node can be mapped onto the candidate.
+    fn is_feasible(&self, node: usize, candidate: usize) -> bool {
+        if self.a[node] != self.b[candidate]
+            || self.degrees[0][node] != self.degrees[1][candidate]
+        {
+            return false;
+        }
+
+        // All edges between the node and already mapped nodes must exist in
+        // both graphs, in both directions, as nodes before it are mapped
+        let x = self.a.topology().distance();
+        let y = self.b.topology().distance();
+        (0..node).all(|prior| {
+            let other = self.mapping[prior];
+            (x[node][prior] == 1) == (y[candidate][other] == 1)
+                && (x[prior][node] == 1) == (y[other][candidate] == 1)
+        })
+    }
+}
+
+// ----------------------------------------------------------------------------
+// Functions
+// ----------------------------------------------------------------------------
+
+/// Returns whether both graphs are isomorphic, taking node data into account.
+///
+/// Two graphs are isomorphic if there's a bijective mapping between their
+/// nodes, which preserves both, the edges and the node data. In contrast to
+/// structural equality, this doesn't depend on the indices of the nodes.
+///
+/// The mapping is searched with a VF2-style backtracking algorithm, which is
+/// pruned with the node data, as well as the number of incoming and outgoing
+/// edges of each node. Checking for an edge between two nodes is O(1), as it
+/// is a lookup in the [`Distance`][] matrix. However, the worst case is still
+/// exponential, so this function is intended to be used as a test oracle on
+/// small graphs, and not on large graphs in production.
+///
+/// [`Distance`]: crate::graph::topology::Distance
+///
+/// # Examples
+///
+/// ```
+/// # use std::error::Error;
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// use zrx_graph::algorithm::is_isomorphic;
+/// use zrx_graph::Graph;
+///
+/// // Create graph builder and add nodes
+/// let mut builder = Graph::builder();
+/// let a = builder.add_node("a");
+/// let b = builder.add_node("b");
+/// let c = builder.add_node("c");
+///
+/// // Create edges between nodes
+/// builder.add_edge(a, b, 0)?;
+/// builder.add_edge(b, c, 0)?;
+///
+/// // Create graph with nodes in different order
+/// let mut relabeled = Graph::builder();
+/// let c = relabeled.add_node("c");
+/// let a = relabeled.add_node("a");
+/// let b = relabeled.add_node("b");
+///
+/// // Create edges between nodes
+/// relabeled.add_edge(b, c, 0)?;
+/// relabeled.add_edge(a, b, 0)?;
+///
+/// // Ensure graphs are isomorphic
+/// assert!(is_isomorphic(&builder.build(), &relabeled.build()));
+/// # Ok(())
+/// # }
+/// ```
+///
+/// Graphs with different structure are not isomorphic:
+///
+/// ```
+/// # use std::error::Error;
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// use zrx_graph::algorithm::is_isomorphic;
+/// use zrx_graph::Graph;
+///
+/// // Create graph builder and add nodes
+/// let mut builder = Graph::builder();
+/// let a = builder.add_node("a");
+/// let b = builder.add_node("b");
+/// let c = builder.add_node("c");
+///
+/// // Create edges between nodes
+/// builder.add_edge(a, b, 0)?;
+/// builder.add_edge(b, c, 0)?;
+///
+/// // Create graph with different edges
+/// let mut other = Graph::builder();
+/// let a = other.add_node("a");
+/// let b = other.add_node("b");
+/// let c = other.add_node("c");
+///
+/// // Create edges between nodes
+/// other.add_edge(a, b, 0)?;
+/// other.add_edge(a, c, 0)?;
+///
+/// // Ensure graphs are not isomorphic
+/// assert!(!is_isomorphic(&builder.build(), &other.build()));
+/// # Ok(())
+/// # }
+/// ```
+#[must_use]
+pub fn is_isomorphic<T>(a: &Graph<T>, b: &Graph<T>) -> bool
+where
+    T: Eq,
+{
+    if a.len() != b.len() {
+        return false;
+    }
+
+    // Compute the degrees of all nodes in both graphs, which must match for
+    // nodes to be mapped onto each other, and prune most candidates early
+    let degrees = |graph: &Graph<T>| {
+        let outgoing = graph.topology().outgoing();
+        let incoming = graph.topology().incoming();
+        (0..graph.len())
+            .map(|node| (incoming[node].len(), outgoing[node].len()))
+            .collect::<Vec<_>>()
+    };
+    let degrees = [degrees(a), degrees(b)];
+
+    // Ensure the degree sequences of both graphs match before searching
+    let mut sequences = degrees.clone();
+    for sequence in &mut sequences {
+        sequence.sort_unstable();
+    }
+    if sequences[0] != sequences[1] {
+        return false;
+    }
+
+    // Search for a mapping, starting with the first node
+    let mut state = State {
+        a,
+        b,
+        degrees,
+        mapping: vec![usize::MAX; a.len()],
+        used: vec![false; b.len()],
+    };
+    state.search(0)
+}
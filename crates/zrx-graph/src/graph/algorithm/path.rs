@@ -26,14 +26,128 @@
 //! Graph algorithms related to paths.
 
 use ahash::HashSet;
-use std::collections::VecDeque;
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, VecDeque};
+use std::ops::Add;
 
-use crate::graph::Graph;
+use crate::graph::{Graph, WeightedView};
 
 // ----------------------------------------------------------------------------
 // Functions
 // ----------------------------------------------------------------------------
 
+/// Returns the length of the longest path in the graph.
+///
+/// The length is measured in hops, i.e., the number of edges on the longest
+/// path, which is computed by visiting all nodes in topological order, while
+/// tracking the maximum depth of each node. Empty graphs have length 0.
+///
+/// # Examples
+///
+/// ```
+/// # use std::error::Error;
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// use zrx_graph::algorithm::longest_path_length;
+/// use zrx_graph::Graph;
+///
+/// // Create graph builder and add nodes
+/// let mut builder = Graph::builder();
+/// let nodes = (0..5).map(|n| builder.add_node(n)).collect::<Vec<_>>();
+///
+/// // Create edges between nodes, forming a chain
+/// for pair in nodes.windows(2) {
+///     builder.add_edge(pair[0], pair[1], 0)?;
+/// }
+///
+/// // Create graph from builder
+/// let graph = builder.build();
+///
+/// // Obtain longest path length
+/// let len = longest_path_length(&graph);
+/// assert_eq!(len, nodes.len() - 1);
+/// # Ok(())
+/// # }
+/// ```
+#[must_use]
+pub fn longest_path_length<T>(graph: &Graph<T>) -> usize {
+    let incoming = graph.topology().incoming();
+
+    // Visit all nodes in topological order, so the depths of all ancestors
+    // are known when a node is visited, and the depth can be derived
+    let mut depths = vec![0; graph.len()];
+    for node in graph.traverse(graph.sources()) {
+        let iter = incoming[node].iter();
+        depths[node] = iter
+            .map(|&ancestor| depths[ancestor] + 1)
+            .max()
+            .unwrap_or(0);
+    }
+
+    // Return maximum depth
+    depths.into_iter().max().unwrap_or(0)
+}
+
+/// Returns the number of distinct paths between two nodes in the graph.
+///
+/// Paths are counted without enumerating them, by visiting all nodes that are
+/// reachable from the source in topological order, and propagating the number
+/// of paths reaching each node to its descendants. As the number of paths can
+/// grow exponentially, counts saturate at [`u128::MAX`]. If the source is the
+/// target, the empty path is counted, so the result is 1.
+///
+/// # Examples
+///
+/// ```
+/// # use std::error::Error;
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// use zrx_graph::algorithm::path_count;
+/// use zrx_graph::Graph;
+///
+/// // Create graph builder and add nodes
+/// let mut builder = Graph::builder();
+/// let a = builder.add_node("a");
+/// let b = builder.add_node("b");
+/// let c = builder.add_node("c");
+/// let d = builder.add_node("d");
+///
+/// // Create edges between nodes, forming a diamond
+/// builder.add_edge(a, b, 0)?;
+/// builder.add_edge(a, c, 0)?;
+/// builder.add_edge(b, d, 0)?;
+/// builder.add_edge(c, d, 0)?;
+///
+/// // Create graph from builder
+/// let graph = builder.build();
+///
+/// // Obtain number of paths
+/// assert_eq!(path_count(&graph, a, d), 2);
+/// assert_eq!(path_count(&graph, b, c), 0);
+/// # Ok(())
+/// # }
+/// ```
+#[must_use]
+pub fn path_count<T>(graph: &Graph<T>, source: usize, target: usize) -> u128 {
+    let outgoing = graph.topology().outgoing();
+
+    // Visit all nodes reachable from the source in topological order, so the
+    // number of paths reaching a node is final when it's visited, and can be
+    // added to the number of paths reaching each of its descendants
+    let mut counts = vec![0u128; graph.len()];
+    counts[source] = 1;
+    for node in graph.traverse([source]) {
+        if node == target {
+            break;
+        }
+        for &descendant in &outgoing[node] {
+            counts[descendant] =
+                counts[descendant].saturating_add(counts[node]);
+        }
+    }
+
+    // Return number of paths reaching the target
+    counts[target]
+}
+
 /// Returns the length of the shortest path between two nodes in the graph.
 ///
 /// # Examples
@@ -95,3 +209,146 @@ pub fn shortest_path_length<T>(
     // No path between nodes found
     None
 }
+
+/// Returns the length of the shortest weighted path between two nodes.
+///
+/// This function implements Dijkstra's algorithm on a [`WeightedView`], which
+/// computes edge weights on demand. Weights must be non-negative, and the
+/// [`Default`] value of the weight type is considered to be zero.
+///
+/// # Examples
+///
+/// ```
+/// # use std::error::Error;
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// use zrx_graph::algorithm::weighted_shortest_path_length;
+/// use zrx_graph::Graph;
+///
+/// // Create graph builder and add nodes
+/// let mut builder = Graph::builder();
+/// let a = builder.add_node("a");
+/// let b = builder.add_node("b");
+/// let c = builder.add_node("c");
+///
+/// // Create edges between nodes
+/// builder.add_edge(a, b, 0)?;
+/// builder.add_edge(b, c, 0)?;
+/// builder.add_edge(a, c, 0)?;
+///
+/// // Create graph from builder and weighted view
+/// let graph = builder.build();
+/// let view = graph.with_edge_weights(|_, _| 3);
+///
+/// // Obtain weighted shortest path length
+/// let len = weighted_shortest_path_length(&view, a, c);
+/// assert_eq!(len, Some(3));
+/// # Ok(())
+/// # }
+/// ```
+#[must_use]
+pub fn weighted_shortest_path_length<T, W>(
+    view: &WeightedView<T, W>, source: usize, target: usize,
+) -> Option<W>
+where
+    W: Copy + Ord + Add<Output = W> + Default,
+{
+    let outgoing = view.graph().topology().outgoing();
+
+    // Initialize set to track visited nodes
+    let mut visited = HashSet::default();
+
+    // Perform Dijkstra's algorithm, always expanding the node with the lowest
+    // distance from the source node next, which is why we use a min-heap. The
+    // node index is part of the heap entry, so ties are resolved by index.
+    let mut heap = BinaryHeap::from([Reverse((W::default(), source))]);
+    while let Some(Reverse((len, node))) = heap.pop() {
+        if node == target {
+            return Some(len);
+        }
+
+        // Skip nodes that were already expanded with a lower distance
+        if !visited.insert(node) {
+            continue;
+        }
+
+        // Add unvisited descendants to the heap with their tentative distance
+        for &descendant in &outgoing[node] {
+            if !visited.contains(&descendant) {
+                let weight = view.weight(node, descendant);
+                heap.push(Reverse((len + weight, descendant)));
+            }
+        }
+    }
+
+    // No path between nodes found
+    None
+}
+
+// ----------------------------------------------------------------------------
+// Tests
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+
+    mod path_count {
+        use crate::graph::algorithm::path_count;
+        use crate::graph::Graph;
+
+        /// Creates a graph of fully connected layers of the given width, so
+        /// each layer multiplies the number of paths by the width.
+        fn layers(width: usize, depth: usize) -> Graph<usize> {
+            let mut builder = Graph::builder();
+            let source = builder.add_node(0);
+            let mut prior = vec![source];
+            for layer in 0..depth {
+                let nodes = (0..width)
+                    .map(|n| builder.add_node(layer * width + n))
+                    .collect::<Vec<_>>();
+                for &a in &prior {
+                    for &b in &nodes {
+                        builder.add_edge(a, b, ()).unwrap();
+                    }
+                }
+                prior = nodes;
+            }
+            let target = builder.add_node(usize::MAX);
+            for &a in &prior {
+                builder.add_edge(a, target, ()).unwrap();
+            }
+            builder.build()
+        }
+
+        #[test]
+        fn counts_paths_in_grid() {
+            let mut builder = Graph::builder();
+            for node in 0..25 {
+                builder.add_node(node);
+            }
+            for row in 0..5 {
+                for col in 0..5 {
+                    let node = row * 5 + col;
+                    if col < 4 {
+                        builder.add_edge(node, node + 1, ()).unwrap();
+                    }
+                    if row < 4 {
+                        builder.add_edge(node, node + 5, ()).unwrap();
+                    }
+                }
+            }
+            let graph = builder.build();
+            assert_eq!(path_count(&graph, 0, 24), 70);
+            assert_eq!(path_count(&graph, 6, 18), 6);
+            assert_eq!(path_count(&graph, 24, 0), 0);
+            assert_eq!(path_count(&graph, 12, 12), 1);
+        }
+
+        #[test]
+        fn saturates_on_large_fan_out() {
+            let graph = layers(16, 31);
+            assert_eq!(path_count(&graph, 0, graph.len() - 1), 1 << 124);
+            let graph = layers(16, 33);
+            assert_eq!(path_count(&graph, 0, graph.len() - 1), u128::MAX);
+        }
+    }
+}
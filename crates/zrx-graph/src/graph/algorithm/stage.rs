@@ -0,0 +1,169 @@
+// Copyright (c) Zensical LLC <https://zensical.org>
+
+// SPDX-License-Identifier: MIT
+// Third-party contributions licensed under CLA
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+
+// ----------------------------------------------------------------------------
+
+//! Graph algorithms related to stages.
+
+use crate::graph::Graph;
+
+// ----------------------------------------------------------------------------
+// Functions
+// ----------------------------------------------------------------------------
+
+/// Returns the nodes of the graph grouped into generations.
+///
+/// Generations represent earliest-possible scheduling, which means that each
+/// node is placed into the first layer after all of its ancestors, i.e., its
+/// generation is the length of the longest path from any source to the node.
+/// Nodes in the same generation are sorted by their indices.
+///
+/// # Examples
+///
+/// ```
+/// # use std::error::Error;
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// use zrx_graph::algorithm::generations;
+/// use zrx_graph::Graph;
+///
+/// // Create graph builder and add nodes
+/// let mut builder = Graph::builder();
+/// let a = builder.add_node("a");
+/// let b = builder.add_node("b");
+/// let c = builder.add_node("c");
+/// let d = builder.add_node("d");
+/// let e = builder.add_node("e");
+///
+/// // Create edges between nodes, forming an uneven diamond
+/// builder.add_edge(a, b, 0)?;
+/// builder.add_edge(b, c, 0)?;
+/// builder.add_edge(c, e, 0)?;
+/// builder.add_edge(a, d, 0)?;
+/// builder.add_edge(d, e, 0)?;
+///
+/// // Create graph from builder
+/// let graph = builder.build();
+///
+/// // Obtain generations
+/// let layers = generations(&graph);
+/// assert_eq!(layers, [vec![a], vec![b, d], vec![c], vec![e]]);
+/// # Ok(())
+/// # }
+/// ```
+#[must_use]
+pub fn generations<T>(graph: &Graph<T>) -> Vec<Vec<usize>> {
+    let incoming = graph.topology().incoming();
+
+    // Visit all nodes in topological order, so the depths of all ancestors
+    // are known when a node is visited, and the depth can be derived
+    let mut depths = vec![0; graph.len()];
+    for node in graph.traverse(graph.sources()) {
+        let iter = incoming[node].iter();
+        depths[node] = iter
+            .map(|&ancestor| depths[ancestor] + 1)
+            .max()
+            .unwrap_or(0);
+    }
+
+    // Group nodes by depth
+    group(depths)
+}
+
+/// Returns the nodes of the graph grouped into stages.
+///
+/// Stages represent latest-possible scheduling, which means that each node is
+/// placed into the last layer before all of its descendants, so the graph is
+/// essentially cut into successive waves at its sinks, which all end up in
+/// the last stage. Nodes in the same stage are sorted by their indices.
+///
+/// Stages complement [`generations`], as both share the same number of layers,
+/// and the difference of a node's layers is its slack, i.e., the number of
+/// layers its execution can be delayed without delaying the overall pipeline.
+///
+/// # Examples
+///
+/// ```
+/// # use std::error::Error;
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// use zrx_graph::algorithm::{generations, stages};
+/// use zrx_graph::Graph;
+///
+/// // Create graph builder and add nodes
+/// let mut builder = Graph::builder();
+/// let a = builder.add_node("a");
+/// let b = builder.add_node("b");
+/// let c = builder.add_node("c");
+/// let d = builder.add_node("d");
+/// let e = builder.add_node("e");
+///
+/// // Create edges between nodes, forming an uneven diamond
+/// builder.add_edge(a, b, 0)?;
+/// builder.add_edge(b, c, 0)?;
+/// builder.add_edge(c, e, 0)?;
+/// builder.add_edge(a, d, 0)?;
+/// builder.add_edge(d, e, 0)?;
+///
+/// // Create graph from builder
+/// let graph = builder.build();
+///
+/// // Obtain stages, and compare them to generations
+/// let layers = stages(&graph);
+/// assert_eq!(layers, [vec![a], vec![b], vec![c, d], vec![e]]);
+/// assert_eq!(generations(&graph), [vec![a], vec![b, d], vec![c], vec![e]]);
+/// # Ok(())
+/// # }
+/// ```
+#[must_use]
+pub fn stages<T>(graph: &Graph<T>) -> Vec<Vec<usize>> {
+    let outgoing = graph.topology().outgoing();
+
+    // Visit all nodes in reverse topological order, so the heights of all
+    // descendants are known when a node is visited, and it can be derived
+    let traversal = graph.traverse(graph.sources());
+    let order: Vec<_> = traversal.into_iter().collect();
+    let mut heights = vec![0; graph.len()];
+    for &node in order.iter().rev() {
+        let iter = outgoing[node].iter();
+        heights[node] = iter
+            .map(|&descendant| heights[descendant] + 1)
+            .max()
+            .unwrap_or(0);
+    }
+
+    // Convert heights into stages, counting from the last stage, as sinks
+    // always have a height of zero, and group nodes by stage
+    let max = heights.iter().copied().max().unwrap_or(0);
+    group(heights.into_iter().map(|height| max - height).collect())
+}
+
+// ----------------------------------------------------------------------------
+
+/// Groups nodes by the given layer indices.
+fn group(layers: Vec<usize>) -> Vec<Vec<usize>> {
+    let len = layers.iter().map(|&layer| layer + 1).max().unwrap_or(0);
+    let mut groups = vec![Vec::new(); len];
+    for (node, layer) in layers.into_iter().enumerate() {
+        groups[layer].push(node);
+    }
+    groups
+}
@@ -0,0 +1,150 @@
+// Copyright (c) Zensical LLC <https://zensical.org>
+
+// SPDX-License-Identifier: MIT
+// Third-party contributions licensed under CLA
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+
+// ----------------------------------------------------------------------------
+
+//! Graph algorithms related to topological ordering.
+
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+use crate::graph::error::{Error, Result};
+use crate::graph::Graph;
+
+// ----------------------------------------------------------------------------
+// Functions
+// ----------------------------------------------------------------------------
+
+/// Returns the nodes of the graph in topological order, breaking ties by key.
+///
+/// This is a variant of Kahn's algorithm, which keeps all nodes whose incoming
+/// edges have been visited in a priority queue ordered by the given key, and
+/// falls back to node indices for equal keys. Thus, among nodes that are not
+/// ordered by the graph, the node with the smallest key always comes first,
+/// which makes the resulting order fully deterministic.
+///
+/// # Errors
+///
+/// In case the graph contains a cycle, [`Error::Cycle`] is returned with the
+/// smallest node index that is part of or reachable from the cycle.
+///
+/// # Examples
+///
+/// ```
+/// # use std::error::Error;
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// use zrx_graph::algorithm::topological_sort_stable;
+/// use zrx_graph::Graph;
+///
+/// // Create graph builder and add nodes
+/// let mut builder = Graph::builder();
+/// let a = builder.add_node("a");
+/// let b = builder.add_node("b");
+/// let c = builder.add_node("c");
+/// let d = builder.add_node("d");
+///
+/// // Create edges between nodes
+/// builder.add_edge(a, b, 0)?;
+/// builder.add_edge(a, c, 0)?;
+/// builder.add_edge(b, d, 0)?;
+/// builder.add_edge(c, d, 0)?;
+///
+/// // Create graph from builder
+/// let graph = builder.build();
+///
+/// // Obtain topological order, preferring c over b
+/// let nodes = topological_sort_stable(&graph, |node| graph[node] == "b")?;
+/// assert_eq!(nodes, [a, c, b, d]);
+///
+/// // Obtain topological order, preferring b over c
+/// let nodes = topological_sort_stable(&graph, |node| graph[node] == "c")?;
+/// assert_eq!(nodes, [a, b, c, d]);
+/// # Ok(())
+/// # }
+/// ```
+///
+/// Detect a cycle:
+///
+/// ```
+/// # use std::error::Error;
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// use zrx_graph::algorithm::topological_sort_stable;
+/// use zrx_graph::Graph;
+///
+/// // Create graph builder and add nodes
+/// let mut builder = Graph::builder();
+/// let a = builder.add_node("a");
+/// let b = builder.add_node("b");
+///
+/// // Create edges between nodes, forming a cycle
+/// builder.add_edge(a, b, 0)?;
+/// builder.add_edge(b, a, 0)?;
+///
+/// // Create graph from builder
+/// let graph = builder.build();
+///
+/// // Obtaining topological order fails
+/// assert!(topological_sort_stable(&graph, |node| node).is_err());
+/// # Ok(())
+/// # }
+/// ```
+pub fn topological_sort_stable<T, K, F>(
+    graph: &Graph<T>, key: F,
+) -> Result<Vec<usize>>
+where
+    K: Ord,
+    F: Fn(usize) -> K,
+{
+    let outgoing = graph.topology().outgoing();
+    let incoming = graph.topology().incoming();
+
+    // Initialize the queue with all sources, and track the number of incoming
+    // edges that still need to be visited for each node in the graph
+    let mut degrees = Vec::with_capacity(graph.len());
+    let mut queue = BinaryHeap::new();
+    for node in 0..graph.len() {
+        degrees.push(incoming[node].len());
+        if incoming[node].is_empty() {
+            queue.push(Reverse((key(node), node)));
+        }
+    }
+
+    // Visit nodes in order of their keys, adding all descendants to the queue
+    // once all of their incoming edges have been visited
+    let mut nodes = Vec::with_capacity(graph.len());
+    while let Some(Reverse((_, node))) = queue.pop() {
+        nodes.push(node);
+        for &descendant in &outgoing[node] {
+            degrees[descendant] -= 1;
+            if degrees[descendant] == 0 {
+                queue.push(Reverse((key(descendant), descendant)));
+            }
+        }
+    }
+
+    // If not all nodes were visited, the graph contains a cycle
+    match degrees.iter().position(|&degree| degree > 0) {
+        Some(node) => Err(Error::Cycle(node)),
+        None => Ok(nodes),
+    }
+}
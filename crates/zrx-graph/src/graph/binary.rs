@@ -0,0 +1,350 @@
+// Copyright (c) Zensical LLC <https://zensical.org>
+
+// SPDX-License-Identifier: MIT
+// Third-party contributions licensed under CLA
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+
+// ----------------------------------------------------------------------------
+
+//! Binary encoding of a graph.
+
+use bincode::Options;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::io::{self, Read, Write};
+
+use super::{Builder, Error, Graph};
+
+// ----------------------------------------------------------------------------
+// Implementations
+// ----------------------------------------------------------------------------
+
+impl<T> Graph<T> {
+    /// Writes the graph in a compact binary edge-list format.
+    ///
+    /// The format starts with a magic number and version, followed by the
+    /// number of nodes, the length-prefixed data of each node, encoded with
+    /// [`bincode`] using variable-length integers, and the outgoing adjacency
+    /// list, where all numbers are encoded as variable-length integers as well.
+    /// This is considerably denser and faster to decode than generic formats,
+    /// since indices of small graphs mostly fit into a single byte. Note that
+    /// the order of edges is preserved, so traversals of the decoded graph are
+    /// identical.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error if writing fails, or if the data of a node
+    /// can't be serialized.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::error::Error;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use zrx_graph::Graph;
+    ///
+    /// // Create graph builder and add nodes
+    /// let mut builder = Graph::builder();
+    /// let a = builder.add_node("a");
+    /// let b = builder.add_node("b");
+    ///
+    /// // Create edges between nodes
+    /// builder.add_edge(a, b, 0)?;
+    ///
+    /// // Create graph from builder
+    /// let graph = builder.build();
+    ///
+    /// // Write graph in binary format
+    /// let mut bytes = Vec::new();
+    /// graph.write_binary(&mut bytes)?;
+    /// assert_eq!(bytes.len(), 15);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn write_binary<W>(&self, mut writer: W) -> io::Result<()>
+    where
+        W: Write,
+        T: Serialize,
+    {
+        writer.write_all(&MAGIC)?;
+        write_varint(&mut writer, self.data.len())?;
+
+        // Write length-prefixed data of each node
+        let mut buffer = Vec::new();
+        for data in &self.data {
+            buffer.clear();
+            bincode::options()
+                .serialize_into(&mut buffer, data)
+                .map_err(|err| {
+                    io::Error::new(io::ErrorKind::InvalidData, err)
+                })?;
+            write_varint(&mut writer, buffer.len())?;
+            writer.write_all(&buffer)?;
+        }
+
+        // Write outgoing adjacency list, prefixing targets with their count
+        for node in 0..self.data.len() {
+            let targets = self.topology.out_neighbors(node);
+            write_varint(&mut writer, targets.len())?;
+            for &target in targets {
+                write_varint(&mut writer, target)?;
+            }
+        }
+
+        // No errors occurred
+        writer.flush()
+    }
+
+    /// Reads a graph in the compact binary edge-list format.
+    ///
+    /// This is the inverse of [`Graph::write_binary`] - see its documentation
+    /// for a description of the format.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error if reading fails, or with kind
+    /// [`io::ErrorKind::InvalidData`] if the input isn't a valid graph, which
+    /// includes nodes with more than 255 incoming or outgoing edges.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::error::Error;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use zrx_graph::Graph;
+    ///
+    /// // Create graph builder and add nodes
+    /// let mut builder = Graph::builder();
+    /// let a = builder.add_node(String::from("a"));
+    /// let b = builder.add_node(String::from("b"));
+    ///
+    /// // Create edges between nodes
+    /// builder.add_edge(a, b, 0)?;
+    ///
+    /// // Create graph from builder
+    /// let graph = builder.build();
+    ///
+    /// // Write and read graph in binary format
+    /// let mut bytes = Vec::new();
+    /// graph.write_binary(&mut bytes)?;
+    /// let graph = Graph::<String>::read_binary(bytes.as_slice())?;
+    /// assert_eq!(graph[b], "b");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn read_binary<R>(mut reader: R) -> io::Result<Self>
+    where
+        R: Read,
+        T: DeserializeOwned,
+    {
+        let mut magic = [0; 5];
+        reader.read_exact(&mut magic)?;
+        if magic != MAGIC {
+            return Err(invalid("invalid magic number or version"));
+        }
+
+        // Read length-prefixed data of each node - note that we don't trust
+        // the length prefix for allocation, but only read what's available
+        let mut builder = Builder::<T>::new();
+        let mut buffer = Vec::new();
+        let len = read_varint(&mut reader)?;
+        for _ in 0..len {
+            let size = read_varint(&mut reader)?;
+            buffer.clear();
+            (&mut reader).take(size as u64).read_to_end(&mut buffer)?;
+            if buffer.len() != size {
+                return Err(io::ErrorKind::UnexpectedEof.into());
+            }
+            let data =
+                bincode::options().deserialize(&buffer).map_err(|err| {
+                    io::Error::new(io::ErrorKind::InvalidData, err)
+                })?;
+            builder.add_node(data);
+        }
+
+        // Read outgoing adjacency list, and add edges in their original order,
+        // ensuring that no node exceeds the maximum number of incoming or
+        // outgoing edges, as building the graph would panic otherwise
+        let mut degrees = vec![0u8; len];
+        for source in 0..len {
+            let count = read_varint(&mut reader)?;
+            if count > usize::from(u8::MAX) {
+                return Err(degree(source));
+            }
+            for _ in 0..count {
+                let target = read_varint(&mut reader)?;
+                builder.add_edge(source, target, ()).map_err(|err| {
+                    io::Error::new(io::ErrorKind::InvalidData, err)
+                })?;
+                degrees[target] = degrees[target]
+                    .checked_add(1)
+                    .ok_or_else(|| degree(target))?;
+            }
+        }
+
+        // No errors occurred
+        Ok(builder.build())
+    }
+}
+
+// ----------------------------------------------------------------------------
+// Constants
+// ----------------------------------------------------------------------------
+
+/// Magic number and format version.
+const MAGIC: [u8; 5] = *b"ZRXG\x01";
+
+// ----------------------------------------------------------------------------
+// Functions
+// ----------------------------------------------------------------------------
+
+/// Writes the given value as an LEB128 variable-length integer.
+fn write_varint<W>(writer: &mut W, mut value: usize) -> io::Result<()>
+where
+    W: Write,
+{
+    let mut buffer = [0; 10];
+    let mut len = 0;
+    loop {
+        #[allow(clippy::cast_possible_truncation)]
+        let byte = (value & 0x7F) as u8;
+        value >>= 7;
+        if value == 0 {
+            buffer[len] = byte;
+            len += 1;
+            break;
+        }
+        buffer[len] = byte | 0x80;
+        len += 1;
+    }
+    writer.write_all(&buffer[..len])
+}
+
+/// Reads an LEB128 variable-length integer.
+fn read_varint<R>(reader: &mut R) -> io::Result<usize>
+where
+    R: Read,
+{
+    let mut value = 0usize;
+    for shift in (0..usize::BITS).step_by(7) {
+        let mut byte = [0];
+        reader.read_exact(&mut byte)?;
+        value |= usize::from(byte[0] & 0x7F)
+            .checked_shl(shift)
+            .ok_or_else(|| invalid("variable-length integer overflow"))?;
+        if byte[0] & 0x80 == 0 {
+            return Ok(value);
+        }
+    }
+    Err(invalid("variable-length integer overflow"))
+}
+
+/// Creates an error for invalid input.
+fn invalid(message: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message)
+}
+
+/// Creates an error for a node exceeding the maximum number of edges.
+fn degree(node: usize) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, Error::Degree(node))
+}
+
+// ----------------------------------------------------------------------------
+// Tests
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+
+    mod write_binary {
+        use crate::graph::Graph;
+
+        fn graph(len: usize) -> Graph<String> {
+            let mut builder = Graph::builder();
+            for node in 0..len {
+                builder.add_node(format!("node-{node}"));
+            }
+            for node in 1..len {
+                builder.add_edge(node / 2, node, ()).unwrap();
+                builder.add_edge(node - 1, node, ()).unwrap();
+            }
+            builder.build()
+        }
+
+        #[test]
+        fn round_trips_nodes_and_edges() {
+            let graph = graph(1000);
+            let mut bytes = Vec::new();
+            graph.write_binary(&mut bytes).unwrap();
+
+            // Ensure decoded graph is identical
+            let decoded = Graph::<String>::read_binary(bytes.as_slice());
+            let decoded = decoded.unwrap();
+            assert_eq!(decoded.len(), graph.len());
+            for node in 0..graph.len() {
+                assert_eq!(decoded[node], graph[node]);
+                assert_eq!(
+                    decoded.topology().out_neighbors(node),
+                    graph.topology().out_neighbors(node)
+                );
+            }
+        }
+
+        #[test]
+        fn is_smaller_than_bincode() {
+            let graph = graph(1000);
+            let mut bytes = Vec::new();
+            graph.write_binary(&mut bytes).unwrap();
+
+            // Encode the same nodes and adjacency list with bincode
+            let data = graph.iter().collect::<Vec<_>>();
+            let edges = (0..graph.len())
+                .map(|node| graph.topology().out_neighbors(node))
+                .collect::<Vec<_>>();
+            let other = bincode::serialize(&(data, edges)).unwrap();
+
+            // Ensure binary format is more compact
+            println!("binary: {} bytes, bincode: {}", bytes.len(), other.len());
+            assert!(bytes.len() < other.len());
+        }
+
+        #[test]
+        fn rejects_exceeding_degree() {
+            let mut bytes = b"ZRXG\x01".to_vec();
+            bytes.push(2);
+            bytes.extend([1, 0, 1, 0]);
+            bytes.extend([0x80, 0x02]);
+            bytes.extend([1; 256]);
+            bytes.push(0);
+
+            // Ensure error is returned instead of panicking
+            let res = Graph::<u8>::read_binary(bytes.as_slice());
+            let err = res.unwrap_err();
+            assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+        }
+
+        #[test]
+        fn rejects_invalid_input() {
+            let res =
+                Graph::<String>::read_binary(&b"ZRXG\x01\x01\x00\x01\x05"[..]);
+            assert!(res.is_err());
+        }
+    }
+}
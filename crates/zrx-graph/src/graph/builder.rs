@@ -26,9 +26,13 @@
 //! Graph builder.
 
 use std::collections::BTreeMap;
+use std::fmt;
+use std::marker::PhantomData;
 use std::ops::Index;
+use std::sync::Arc;
 
 use super::error::{Error, Result};
+use super::node::NodeId;
 use super::topology::Topology;
 use super::Graph;
 
@@ -37,12 +41,20 @@ use super::Graph;
 // ----------------------------------------------------------------------------
 
 /// Graph builder.
-#[derive(Clone, Debug)]
-pub struct Builder<T, W = ()> {
+///
+/// Node handles are plain [`usize`] indices by default. For type-safe handles
+/// that can't be mixed up between graphs, use [`Builder::typed`], which makes
+/// the builder return and accept [`NodeId`] handles instead.
+#[derive(Clone)]
+pub struct Builder<T, W = (), I = usize> {
     /// Nodes of the graph.
     nodes: Vec<T>,
     /// Edges of the graph.
     edges: Vec<Edge<W>>,
+    /// Edge validator, if any.
+    validator: Option<Validator<T>>,
+    /// Type marker.
+    marker: PhantomData<I>,
 }
 
 /// Graph edge.
@@ -88,9 +100,53 @@ impl<T, W> Builder<T, W> {
         Self {
             nodes: Vec::new(),
             edges: Vec::new(),
+            validator: None,
+            marker: PhantomData,
         }
     }
+}
+
+impl<T, W, Tag> Builder<T, W, NodeId<Tag>> {
+    /// Creates a graph builder with typed node handles.
+    ///
+    /// The returned builder hands out [`NodeId`] handles parameterized by the
+    /// given tag, so handles from builders with different tags can't be mixed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::error::Error;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use zrx_graph::{Builder, NodeId};
+    ///
+    /// // Create tag for graph
+    /// struct Pipeline;
+    ///
+    /// // Create graph builder and add nodes
+    /// let mut builder: Builder<_, _, NodeId<Pipeline>> = Builder::typed();
+    /// let a = builder.add_node("a");
+    /// let b = builder.add_node("b");
+    ///
+    /// // Create edges between nodes
+    /// builder.add_edge(a, b, 0)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn typed() -> Self {
+        Self {
+            nodes: Vec::new(),
+            edges: Vec::new(),
+            validator: None,
+            marker: PhantomData,
+        }
+    }
+}
 
+impl<T, W, I> Builder<T, W, I>
+where
+    I: Copy + From<usize> + Into<usize>,
+{
     /// Adds a node to the graph.
     ///
     /// # Examples
@@ -110,9 +166,9 @@ impl<T, W> Builder<T, W> {
     /// # Ok(())
     /// # }
     /// ```
-    pub fn add_node(&mut self, node: T) -> usize {
+    pub fn add_node(&mut self, node: T) -> I {
         self.nodes.push(node);
-        self.nodes.len() - 1
+        I::from(self.nodes.len() - 1)
     }
 
     /// Adds an edge to the graph.
@@ -122,7 +178,9 @@ impl<T, W> Builder<T, W> {
     /// In case the source or target node doesn't exist, [`Error::NotFound`] is
     /// returned, to make sure the graph does not contain stale node references.
     /// By returning an error instead of panicking, we can provide recoverable
-    /// and proper error handling to the caller.
+    /// and proper error handling to the caller. In case the edge is rejected
+    /// by the validator set via [`Builder::with_edge_validator`], the error is
+    /// [`Error::Rejected`].
     ///
     /// This is mentionable, as some other graph libraries will just panic and
     /// crash the program, like the popular [`petgraph`][] crate. Additionally,
@@ -150,9 +208,9 @@ impl<T, W> Builder<T, W> {
     /// # Ok(())
     /// # }
     /// ```
-    pub fn add_edge(
-        &mut self, source: usize, target: usize, weight: W,
-    ) -> Result {
+    pub fn add_edge(&mut self, source: I, target: I, weight: W) -> Result {
+        let source = source.into();
+        let target = target.into();
         if source >= self.nodes.len() {
             return Err(Error::NotFound(source));
         }
@@ -160,11 +218,129 @@ impl<T, W> Builder<T, W> {
             return Err(Error::NotFound(target));
         }
 
+        // Ensure the edge satisfies the constraints of the domain, if any
+        if let Some(validator) = &self.validator {
+            if !validator(&self.nodes[source], &self.nodes[target]) {
+                return Err(Error::Rejected(source, target));
+            }
+        }
+
         // Add edge, as both nodes were found
         self.edges.push(Edge { source, target, weight });
         Ok(())
     }
 
+    /// Sets a validator for edges, returning the builder for chaining.
+    ///
+    /// The validator receives the data of the source and target node of each
+    /// edge that is added after it was set, and returns whether the edge is
+    /// allowed, which makes it possible to enforce domain constraints, e.g.,
+    /// that certain kinds of nodes must not depend on each other. Edges that
+    /// are rejected are not added, and [`Builder::add_edge`] returns an error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::error::Error;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use zrx_graph::Graph;
+    ///
+    /// // Create graph builder, only allowing edges to larger nodes
+    /// let mut builder = Graph::builder()
+    ///     .with_edge_validator(|source: &i32, target: &i32| source < target);
+    /// let a = builder.add_node(1);
+    /// let b = builder.add_node(2);
+    /// let c = builder.add_node(3);
+    ///
+    /// // Create edges between nodes
+    /// builder.add_edge(a, b, 0)?;
+    /// builder.add_edge(b, c, 0)?;
+    /// assert!(builder.add_edge(c, a, 0).is_err());
+    /// assert_eq!(builder.edges().len(), 2);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn with_edge_validator<F>(mut self, f: F) -> Self
+    where
+        F: Fn(&T, &T) -> bool + Send + Sync + 'static,
+    {
+        self.validator = Some(Arc::new(f));
+        self
+    }
+
+    /// Adds a node to the graph, returning the builder for chaining.
+    ///
+    /// This is a fluent variant of [`Builder::add_node`], which allows to build
+    /// simple graphs in a single expression. Node indices are assigned in the
+    /// order in which nodes are added, starting at `0`, so the first node has
+    /// index `0`, the second node has index `1`, and so on.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::error::Error;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use zrx_graph::Graph;
+    ///
+    /// // Create graph fluently
+    /// let graph = Graph::builder::<()>()
+    ///     .node("a")
+    ///     .node("b")
+    ///     .node("c")
+    ///     .edge(0, 1)?
+    ///     .edge(1, 2)?
+    ///     .build();
+    ///
+    /// // Obtain descendants of first node
+    /// let descendants: Vec<_> = graph.descendants(0).collect();
+    /// assert_eq!(descendants, [1, 2]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn node(mut self, node: T) -> Self {
+        self.add_node(node);
+        self
+    }
+
+    /// Adds an edge with a default weight to the graph, returning the builder
+    /// for chaining.
+    ///
+    /// This is a fluent variant of [`Builder::add_edge`], which allows to build
+    /// simple graphs in a single expression.
+    ///
+    /// # Errors
+    ///
+    /// In case the source or target node doesn't exist, [`Error::NotFound`] is
+    /// returned, just like with [`Builder::add_edge`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::error::Error;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use zrx_graph::Graph;
+    ///
+    /// // Create graph fluently
+    /// let builder = Graph::builder::<()>()
+    ///     .node("a")
+    ///     .node("b")
+    ///     .edge(0, 1)?;
+    ///
+    /// // Adding an edge to a missing node fails
+    /// assert!(builder.edge(1, 2).is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn edge(mut self, source: I, target: I) -> Result<Self>
+    where
+        W: Default,
+    {
+        self.add_edge(source, target, W::default())?;
+        Ok(self)
+    }
+
     /// Creates the edge graph of the graph.
     ///
     /// This method derives a new graph from the given graph in which each edge
@@ -231,6 +407,8 @@ impl<T, W> Builder<T, W> {
         Builder {
             nodes: self.edges.clone(),
             edges,
+            validator: None,
+            marker: PhantomData,
         }
     }
 
@@ -271,10 +449,57 @@ impl<T, W> Builder<T, W> {
             data: self.nodes,
         }
     }
+
+    /// Builds the graph without consuming the builder.
+    ///
+    /// This method is equivalent to [`Builder::build`], but clones the nodes,
+    /// so the builder remains editable, which allows to build a graph, inspect
+    /// it, and then add further nodes and edges to build a variant of it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::error::Error;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use zrx_graph::Graph;
+    ///
+    /// // Create graph builder and add nodes
+    /// let mut builder = Graph::builder();
+    /// let a = builder.add_node("a");
+    /// let b = builder.add_node("b");
+    /// let c = builder.add_node("c");
+    ///
+    /// // Create edges between nodes
+    /// builder.add_edge(a, b, 0)?;
+    ///
+    /// // Create graphs from builder, which are equal
+    /// let x = builder.build_ref();
+    /// let y = builder.build_ref();
+    /// assert!(x.iter().eq(y.iter()));
+    /// assert_eq!(x.topology().checksum(), y.topology().checksum());
+    ///
+    /// // Create variant of graph after adding an edge
+    /// builder.add_edge(b, c, 0)?;
+    /// let z = builder.build();
+    /// assert_ne!(x.topology().checksum(), z.topology().checksum());
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn build_ref(&self) -> Graph<T>
+    where
+        T: Clone,
+        W: Clone,
+    {
+        Graph {
+            topology: Topology::new(self),
+            data: self.nodes.clone(),
+        }
+    }
 }
 
 #[allow(clippy::must_use_candidate)]
-impl<T, W> Builder<T, W> {
+impl<T, W, I> Builder<T, W, I> {
     /// Returns a reference to the nodes.
     #[inline]
     pub fn nodes(&self) -> &[T] {
@@ -304,7 +529,10 @@ impl<T, W> Builder<T, W> {
 // Trait implementations
 // ----------------------------------------------------------------------------
 
-impl<T, W> Index<usize> for Builder<T, W> {
+impl<T, W, I> Index<I> for Builder<T, W, I>
+where
+    I: Copy + From<usize> + Into<usize>,
+{
     type Output = T;
 
     /// Returns a reference to the node at the index.
@@ -339,8 +567,8 @@ impl<T, W> Index<usize> for Builder<T, W> {
     /// # }
     /// ```
     #[inline]
-    fn index(&self, index: usize) -> &Self::Output {
-        &self.nodes[index]
+    fn index(&self, index: I) -> &Self::Output {
+        &self.nodes[index.into()]
     }
 }
 
@@ -374,3 +602,27 @@ where
         Self::new()
     }
 }
+
+// ----------------------------------------------------------------------------
+
+impl<T, W, I> fmt::Debug for Builder<T, W, I>
+where
+    T: fmt::Debug,
+    W: fmt::Debug,
+{
+    /// Formats the graph builder for debugging.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Builder")
+            .field("nodes", &self.nodes)
+            .field("edges", &self.edges)
+            .field("validator", &self.validator.is_some())
+            .finish()
+    }
+}
+
+// ----------------------------------------------------------------------------
+// Type aliases
+// ----------------------------------------------------------------------------
+
+/// Edge validator.
+type Validator<T> = Arc<dyn Fn(&T, &T) -> bool + Send + Sync>;
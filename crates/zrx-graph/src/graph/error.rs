@@ -42,6 +42,18 @@ pub enum Error {
     /// Node found.
     #[error("node found: {0}")]
     Found(usize),
+
+    /// Cycle detected.
+    #[error("cycle detected at node: {0}")]
+    Cycle(usize),
+
+    /// Degree exceeded.
+    #[error("degree exceeded at node: {0}")]
+    Degree(usize),
+
+    /// Edge rejected.
+    #[error("edge rejected by validator: {0} -> {1}")]
+    Rejected(usize, usize),
 }
 
 // ----------------------------------------------------------------------------
@@ -0,0 +1,221 @@
+// Copyright (c) Zensical LLC <https://zensical.org>
+
+// SPDX-License-Identifier: MIT
+// Third-party contributions licensed under CLA
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+
+// ----------------------------------------------------------------------------
+
+//! Node expansion.
+
+// ----------------------------------------------------------------------------
+// Structs
+// ----------------------------------------------------------------------------
+
+/// Node expansion.
+///
+/// Expansions describe the subgraph a single node is expanded into when using
+/// [`Graph::flat_map_nodes`][], consisting of nodes, edges between those nodes,
+/// and two designated ports: the input, to which all incoming edges of the
+/// original node are rewired, and the output, from which all outgoing edges of
+/// the original node originate. All indices are local to the expansion.
+///
+/// Any value can be converted into an expansion consisting of a single node,
+/// which is both the input and output, so nodes can be retained as they are.
+///
+/// [`Graph::flat_map_nodes`]: crate::graph::Graph::flat_map_nodes
+///
+/// # Examples
+///
+/// ```
+/// use zrx_graph::Expansion;
+///
+/// // Create expansion of three chained nodes
+/// let expansion = Expansion::chain(["a", "b", "c"]);
+/// assert_eq!(expansion.edges(), [(0, 1), (1, 2)]);
+/// assert_eq!(expansion.input(), 0);
+/// assert_eq!(expansion.output(), 2);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Expansion<U> {
+    /// Nodes of the expansion.
+    nodes: Vec<U>,
+    /// Edges between nodes of the expansion.
+    edges: Vec<(usize, usize)>,
+    /// Input port.
+    input: usize,
+    /// Output port.
+    output: usize,
+}
+
+// ----------------------------------------------------------------------------
+// Implementations
+// ----------------------------------------------------------------------------
+
+impl<U> Expansion<U> {
+    /// Creates an expansion without edges.
+    ///
+    /// The first node is used as the input, and the last node as the output,
+    /// which can be changed with [`Expansion::with_ports`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zrx_graph::Expansion;
+    ///
+    /// // Create expansion of two nodes
+    /// let expansion = Expansion::new(["a", "b"]).with_edge(0, 1);
+    /// assert_eq!(expansion.nodes(), ["a", "b"]);
+    /// ```
+    #[must_use]
+    pub fn new<N>(nodes: N) -> Self
+    where
+        N: IntoIterator<Item = U>,
+    {
+        let nodes: Vec<_> = nodes.into_iter().collect();
+        Self {
+            output: nodes.len().saturating_sub(1),
+            nodes,
+            edges: Vec::new(),
+            input: 0,
+        }
+    }
+
+    /// Creates an expansion of chained nodes.
+    ///
+    /// Each node is connected to the next node, so the first node is the input,
+    /// and the last node is the output of the expansion.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zrx_graph::Expansion;
+    ///
+    /// // Create expansion of three chained nodes
+    /// let expansion = Expansion::chain(["a", "b", "c"]);
+    /// assert_eq!(expansion.edges(), [(0, 1), (1, 2)]);
+    /// ```
+    #[must_use]
+    pub fn chain<N>(nodes: N) -> Self
+    where
+        N: IntoIterator<Item = U>,
+    {
+        let mut expansion = Self::new(nodes);
+        let len = expansion.nodes.len();
+        expansion.edges = (1..len).map(|node| (node - 1, node)).collect();
+        expansion
+    }
+
+    /// Adds an edge between two nodes, returning the expansion for chaining.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zrx_graph::Expansion;
+    ///
+    /// // Create expansion of a fork
+    /// let expansion = Expansion::new(["a", "b", "c"])
+    ///     .with_edge(0, 1)
+    ///     .with_edge(0, 2);
+    /// assert_eq!(expansion.edges(), [(0, 1), (0, 2)]);
+    /// ```
+    #[must_use]
+    pub fn with_edge(mut self, source: usize, target: usize) -> Self {
+        self.edges.push((source, target));
+        self
+    }
+
+    /// Sets the input and output ports, returning the expansion for chaining.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zrx_graph::Expansion;
+    ///
+    /// // Create expansion with custom ports
+    /// let expansion = Expansion::new(["a", "b"])
+    ///     .with_edge(1, 0)
+    ///     .with_ports(1, 0);
+    /// assert_eq!(expansion.input(), 1);
+    /// assert_eq!(expansion.output(), 0);
+    /// ```
+    #[must_use]
+    pub fn with_ports(mut self, input: usize, output: usize) -> Self {
+        self.input = input;
+        self.output = output;
+        self
+    }
+
+    /// Consumes the expansion, returning its parts.
+    pub(crate) fn into_parts(
+        self,
+    ) -> (Vec<U>, Vec<(usize, usize)>, usize, usize) {
+        (self.nodes, self.edges, self.input, self.output)
+    }
+}
+
+#[allow(clippy::must_use_candidate)]
+impl<U> Expansion<U> {
+    /// Returns a reference to the nodes.
+    #[inline]
+    pub fn nodes(&self) -> &[U] {
+        &self.nodes
+    }
+
+    /// Returns a reference to the edges.
+    #[inline]
+    pub fn edges(&self) -> &[(usize, usize)] {
+        &self.edges
+    }
+
+    /// Returns the input port.
+    #[inline]
+    pub fn input(&self) -> usize {
+        self.input
+    }
+
+    /// Returns the output port.
+    #[inline]
+    pub fn output(&self) -> usize {
+        self.output
+    }
+}
+
+// ----------------------------------------------------------------------------
+// Trait implementations
+// ----------------------------------------------------------------------------
+
+impl<U> From<U> for Expansion<U> {
+    /// Creates an expansion consisting of a single node.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zrx_graph::Expansion;
+    ///
+    /// // Create expansion from node
+    /// let expansion = Expansion::from("a");
+    /// assert_eq!(expansion.nodes(), ["a"]);
+    /// ```
+    #[inline]
+    fn from(node: U) -> Self {
+        Self::new([node])
+    }
+}
@@ -0,0 +1,211 @@
+// Copyright (c) Zensical LLC <https://zensical.org>
+
+// SPDX-License-Identifier: MIT
+// Third-party contributions licensed under CLA
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+
+// ----------------------------------------------------------------------------
+
+//! Node identifier.
+
+use std::cmp::Ordering;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::marker::PhantomData;
+
+// ----------------------------------------------------------------------------
+// Structs
+// ----------------------------------------------------------------------------
+
+/// Node identifier.
+///
+/// Node indices are plain [`usize`] values by default, which makes it easy to
+/// accidentally mix up indices of different graphs. This data type wraps the
+/// index and is parameterized by a zero-sized tag, so handles of graphs with
+/// different tags can't be used interchangeably. It's returned by and passed to
+/// a [`Builder`][] that was created with [`Builder::typed`][].
+///
+/// Node identifiers can be converted into [`usize`] and back through [`From`],
+/// which is the escape hatch for interacting with APIs that expect indices.
+///
+/// [`Builder`]: crate::graph::Builder
+/// [`Builder::typed`]: crate::graph::Builder::typed
+///
+/// # Examples
+///
+/// ```
+/// # use std::error::Error;
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// use zrx_graph::{Builder, NodeId};
+///
+/// // Create tag for graph
+/// struct Pipeline;
+///
+/// // Create graph builder and add nodes
+/// let mut builder: Builder<_, _, NodeId<Pipeline>> = Builder::typed();
+/// let a = builder.add_node("a");
+/// let b = builder.add_node("b");
+///
+/// // Create edges between nodes
+/// builder.add_edge(a, b, 0)?;
+/// assert_eq!(b.index(), 1);
+/// # Ok(())
+/// # }
+/// ```
+///
+/// Handles of graphs with different tags can't be mixed:
+///
+/// ``` compile_fail
+/// use zrx_graph::{Builder, NodeId};
+///
+/// // Create tags for graphs
+/// struct A;
+/// struct B;
+///
+/// // Create graph builders and add nodes
+/// let mut a: Builder<_, (), NodeId<A>> = Builder::typed();
+/// let mut b: Builder<_, (), NodeId<B>> = Builder::typed();
+/// let x = a.add_node("x");
+/// let y = b.add_node("y");
+///
+/// // Create edge between nodes of different graphs
+/// a.add_edge(x, y, ());
+/// ```
+pub struct NodeId<Tag> {
+    /// Node index.
+    index: usize,
+    /// Type marker.
+    marker: PhantomData<fn() -> Tag>,
+}
+
+// ----------------------------------------------------------------------------
+// Implementations
+// ----------------------------------------------------------------------------
+
+impl<Tag> NodeId<Tag> {
+    /// Creates a node identifier from the given index.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zrx_graph::NodeId;
+    ///
+    /// // Create node identifier
+    /// let id = NodeId::<()>::new(0);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn new(index: usize) -> Self {
+        Self { index, marker: PhantomData }
+    }
+}
+
+#[allow(clippy::must_use_candidate)]
+impl<Tag> NodeId<Tag> {
+    /// Returns the node index.
+    #[inline]
+    pub fn index(&self) -> usize {
+        self.index
+    }
+}
+
+// ----------------------------------------------------------------------------
+// Trait implementations
+// ----------------------------------------------------------------------------
+
+impl<Tag> From<usize> for NodeId<Tag> {
+    /// Creates a node identifier from an index.
+    #[inline]
+    fn from(index: usize) -> Self {
+        Self::new(index)
+    }
+}
+
+impl<Tag> From<NodeId<Tag>> for usize {
+    /// Returns the index of a node identifier.
+    #[inline]
+    fn from(id: NodeId<Tag>) -> Self {
+        id.index
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+impl<Tag> Clone for NodeId<Tag> {
+    // relax trait bounds
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<Tag> Copy for NodeId<Tag> {}
+
+// ----------------------------------------------------------------------------
+
+impl<Tag> PartialEq for NodeId<Tag> {
+    /// Compares two node identifiers for equality.
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.index == other.index
+    }
+}
+
+impl<Tag> Eq for NodeId<Tag> {}
+
+// ----------------------------------------------------------------------------
+
+impl<Tag> PartialOrd for NodeId<Tag> {
+    /// Orders two node identifiers.
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<Tag> Ord for NodeId<Tag> {
+    /// Orders two node identifiers.
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.index.cmp(&other.index)
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+impl<Tag> Hash for NodeId<Tag> {
+    /// Hashes the node identifier.
+    #[inline]
+    fn hash<H>(&self, state: &mut H)
+    where
+        H: Hasher,
+    {
+        self.index.hash(state);
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+impl<Tag> fmt::Debug for NodeId<Tag> {
+    /// Formats the node identifier for debugging.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("NodeId").field(&self.index).finish()
+    }
+}
@@ -55,6 +55,8 @@ pub use distance::Distance;
 ///
 /// Cloning is very cheap, since both incoming and outgoing edges are stored in
 /// [`Rc`] smart pointers, so they can be shared among multiple traversals.
+/// Additionally, a checksum over the adjacency lists is computed once, which
+/// allows to cheaply detect structural changes, e.g., to invalidate caches.
 ///
 /// [`Traversal`]: crate::graph::traversal::Traversal
 #[derive(Clone, Debug)]
@@ -65,6 +67,12 @@ pub struct Topology {
     incoming: Rc<Adjacency>,
     /// Distance matrix.
     distance: Rc<Distance>,
+    /// Nodes without incoming edges.
+    sources: Rc<[usize]>,
+    /// Nodes without outgoing edges.
+    sinks: Rc<[usize]>,
+    /// Checksum over outgoing edges.
+    checksum: u64,
 }
 
 // ----------------------------------------------------------------------------
@@ -104,16 +112,223 @@ impl Topology {
     /// # }
     /// ```
     #[must_use]
-    pub fn new<T, W>(builder: &Builder<T, W>) -> Self
+    pub fn new<T, W, I>(builder: &Builder<T, W, I>) -> Self
     where
         W: Clone,
     {
+        let outgoing = Adjacency::outgoing(builder);
+        let incoming = Adjacency::incoming(builder);
+
+        // Precompute sources and sinks, as they're queried frequently, and
+        // computing them requires a scan over all nodes of the graph
+        let sources = incoming.iter().filter(|&node| incoming[node].is_empty());
+        let sinks = outgoing.iter().filter(|&node| outgoing[node].is_empty());
         Self {
-            outgoing: Rc::new(Adjacency::outgoing(builder)),
-            incoming: Rc::new(Adjacency::incoming(builder)),
+            checksum: checksum(&outgoing),
+            sources: sources.collect(),
+            sinks: sinks.collect(),
+            outgoing: Rc::new(outgoing),
+            incoming: Rc::new(incoming),
             distance: Rc::new(Distance::new(builder)),
         }
     }
+
+    /// Returns the topology with all edges reversed.
+    ///
+    /// Outgoing and incoming edges are swapped, as are sources and sinks, which
+    /// is cheap, since the adjacency lists are shared. The [`Distance`] matrix
+    /// is transposed rather than recomputed, so shortest paths in the reversed
+    /// topology are the shortest paths of the original one, walked backwards.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::error::Error;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use zrx_graph::{Graph, Topology};
+    ///
+    /// // Create graph builder and add nodes
+    /// let mut builder = Graph::builder();
+    /// let a = builder.add_node("a");
+    /// let b = builder.add_node("b");
+    ///
+    /// // Create edges between nodes
+    /// builder.add_edge(a, b, 0)?;
+    ///
+    /// // Create topology and reverse it
+    /// let topology = Topology::new(&builder).reverse();
+    /// assert_eq!(topology.out_neighbors(b), [a]);
+    /// assert_eq!(topology.sources(), [b]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn reverse(&self) -> Self {
+        Self {
+            checksum: checksum(&self.incoming),
+            sources: Rc::clone(&self.sinks),
+            sinks: Rc::clone(&self.sources),
+            outgoing: Rc::clone(&self.incoming),
+            incoming: Rc::clone(&self.outgoing),
+            distance: Rc::new(self.distance.transpose()),
+        }
+    }
+
+    /// Returns the nodes reachable from the given node via outgoing edges.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the node is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::error::Error;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use zrx_graph::{Graph, Topology};
+    ///
+    /// // Create graph builder and add nodes
+    /// let mut builder = Graph::builder();
+    /// let a = builder.add_node("a");
+    /// let b = builder.add_node("b");
+    /// let c = builder.add_node("c");
+    ///
+    /// // Create edges between nodes
+    /// builder.add_edge(a, b, 0)?;
+    ///
+    /// // Create topology and obtain neighbors
+    /// let topology = Topology::new(&builder);
+    /// assert_eq!(topology.out_neighbors(a), [b]);
+    /// assert!(topology.out_neighbors(c).is_empty());
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn out_neighbors(&self, node: usize) -> &[usize] {
+        &self.outgoing[node]
+    }
+
+    /// Returns the nodes the given node is reachable from via incoming edges.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the node is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::error::Error;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use zrx_graph::{Graph, Topology};
+    ///
+    /// // Create graph builder and add nodes
+    /// let mut builder = Graph::builder();
+    /// let a = builder.add_node("a");
+    /// let b = builder.add_node("b");
+    /// let c = builder.add_node("c");
+    ///
+    /// // Create edges between nodes
+    /// builder.add_edge(a, b, 0)?;
+    ///
+    /// // Create topology and obtain neighbors
+    /// let topology = Topology::new(&builder);
+    /// assert_eq!(topology.in_neighbors(b), [a]);
+    /// assert!(topology.in_neighbors(c).is_empty());
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn in_neighbors(&self, node: usize) -> &[usize] {
+        &self.incoming[node]
+    }
+
+    /// Returns the outgoing edges in Compressed Sparse Row (CSR) form.
+    ///
+    /// The first slice contains the offsets, and the second slice the targets
+    /// of all edges, so the targets of a node `n` are located in the range from
+    /// `offsets[n]` to `offsets[n + 1]`. Since [`Adjacency`] lists are already
+    /// stored in this form, no computation is necessary, and scans over all
+    /// edges are as cache-friendly as possible, which is useful for algorithms
+    /// on large graphs that need to touch every edge.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::error::Error;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use zrx_graph::{Graph, Topology};
+    ///
+    /// // Create graph builder and add nodes
+    /// let mut builder = Graph::builder();
+    /// let a = builder.add_node("a");
+    /// let b = builder.add_node("b");
+    /// let c = builder.add_node("c");
+    ///
+    /// // Create edges between nodes
+    /// builder.add_edge(a, b, 0)?;
+    /// builder.add_edge(a, c, 0)?;
+    /// builder.add_edge(b, c, 0)?;
+    ///
+    /// // Create topology and obtain edges
+    /// let topology = Topology::new(&builder);
+    /// let (offsets, targets) = topology.csr();
+    /// assert_eq!(offsets, [0, 2, 3, 3]);
+    /// assert_eq!(targets, [b, c, c]);
+    ///
+    /// // Ensure edges equal those of all nodes
+    /// for node in topology.outgoing() {
+    ///     let range = offsets[node]..offsets[node + 1];
+    ///     assert_eq!(&targets[range], topology.out_neighbors(node));
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn csr(&self) -> (&[usize], &[usize]) {
+        (self.outgoing.rows(), self.outgoing.columns())
+    }
+
+    /// Returns the checksum of the topology.
+    ///
+    /// The checksum is computed once when the topology is created, using the
+    /// 64-bit FNV-1a hash over the outgoing adjacency lists, which is stable
+    /// across runs, platforms and compiler versions, so it can be persisted.
+    /// Topologies with identical edges share the same checksum, regardless of
+    /// the data associated with their nodes, or the weights of their edges.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::error::Error;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use zrx_graph::{Graph, Topology};
+    ///
+    /// // Create graph builder and add nodes
+    /// let mut builder = Graph::builder();
+    /// let a = builder.add_node("a");
+    /// let b = builder.add_node("b");
+    /// let c = builder.add_node("c");
+    ///
+    /// // Create edges between nodes
+    /// builder.add_edge(a, b, 0)?;
+    /// builder.add_edge(b, c, 0)?;
+    ///
+    /// // Create topologies with identical and changed edges
+    /// let topology = Topology::new(&builder);
+    /// assert_eq!(topology.checksum(), Topology::new(&builder).checksum());
+    /// builder.add_edge(a, c, 0)?;
+    /// assert_ne!(topology.checksum(), Topology::new(&builder).checksum());
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn checksum(&self) -> u64 {
+        self.checksum
+    }
 }
 
 #[allow(clippy::must_use_candidate)]
@@ -135,4 +350,101 @@ impl Topology {
     pub fn distance(&self) -> &Distance {
         &self.distance
     }
+
+    /// Returns a reference to the sources, i.e., nodes without incoming edges.
+    #[inline]
+    pub fn sources(&self) -> &[usize] {
+        &self.sources
+    }
+
+    /// Returns a reference to the sinks, i.e., nodes without outgoing edges.
+    #[inline]
+    pub fn sinks(&self) -> &[usize] {
+        &self.sinks
+    }
+}
+
+// ----------------------------------------------------------------------------
+// Constants
+// ----------------------------------------------------------------------------
+
+/// FNV-1a offset basis for 64-bit hashes.
+const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+
+/// FNV-1a prime for 64-bit hashes.
+const FNV_PRIME: u64 = 0x0100_0000_01b3;
+
+// ----------------------------------------------------------------------------
+// Functions
+// ----------------------------------------------------------------------------
+
+/// Computes the checksum over the given adjacency list.
+///
+/// The number of neighbors is hashed before the neighbors of each node, so
+/// edges can't be shifted between nodes without changing the checksum. Since
+/// the neighbors of each node retain the order in which edges were added, they
+/// are hashed in sorted order, so the checksum only depends on the edges.
+fn checksum(adjacency: &Adjacency) -> u64 {
+    let mut hash = fnv(FNV_OFFSET_BASIS, adjacency.len());
+
+    // Hash the neighbors of each node in sorted order, reusing the buffer, as
+    // the number of neighbors of each node is bounded by the maximum degree
+    let mut row = Vec::new();
+    for node in adjacency {
+        row.clear();
+        row.extend_from_slice(&adjacency[node]);
+        row.sort_unstable();
+        hash = fnv(hash, row.len());
+        hash = row.iter().fold(hash, |hash, &value| fnv(hash, value));
+    }
+    hash
+}
+
+/// Folds the given value into the given hash using 64-bit FNV-1a.
+///
+/// Values are hashed as fixed-width integers, so the checksum is the same
+/// across platforms, regardless of the width of pointer-sized integers.
+fn fnv(hash: u64, value: usize) -> u64 {
+    (value as u64)
+        .to_le_bytes()
+        .into_iter()
+        .fold(hash, |hash, byte| {
+            (hash ^ u64::from(byte)).wrapping_mul(FNV_PRIME)
+        })
+}
+
+// ----------------------------------------------------------------------------
+// Tests
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+
+    mod checksum {
+        use crate::graph::{Graph, Topology};
+
+        #[test]
+        fn ignores_order_of_edges() {
+            let mut a = Graph::builder();
+            let mut b = Graph::builder();
+            for node in 0..4 {
+                a.add_node(node);
+                b.add_node(node);
+            }
+
+            // Add the same edges in different orders
+            for (source, target) in [(0, 1), (0, 2), (0, 3), (1, 3), (2, 3)] {
+                a.add_edge(source, target, ()).unwrap();
+            }
+            for (source, target) in [(2, 3), (0, 3), (1, 3), (0, 2), (0, 1)] {
+                b.add_edge(source, target, ()).unwrap();
+            }
+
+            // Ensure checksums are identical
+            assert_eq!(
+                Topology::new(&a).checksum(),
+                Topology::new(&b).checksum()
+            );
+        }
+    }
 }
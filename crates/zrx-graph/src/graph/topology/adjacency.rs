@@ -93,7 +93,7 @@ impl Adjacency {
     /// ```
     #[inline]
     #[must_use]
-    pub fn outgoing<T, W>(builder: &Builder<T, W>) -> Self
+    pub fn outgoing<T, W, I>(builder: &Builder<T, W, I>) -> Self
     where
         W: Clone,
     {
@@ -134,7 +134,7 @@ impl Adjacency {
     /// ```
     #[inline]
     #[must_use]
-    pub fn incoming<T, W>(builder: &Builder<T, W>) -> Self
+    pub fn incoming<T, W, I>(builder: &Builder<T, W, I>) -> Self
     where
         W: Clone,
     {
@@ -241,6 +241,18 @@ impl Adjacency {
 
 #[allow(clippy::must_use_candidate)]
 impl Adjacency {
+    /// Returns a reference to the row pointers.
+    #[inline]
+    pub fn rows(&self) -> &[usize] {
+        &self.rows
+    }
+
+    /// Returns a reference to the column indices.
+    #[inline]
+    pub fn columns(&self) -> &[usize] {
+        &self.columns
+    }
+
     /// Returns a reference to the in- or out-degrees.
     #[inline]
     pub fn degrees(&self) -> &[u8] {
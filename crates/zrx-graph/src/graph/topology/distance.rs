@@ -79,7 +79,7 @@ impl Distance {
     /// # }
     /// ```
     #[must_use]
-    pub fn new<T, W>(builder: &Builder<T, W>) -> Self
+    pub fn new<T, W, I>(builder: &Builder<T, W, I>) -> Self
     where
         W: Clone,
     {
@@ -101,6 +101,49 @@ impl Distance {
         floyd_warshall(&mut dist);
         dist
     }
+
+    /// Returns the transposed distance matrix.
+    ///
+    /// The distance from `a` to `b` in the transposed matrix equals the one
+    /// from `b` to `a` in the original matrix, which corresponds to the graph
+    /// with all edges reversed, so it doesn't need to be recomputed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::error::Error;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use zrx_graph::topology::Distance;
+    /// use zrx_graph::Graph;
+    ///
+    /// // Create graph builder and add nodes
+    /// let mut builder = Graph::builder();
+    /// let a = builder.add_node("a");
+    /// let b = builder.add_node("b");
+    /// let c = builder.add_node("c");
+    ///
+    /// // Create edges between nodes
+    /// builder.add_edge(a, b, 0)?;
+    /// builder.add_edge(b, c, 0)?;
+    ///
+    /// // Create and transpose distance matrix
+    /// let dist = Distance::new(&builder).transpose();
+    /// assert_eq!(dist[c][a], 2);
+    /// assert_eq!(dist[a][c], u8::MAX);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn transpose(&self) -> Self {
+        let n = self.rows;
+        let mut data = vec![u8::MAX; n * n];
+        for i in 0..n {
+            for j in 0..n {
+                data[j * n + i] = self.columns[i * n + j];
+            }
+        }
+        Self { rows: n, columns: data }
+    }
 }
 
 // ----------------------------------------------------------------------------
@@ -59,6 +59,17 @@ pub struct Traversal {
     visitable: VecDeque<usize>,
 }
 
+/// Topological traversal iterator.
+///
+/// This iterator is created by [`Traversal::into_iter`], and marks each node
+/// as visited right after yielding it. It's a convenience for read-only use
+/// cases that don't need to control when dependent nodes become visitable.
+#[derive(Clone, Debug)]
+pub struct IntoIter {
+    /// Topological traversal.
+    traversal: Traversal,
+}
+
 // ----------------------------------------------------------------------------
 // Implementations
 // ----------------------------------------------------------------------------
@@ -249,6 +260,100 @@ impl Traversal {
         // No errors occurred.
         Ok(())
     }
+
+    /// Marks the given node as failed, skipping all of its descendants.
+    ///
+    /// This method marks a node as visited, but in contrast to [`Traversal::complete`],
+    /// none of its dependents are released, since a failure must not trigger
+    /// the execution of nodes that depend on the failed node. Instead, all of
+    /// its descendants are marked as visited as well, as they will never be
+    /// visitable, and returned in ascending order, so the caller can report
+    /// them as skipped. Nodes that are not descendants are not affected, so
+    /// the traversal can be continued with [`Traversal::take`].
+    ///
+    /// # Errors
+    ///
+    /// In case the node has already been marked as visited, [`Error::Found`]
+    /// is returned. This is likely an error in the traversal business logic.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a node does not exist, as this indicates that there's a bug
+    /// in the code that creates or uses the traversal.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::error::Error;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use zrx_graph::Graph;
+    ///
+    /// // Create graph builder and add nodes
+    /// let mut builder = Graph::builder();
+    /// let a = builder.add_node("a");
+    /// let b = builder.add_node("b");
+    /// let c = builder.add_node("c");
+    /// let d = builder.add_node("d");
+    /// let e = builder.add_node("e");
+    ///
+    /// // Create edges between nodes
+    /// builder.add_edge(a, b, 0)?;
+    /// builder.add_edge(b, c, 0)?;
+    /// builder.add_edge(c, d, 0)?;
+    /// builder.add_edge(a, e, 0)?;
+    ///
+    /// // Create graph from builder
+    /// let graph = builder.build();
+    ///
+    /// // Create topological traversal, and fail the middle node
+    /// let mut traversal = graph.traverse([a]);
+    /// let mut visited = Vec::new();
+    /// let mut skipped = Vec::new();
+    /// while let Some(node) = traversal.take() {
+    ///     if node == b {
+    ///         skipped.extend(traversal.fail(node)?);
+    ///     } else {
+    ///         visited.push(node);
+    ///         traversal.complete(node)?;
+    ///     }
+    /// }
+    ///
+    /// // Ensure downstream nodes were skipped
+    /// assert_eq!(visited, [a, e]);
+    /// assert_eq!(skipped, [c, d]);
+    /// assert!(traversal.complete(c).is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn fail(&mut self, node: usize) -> Result<Vec<usize>> {
+        if self.dependencies[node] == u8::MAX {
+            return Err(Error::Found(node));
+        }
+
+        // Mark node as visited, and ensure it's not visitable anymore, in case
+        // it's failed before it was taken from the queue of visitable nodes
+        self.dependencies[node] = u8::MAX;
+        self.visitable.retain(|&visitable| visitable != node);
+
+        // Perform a depth-first search to mark all descendants as visited, as
+        // they depend on the failed node, so they would never become visitable
+        let outgoing = self.topology.outgoing();
+        let mut skipped = Vec::new();
+        let mut stack = Vec::from([node]);
+        while let Some(node) = stack.pop() {
+            for &descendant in &outgoing[node] {
+                if self.dependencies[descendant] != u8::MAX {
+                    self.dependencies[descendant] = u8::MAX;
+                    skipped.push(descendant);
+                    stack.push(descendant);
+                }
+            }
+        }
+
+        // Return skipped nodes in ascending order
+        skipped.sort_unstable();
+        Ok(skipped)
+    }
 }
 
 #[allow(clippy::must_use_candidate)]
@@ -271,3 +376,78 @@ impl Traversal {
         self.visitable.is_empty()
     }
 }
+
+// ----------------------------------------------------------------------------
+// Trait implementations
+// ----------------------------------------------------------------------------
+
+impl IntoIterator for Traversal {
+    type Item = usize;
+    type IntoIter = IntoIter;
+
+    /// Creates an iterator over the topological traversal.
+    ///
+    /// Each node is immediately marked as visited when it's yielded, so the
+    /// nodes are emitted in topological order without the need to invoke
+    /// [`Traversal::complete`] manually.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::error::Error;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use zrx_graph::Graph;
+    ///
+    /// // Create graph builder and add nodes
+    /// let mut builder = Graph::builder();
+    /// let a = builder.add_node("a");
+    /// let b = builder.add_node("b");
+    /// let c = builder.add_node("c");
+    /// let d = builder.add_node("d");
+    ///
+    /// // Create edges between nodes
+    /// builder.add_edge(a, c, 0)?;
+    /// builder.add_edge(b, c, 0)?;
+    /// builder.add_edge(c, d, 0)?;
+    /// builder.add_edge(a, d, 0)?;
+    ///
+    /// // Create graph from builder
+    /// let graph = builder.build();
+    ///
+    /// // Create topological traversal and collect nodes
+    /// let nodes: Vec<_> = graph.traverse([a, b]).into_iter().collect();
+    /// assert_eq!(nodes.len(), graph.len());
+    ///
+    /// // Ensure each node is yielded after all of its dependencies
+    /// let position = |node| nodes.iter().position(|&n| n == node);
+    /// for node in graph.topology().incoming() {
+    ///     for &dependency in &graph.topology().incoming()[node] {
+    ///         assert!(position(dependency) < position(node));
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter { traversal: self }
+    }
+}
+
+impl Iterator for IntoIter {
+    type Item = usize;
+
+    /// Returns the next node in topological order.
+    fn next(&mut self) -> Option<Self::Item> {
+        // Nodes that were passed more than once as initial nodes are skipped,
+        // as they have already been marked as visited before
+        while let Some(node) = self.traversal.take() {
+            if self.traversal.complete(node).is_ok() {
+                return Some(node);
+            }
+        }
+
+        // No more visitable nodes
+        None
+    }
+}
@@ -0,0 +1,197 @@
+// Copyright (c) Zensical LLC <https://zensical.org>
+
+// SPDX-License-Identifier: MIT
+// Third-party contributions licensed under CLA
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+
+// ----------------------------------------------------------------------------
+
+//! Graph validation.
+
+use ahash::HashSet;
+
+use super::topology::Topology;
+
+// ----------------------------------------------------------------------------
+// Structs
+// ----------------------------------------------------------------------------
+
+/// Graph validation report.
+///
+/// This data type collects all structural issues of a graph in a single pass,
+/// instead of failing on the first problem that is encountered, which makes it
+/// suitable for tooling that wants to present a full report to the user. Note
+/// that the [`Builder`][] deliberately allows for all of those structures, as
+/// some of them are valid in certain scenarios, e.g., multiple edges between
+/// the same nodes, so it's up to the caller to decide which issues matter.
+///
+/// All issues are sorted by node index, so reports are deterministic.
+///
+/// [`Builder`]: crate::graph::Builder
+///
+/// # Examples
+///
+/// ```
+/// # use std::error::Error;
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// use zrx_graph::Graph;
+///
+/// // Create graph builder and add nodes
+/// let mut builder = Graph::builder();
+/// let a = builder.add_node("a");
+/// let b = builder.add_node("b");
+/// let c = builder.add_node("c");
+///
+/// // Create edges between nodes
+/// builder.add_edge(a, b, 0)?;
+/// builder.add_edge(b, c, 0)?;
+///
+/// // Create graph from builder and validate it
+/// let graph = builder.build();
+/// assert!(graph.validate().is_empty());
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ValidationReport {
+    /// Nodes with an edge to themselves.
+    pub self_loops: Vec<usize>,
+    /// Edges that occur more than once.
+    pub duplicate_edges: Vec<(usize, usize)>,
+    /// Nodes without incoming or outgoing edges.
+    pub isolated_nodes: Vec<usize>,
+    /// Strongly connected components with more than one node.
+    pub cycles: Vec<Vec<usize>>,
+}
+
+// ----------------------------------------------------------------------------
+// Implementations
+// ----------------------------------------------------------------------------
+
+impl ValidationReport {
+    /// Creates a validation report for the given topology.
+    ///
+    /// Note that the canonical way to create a [`ValidationReport`] is to
+    /// invoke the [`Graph::validate`][] method.
+    ///
+    /// [`Graph::validate`]: crate::graph::Graph::validate
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::error::Error;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use zrx_graph::validation::ValidationReport;
+    /// use zrx_graph::Graph;
+    ///
+    /// // Create graph builder and add nodes
+    /// let mut builder = Graph::builder();
+    /// let a = builder.add_node("a");
+    /// let b = builder.add_node("b");
+    ///
+    /// // Create edges between nodes
+    /// builder.add_edge(a, b, 0)?;
+    ///
+    /// // Create graph from builder
+    /// let graph = builder.build();
+    ///
+    /// // Create validation report
+    /// let report = ValidationReport::new(graph.topology());
+    /// assert!(report.is_empty());
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn new(topology: &Topology) -> Self {
+        let outgoing = topology.outgoing();
+        let incoming = topology.incoming();
+        let distance = topology.distance();
+
+        // Check the outgoing edges of each node for self-loops and duplicates,
+        // and whether the node is isolated, i.e., has no edges at all
+        let mut report = Self::default();
+        for node in outgoing {
+            let mut targets = HashSet::default();
+            for &target in &outgoing[node] {
+                if target == node && !report.self_loops.contains(&node) {
+                    report.self_loops.push(node);
+                }
+                if !targets.insert(target)
+                    && !report.duplicate_edges.contains(&(node, target))
+                {
+                    report.duplicate_edges.push((node, target));
+                }
+            }
+
+            // Nodes with self-loops are not considered to be isolated
+            if outgoing[node].is_empty() && incoming[node].is_empty() {
+                report.isolated_nodes.push(node);
+            }
+        }
+
+        // Nodes that are mutually reachable form a strongly connected component,
+        // which we can determine through the distance matrix. Each component
+        // with more than one node contains at least one cycle.
+        let mut visited = vec![false; outgoing.len()];
+        for node in outgoing {
+            if visited[node] {
+                continue;
+            }
+
+            // Collect all nodes that are reachable from and can reach the node
+            let component = outgoing
+                .iter()
+                .filter(|&other| {
+                    distance[node][other] != u8::MAX
+                        && distance[other][node] != u8::MAX
+                })
+                .collect::<Vec<_>>();
+
+            // Mark all nodes of the component as visited
+            for &other in &component {
+                visited[other] = true;
+            }
+            if component.len() > 1 {
+                report.cycles.push(component);
+            }
+        }
+
+        // Return validation report
+        report
+    }
+}
+
+#[allow(clippy::must_use_candidate)]
+impl ValidationReport {
+    /// Returns the number of issues.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.self_loops.len()
+            + self.duplicate_edges.len()
+            + self.isolated_nodes.len()
+            + self.cycles.len()
+    }
+
+    /// Returns whether there are any issues.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
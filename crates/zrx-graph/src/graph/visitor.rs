@@ -26,9 +26,15 @@
 //! Visitors.
 
 mod ancestor;
+mod bfs;
 mod descendant;
+mod dfs;
 mod path;
+#[cfg(test)]
+mod testing;
 
 pub use ancestor::Ancestors;
-pub use descendant::Descendants;
+pub use bfs::Bfs;
+pub use descendant::{Descendants, FilteredDescendants};
+pub use dfs::Dfs;
 pub use path::Paths;
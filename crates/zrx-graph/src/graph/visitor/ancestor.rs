@@ -26,6 +26,7 @@
 //! Visitor for ancestors of a node.
 
 use ahash::HashSet;
+use std::borrow::BorrowMut;
 
 use crate::graph::topology::Topology;
 
@@ -34,13 +35,21 @@ use crate::graph::topology::Topology;
 // ----------------------------------------------------------------------------
 
 /// Visitor for ancestors of a node.
-pub struct Ancestors<'a> {
+///
+/// Ancestors are yielded in depth-first pre-order, i.e., as soon as they're
+/// discovered, which means that a node reaching the given node via multiple
+/// paths might be yielded before all of its outgoing edges have been seen, so
+/// the order is not topological. Nodes are marked as visited on discovery, so
+/// every node is yielded exactly once, regardless of the number of paths.
+pub struct Ancestors<'a, V = HashSet<usize>> {
     /// Graph topology.
     topology: &'a Topology,
     /// Stack for depth-first search.
     stack: Vec<usize>,
-    /// Set of visited nodes.
-    visited: HashSet<usize>,
+    /// Set of visited nodes, owned or borrowed.
+    visited: V,
+    /// Set of nodes that are yielded, but not expanded.
+    stop: Option<&'a HashSet<usize>>,
 }
 
 // ----------------------------------------------------------------------------
@@ -55,6 +64,112 @@ impl<'a> Ancestors<'a> {
             topology,
             stack: Vec::from([node]),
             visited: HashSet::default(),
+            stop: None,
+        }
+    }
+
+    /// Creates a visitor that yields all ancestors of the given node, treating
+    /// the nodes of the given set as walls.
+    ///
+    /// Nodes in the stop set are yielded when discovered, but their ancestors
+    /// are not explored, unless they're reachable via other paths. This allows
+    /// to prune regions of the graph that are already known to be processed.
+    /// Note that the given node itself is always expanded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::error::Error;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use ahash::HashSet;
+    /// use zrx_graph::visitor::Ancestors;
+    /// use zrx_graph::Graph;
+    ///
+    /// // Create graph builder and add nodes
+    /// let mut builder = Graph::builder();
+    /// let a = builder.add_node("a");
+    /// let b = builder.add_node("b");
+    /// let c = builder.add_node("c");
+    ///
+    /// // Create edges between nodes
+    /// builder.add_edge(a, b, 0)?;
+    /// builder.add_edge(b, c, 0)?;
+    ///
+    /// // Create graph from builder
+    /// let graph = builder.build();
+    ///
+    /// // Create iterator over ancestors, stopping at b
+    /// let stop = HashSet::from_iter([b]);
+    /// let ancestors = Ancestors::new_until(graph.topology(), c, &stop);
+    /// assert_eq!(ancestors.collect::<Vec<_>>(), [b]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[allow(clippy::implicit_hasher)]
+    #[must_use]
+    pub fn new_until(
+        topology: &'a Topology, node: usize, stop: &'a HashSet<usize>,
+    ) -> Self {
+        Self {
+            topology,
+            stack: Vec::from([node]),
+            visited: HashSet::default(),
+            stop: Some(stop),
+        }
+    }
+}
+
+#[allow(clippy::implicit_hasher)]
+impl<'a> Ancestors<'a, &'a mut HashSet<usize>> {
+    /// Creates a visitor that yields all ancestors of the given node, using the
+    /// given set as scratch space for visited nodes.
+    ///
+    /// The set is cleared before use, retaining its capacity, which allows to
+    /// reuse a single allocation when visiting the ancestors of many nodes. After
+    /// the visitor is exhausted, the set contains exactly the ancestors.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::error::Error;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use ahash::HashSet;
+    /// use zrx_graph::visitor::Ancestors;
+    /// use zrx_graph::Graph;
+    ///
+    /// // Create graph builder and add nodes
+    /// let mut builder = Graph::builder();
+    /// let a = builder.add_node("a");
+    /// let b = builder.add_node("b");
+    /// let c = builder.add_node("c");
+    ///
+    /// // Create edges between nodes
+    /// builder.add_edge(a, b, 0)?;
+    /// builder.add_edge(b, c, 0)?;
+    ///
+    /// // Create graph from builder
+    /// let graph = builder.build();
+    ///
+    /// // Create iterators over ancestors, reusing scratch space
+    /// let mut visited = HashSet::default();
+    /// for node in [a, b, c] {
+    ///     let ancestors =
+    ///         Ancestors::new_with_scratch(graph.topology(), node, &mut visited);
+    ///     println!("{:?}", ancestors.collect::<Vec<_>>());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn new_with_scratch(
+        topology: &'a Topology, node: usize, visited: &'a mut HashSet<usize>,
+    ) -> Self {
+        visited.clear();
+        Self {
+            topology,
+            stack: Vec::from([node]),
+            visited,
+            stop: None,
         }
     }
 }
@@ -63,7 +178,10 @@ impl<'a> Ancestors<'a> {
 // Trait implementations
 // ----------------------------------------------------------------------------
 
-impl Iterator for Ancestors<'_> {
+impl<V> Iterator for Ancestors<'_, V>
+where
+    V: BorrowMut<HashSet<usize>>,
+{
     type Item = usize;
 
     /// Returns the next ancestor.
@@ -105,9 +223,15 @@ impl Iterator for Ancestors<'_> {
         while let Some(node) = self.stack.pop() {
             for &ancestor in &incoming[node] {
                 // If we haven't visited this ancestor yet, we put it on the
-                // stack after marking it as visited and return it immediately
-                if self.visited.insert(ancestor) {
-                    self.stack.push(ancestor);
+                // stack after marking it as visited and return it immediately.
+                // The current node is put back first, so its remaining ancestors
+                // are visited once the ancestor has been fully explored. Nodes
+                // in the stop set are returned, but never put on the stack.
+                if self.visited.borrow_mut().insert(ancestor) {
+                    self.stack.push(node);
+                    if !self.stop.is_some_and(|stop| stop.contains(&ancestor)) {
+                        self.stack.push(ancestor);
+                    }
                     return Some(ancestor);
                 }
             }
@@ -117,3 +241,45 @@ impl Iterator for Ancestors<'_> {
         None
     }
 }
+
+// ----------------------------------------------------------------------------
+// Tests
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+
+    mod next {
+        use ahash::HashSet;
+
+        use crate::graph::visitor::testing::{diamonds, orders};
+        use crate::graph::visitor::Ancestors;
+
+        #[test]
+        fn yields_shared_ancestors_once() {
+            for order in orders() {
+                let graph = diamonds(&order);
+                let mut nodes = graph.ancestors(6).collect::<Vec<_>>();
+                nodes.sort_unstable();
+                assert_eq!(nodes, [0, 1, 2, 3, 4, 5], "order {order:?}");
+            }
+        }
+
+        #[test]
+        fn yields_same_with_scratch() {
+            let graph = diamonds(&(0..8).collect::<Vec<_>>());
+            let topology = graph.topology();
+
+            // Reuse scratch space across all nodes, starting with a dirty set
+            let mut visited = HashSet::from_iter([6]);
+            for node in 0..graph.len() {
+                let fresh = Ancestors::new(topology, node).collect::<Vec<_>>();
+                let reused =
+                    Ancestors::new_with_scratch(topology, node, &mut visited)
+                        .collect::<Vec<_>>();
+                assert_eq!(fresh, reused, "node {node}");
+                assert_eq!(visited, fresh.into_iter().collect());
+            }
+        }
+    }
+}
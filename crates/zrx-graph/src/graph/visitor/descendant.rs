@@ -26,6 +26,7 @@
 //! Visitor for descendants of a node.
 
 use ahash::HashSet;
+use std::borrow::BorrowMut;
 
 use crate::graph::topology::Topology;
 
@@ -34,11 +35,32 @@ use crate::graph::topology::Topology;
 // ----------------------------------------------------------------------------
 
 /// Visitor for descendants of a node.
-pub struct Descendants<'a> {
+///
+/// Descendants are yielded in depth-first pre-order, i.e., as soon as they're
+/// discovered, which means that a node reachable via multiple paths might be
+/// yielded before all of its incoming edges have been seen, so the order is
+/// not topological. Nodes are marked as visited on discovery, so every node is
+/// yielded exactly once, regardless of the number of paths leading to it. Use
+/// a [`Traversal`][] if topological order is required.
+///
+/// [`Traversal`]: crate::graph::traversal::Traversal
+pub struct Descendants<'a, V = HashSet<usize>> {
     /// Graph topology.
     topology: &'a Topology,
     /// Stack for depth-first search.
     stack: Vec<usize>,
+    /// Set of visited nodes, owned or borrowed.
+    visited: V,
+}
+
+/// Visitor for descendants of a node, following only matching edges.
+pub struct FilteredDescendants<'a, F> {
+    /// Graph topology.
+    topology: &'a Topology,
+    /// Edge filter.
+    filter: F,
+    /// Stack for depth-first search.
+    stack: Vec<usize>,
     /// Set of visited nodes.
     visited: HashSet<usize>,
 }
@@ -59,11 +81,85 @@ impl<'a> Descendants<'a> {
     }
 }
 
+#[allow(clippy::implicit_hasher)]
+impl<'a> Descendants<'a, &'a mut HashSet<usize>> {
+    /// Creates a visitor that yields all descendants of the given node, using the
+    /// given set as scratch space for visited nodes.
+    ///
+    /// The set is cleared before use, retaining its capacity, which allows to
+    /// reuse a single allocation when visiting the descendants of many nodes. After
+    /// the visitor is exhausted, the set contains exactly the descendants.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::error::Error;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use ahash::HashSet;
+    /// use zrx_graph::visitor::Descendants;
+    /// use zrx_graph::Graph;
+    ///
+    /// // Create graph builder and add nodes
+    /// let mut builder = Graph::builder();
+    /// let a = builder.add_node("a");
+    /// let b = builder.add_node("b");
+    /// let c = builder.add_node("c");
+    ///
+    /// // Create edges between nodes
+    /// builder.add_edge(a, b, 0)?;
+    /// builder.add_edge(b, c, 0)?;
+    ///
+    /// // Create graph from builder
+    /// let graph = builder.build();
+    ///
+    /// // Create iterators over descendants, reusing scratch space
+    /// let mut visited = HashSet::default();
+    /// for node in [a, b, c] {
+    ///     let descendants =
+    ///         Descendants::new_with_scratch(graph.topology(), node, &mut visited);
+    ///     println!("{:?}", descendants.collect::<Vec<_>>());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn new_with_scratch(
+        topology: &'a Topology, node: usize, visited: &'a mut HashSet<usize>,
+    ) -> Self {
+        visited.clear();
+        Self {
+            topology,
+            stack: Vec::from([node]),
+            visited,
+        }
+    }
+}
+
+impl<'a, F> FilteredDescendants<'a, F>
+where
+    F: Fn(usize, usize) -> bool,
+{
+    /// Creates a visitor that yields all descendants of the given node, which
+    /// are reachable through edges that satisfy the given filter.
+    #[must_use]
+    pub fn new(topology: &'a Topology, node: usize, filter: F) -> Self {
+        Self {
+            topology,
+            filter,
+            stack: Vec::from([node]),
+            visited: HashSet::default(),
+        }
+    }
+}
+
 // ----------------------------------------------------------------------------
 // Trait implementations
 // ----------------------------------------------------------------------------
 
-impl Iterator for Descendants<'_> {
+impl<V> Iterator for Descendants<'_, V>
+where
+    V: BorrowMut<HashSet<usize>>,
+{
     type Item = usize;
 
     /// Returns the next descendant.
@@ -105,8 +201,77 @@ impl Iterator for Descendants<'_> {
         while let Some(node) = self.stack.pop() {
             for &descendant in &outgoing[node] {
                 // If we haven't visited this descendant yet, we put it on the
-                // stack after marking it as visited and return it immediately
+                // stack after marking it as visited and return it immediately.
+                // The current node is put back first, so its remaining descendants
+                // are visited once the descendant has been fully explored.
+                if self.visited.borrow_mut().insert(descendant) {
+                    self.stack.push(node);
+                    self.stack.push(descendant);
+                    return Some(descendant);
+                }
+            }
+        }
+
+        // No more descendants to visit
+        None
+    }
+}
+
+impl<F> Iterator for FilteredDescendants<'_, F>
+where
+    F: Fn(usize, usize) -> bool,
+{
+    type Item = usize;
+
+    /// Returns the next descendant.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::error::Error;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use zrx_graph::visitor::FilteredDescendants;
+    /// use zrx_graph::Graph;
+    ///
+    /// // Create graph builder and add nodes
+    /// let mut builder = Graph::builder();
+    /// let a = builder.add_node("a");
+    /// let b = builder.add_node("b");
+    /// let c = builder.add_node("c");
+    ///
+    /// // Create edges between nodes
+    /// builder.add_edge(a, b, 0)?;
+    /// builder.add_edge(b, c, 0)?;
+    ///
+    /// // Create graph from builder
+    /// let graph = builder.build();
+    ///
+    /// // Create iterator over descendants
+    /// let filter = |_, target| target != c;
+    /// let mut descendants =
+    ///     FilteredDescendants::new(graph.topology(), a, filter);
+    /// while let Some(descendant) = descendants.next() {
+    ///     println!("{descendant:?}");
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn next(&mut self) -> Option<Self::Item> {
+        let outgoing = self.topology.outgoing();
+
+        // Perform a depth-first search to find all descendants, skipping all
+        // edges that don't satisfy the filter, as if they didn't exist
+        while let Some(node) = self.stack.pop() {
+            for &descendant in &outgoing[node] {
+                if !(self.filter)(node, descendant) {
+                    continue;
+                }
+
+                // If we haven't visited this descendant yet, we put it on the
+                // stack after the current node, so the remaining descendants
+                // of the current node are visited once it's fully explored
                 if self.visited.insert(descendant) {
+                    self.stack.push(node);
                     self.stack.push(descendant);
                     return Some(descendant);
                 }
@@ -117,3 +282,58 @@ impl Iterator for Descendants<'_> {
         None
     }
 }
+
+// ----------------------------------------------------------------------------
+// Tests
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+
+    mod next {
+        use ahash::HashSet;
+
+        use crate::graph::visitor::testing::{diamonds, orders};
+        use crate::graph::visitor::Descendants;
+
+        #[test]
+        fn yields_shared_descendants_once() {
+            for order in orders() {
+                let graph = diamonds(&order);
+                let mut nodes = graph.descendants(0).collect::<Vec<_>>();
+                nodes.sort_unstable();
+                assert_eq!(nodes, [1, 2, 3, 4, 5, 6], "order {order:?}");
+            }
+        }
+
+        #[test]
+        fn yields_shared_filtered_descendants_once() {
+            for order in orders() {
+                let graph = diamonds(&order);
+                let mut nodes = graph
+                    .descendants_filtered(0, |_, target| target != 5)
+                    .collect::<Vec<_>>();
+                nodes.sort_unstable();
+                assert_eq!(nodes, [1, 2, 3, 4, 6], "order {order:?}");
+            }
+        }
+
+        #[test]
+        fn yields_same_with_scratch() {
+            let graph = diamonds(&(0..8).collect::<Vec<_>>());
+            let topology = graph.topology();
+
+            // Reuse scratch space across all nodes, starting with a dirty set
+            let mut visited = HashSet::from_iter([0]);
+            for node in 0..graph.len() {
+                let fresh =
+                    Descendants::new(topology, node).collect::<Vec<_>>();
+                let reused =
+                    Descendants::new_with_scratch(topology, node, &mut visited)
+                        .collect::<Vec<_>>();
+                assert_eq!(fresh, reused, "node {node}");
+                assert_eq!(visited, fresh.into_iter().collect());
+            }
+        }
+    }
+}
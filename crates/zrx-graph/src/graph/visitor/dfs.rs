@@ -0,0 +1,121 @@
+// Copyright (c) Zensical LLC <https://zensical.org>
+
+// SPDX-License-Identifier: MIT
+// Third-party contributions licensed under CLA
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+
+// ----------------------------------------------------------------------------
+
+//! Visitor for depth-first search.
+
+use ahash::HashSet;
+
+use crate::graph::topology::Topology;
+
+// ----------------------------------------------------------------------------
+// Structs
+// ----------------------------------------------------------------------------
+
+/// Visitor for depth-first search.
+pub struct Dfs<'a> {
+    /// Graph topology.
+    topology: &'a Topology,
+    /// Stack for depth-first search.
+    stack: Vec<usize>,
+    /// Set of visited nodes.
+    visited: HashSet<usize>,
+}
+
+// ----------------------------------------------------------------------------
+// Implementations
+// ----------------------------------------------------------------------------
+
+impl<'a> Dfs<'a> {
+    /// Creates a visitor that yields all nodes reachable from the given node
+    /// in depth-first pre-order, starting with the node itself.
+    #[must_use]
+    pub fn new(topology: &'a Topology, node: usize) -> Self {
+        Self {
+            topology,
+            stack: Vec::from([node]),
+            visited: HashSet::default(),
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------
+// Trait implementations
+// ----------------------------------------------------------------------------
+
+impl Iterator for Dfs<'_> {
+    type Item = usize;
+
+    /// Returns the next node.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::error::Error;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use zrx_graph::visitor::Dfs;
+    /// use zrx_graph::Graph;
+    ///
+    /// // Create graph builder and add nodes
+    /// let mut builder = Graph::builder();
+    /// let a = builder.add_node("a");
+    /// let b = builder.add_node("b");
+    /// let c = builder.add_node("c");
+    ///
+    /// // Create edges between nodes
+    /// builder.add_edge(a, b, 0)?;
+    /// builder.add_edge(b, c, 0)?;
+    ///
+    /// // Create graph from builder
+    /// let graph = builder.build();
+    ///
+    /// // Create iterator in depth-first order
+    /// let mut dfs = Dfs::new(graph.topology(), a);
+    /// while let Some(node) = dfs.next() {
+    ///     println!("{node:?}");
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn next(&mut self) -> Option<Self::Item> {
+        let outgoing = self.topology.outgoing();
+
+        // Nodes are marked as visited when they're popped, since a node might
+        // be pushed several times before it's visited. Successors are pushed
+        // in reverse order, so they're visited in the order of their edges.
+        while let Some(node) = self.stack.pop() {
+            if self.visited.insert(node) {
+                for &next in outgoing[node].iter().rev() {
+                    if !self.visited.contains(&next) {
+                        self.stack.push(next);
+                    }
+                }
+                return Some(node);
+            }
+        }
+
+        // No more nodes to visit
+        None
+    }
+}
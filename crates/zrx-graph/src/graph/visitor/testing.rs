@@ -0,0 +1,60 @@
+// Copyright (c) Zensical LLC <https://zensical.org>
+
+// SPDX-License-Identifier: MIT
+// Third-party contributions licensed under CLA
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+
+// ----------------------------------------------------------------------------
+
+//! Test fixtures for visitors.
+
+use crate::graph::Graph;
+
+// ----------------------------------------------------------------------------
+// Functions
+// ----------------------------------------------------------------------------
+
+/// Creates a graph of two stacked diamonds, adding the edges in the given
+/// order, so shared nodes are discovered via either path.
+pub fn diamonds(order: &[usize]) -> Graph<usize> {
+    let edges = [(0, 1), (0, 2), (1, 3), (2, 3), (3, 4), (3, 5)];
+    let edges = [edges.as_slice(), &[(4, 6), (5, 6)]].concat();
+    let mut builder = Graph::builder();
+    for node in 0..7 {
+        builder.add_node(node);
+    }
+    for &index in order {
+        let (source, target) = edges[index];
+        builder.add_edge(source, target, ()).unwrap();
+    }
+    builder.build()
+}
+
+/// Returns all edge orders to check, i.e., forward and reverse, as well as
+/// all rotations, which covers different discovery orders.
+pub fn orders() -> Vec<Vec<usize>> {
+    let mut orders = Vec::new();
+    for shift in 0..8 {
+        let order = (0..8).map(|n| (n + shift) % 8).collect::<Vec<_>>();
+        orders.push(order.iter().rev().copied().collect());
+        orders.push(order);
+    }
+    orders
+}
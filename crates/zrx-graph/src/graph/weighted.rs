@@ -0,0 +1,111 @@
+// Copyright (c) Zensical LLC <https://zensical.org>
+
+// SPDX-License-Identifier: MIT
+// Third-party contributions licensed under CLA
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+
+// ----------------------------------------------------------------------------
+
+//! Weighted view of a graph.
+
+use super::Graph;
+
+// ----------------------------------------------------------------------------
+// Structs
+// ----------------------------------------------------------------------------
+
+/// Weighted view of a graph.
+///
+/// Since edge weights are dropped when building a [`Graph`], this data type
+/// allows to attach weights after the fact, which are computed on demand by
+/// a function receiving the source and target of an edge. It's created with
+/// [`Graph::with_edge_weights`], and can be passed to weighted algorithms,
+/// e.g., [`weighted_shortest_path_length`][].
+///
+/// [`weighted_shortest_path_length`]: crate::graph::algorithm::weighted_shortest_path_length
+pub struct WeightedView<'a, T, W> {
+    /// Graph.
+    graph: &'a Graph<T>,
+    /// Weight function.
+    function: Box<dyn Fn(usize, usize) -> W + 'a>,
+}
+
+// ----------------------------------------------------------------------------
+// Implementations
+// ----------------------------------------------------------------------------
+
+impl<'a, T, W> WeightedView<'a, T, W> {
+    /// Creates a weighted view of the given graph.
+    ///
+    /// Note that the canonical way to create a [`WeightedView`] is to invoke
+    /// the [`Graph::with_edge_weights`] method.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::error::Error;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use zrx_graph::{Graph, WeightedView};
+    ///
+    /// // Create graph builder and add nodes
+    /// let mut builder = Graph::builder();
+    /// let a = builder.add_node("a");
+    /// let b = builder.add_node("b");
+    ///
+    /// // Create edges between nodes
+    /// builder.add_edge(a, b, 0)?;
+    ///
+    /// // Create graph from builder
+    /// let graph = builder.build();
+    ///
+    /// // Create weighted view of graph
+    /// let view = WeightedView::new(&graph, |source, target| source + target);
+    /// assert_eq!(view.weight(a, b), 1);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn new<F>(graph: &'a Graph<T>, f: F) -> Self
+    where
+        F: Fn(usize, usize) -> W + 'a,
+    {
+        Self { graph, function: Box::new(f) }
+    }
+
+    /// Returns the weight of the edge between the given nodes.
+    ///
+    /// Note that the weight function is invoked for any pair of nodes, which
+    /// means that it's the responsibility of the caller to only pass nodes
+    /// that are connected by an edge.
+    #[inline]
+    #[must_use]
+    pub fn weight(&self, source: usize, target: usize) -> W {
+        (self.function)(source, target)
+    }
+}
+
+#[allow(clippy::must_use_candidate)]
+impl<T, W> WeightedView<'_, T, W> {
+    /// Returns the graph.
+    #[inline]
+    pub fn graph(&self) -> &Graph<T> {
+        self.graph
+    }
+}
@@ -30,5 +30,8 @@ mod graph;
 pub use graph::algorithm;
 pub use graph::topology::{self, Topology};
 pub use graph::traversal::{self, Traversal};
+pub use graph::validation::{self, ValidationReport};
 pub use graph::visitor;
-pub use graph::{Builder, Error, Graph, Result};
+pub use graph::{
+    Builder, Error, Expansion, Graph, NodeId, Result, WeightedView,
+};
@@ -0,0 +1,108 @@
+// Copyright (c) Zensical LLC <https://zensical.org>
+
+// SPDX-License-Identifier: MIT
+// Third-party contributions licensed under CLA
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+
+// ----------------------------------------------------------------------------
+
+//! Benchmarks for matcher component order and identifier forms.
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use std::hint::black_box;
+use zrx_id::{Id, IdRef, Matcher};
+
+// ----------------------------------------------------------------------------
+// Benchmarks
+// ----------------------------------------------------------------------------
+
+/// Benchmarks default and adaptive component order on a skewed selector set,
+/// where selectors only constrain the `provider`, which rarely matches.
+fn skewed(c: &mut Criterion) {
+    let selectors = (0..64)
+        .map(|n| format!("zrs:provider-{n}:::::"))
+        .collect::<Vec<_>>();
+
+    // Create matchers with default and adaptive order
+    let builder = Matcher::builder().with_many(&selectors).unwrap();
+    let default = builder.clone().build().unwrap();
+    let adaptive = builder.adaptive(true).build().unwrap();
+
+    // Create identifiers, none of which matches any provider
+    let ids = (0..64)
+        .map(|n| format!("zri:file:::docs:path/to/page-{n}.md:"))
+        .map(|id| id.parse::<Id>().unwrap())
+        .collect::<Vec<_>>();
+
+    // Benchmark both matchers on the same identifiers
+    let mut group = c.benchmark_group("skewed");
+    for (name, matcher) in [("default", &default), ("adaptive", &adaptive)] {
+        group.bench_function(name, |b| {
+            b.iter(|| {
+                for id in &ids {
+                    black_box(matcher.is_match(id).unwrap());
+                }
+            });
+        });
+    }
+    group.finish();
+}
+
+/// Benchmarks matching strings via owned and borrowed identifiers, where the
+/// strings are parsed within the hot loop, as they would be if discarded.
+fn borrowed(c: &mut Criterion) {
+    let selectors = (0..64)
+        .map(|n| format!("zrs::::docs:**/page-{n}.md:"))
+        .collect::<Vec<_>>();
+
+    // Create matcher
+    let builder = Matcher::builder().with_many(&selectors).unwrap();
+    let matcher = builder.build().unwrap();
+
+    // Create identifiers, half of which match a selector
+    let ids = (0..128)
+        .map(|n| format!("zri:file:::docs:path/to/page-{n}.md:"))
+        .collect::<Vec<_>>();
+
+    // Benchmark matching on the same identifiers
+    let mut group = c.benchmark_group("borrowed");
+    group.bench_function("id", |b| {
+        b.iter(|| {
+            for id in &ids {
+                let id: Id = id.parse().unwrap();
+                black_box(matcher.is_match(&id).unwrap());
+            }
+        });
+    });
+    group.bench_function("id_ref", |b| {
+        b.iter(|| {
+            for id in &ids {
+                let id = IdRef::try_from(id.as_str()).unwrap();
+                black_box(matcher.is_match(&id).unwrap());
+            }
+        });
+    });
+    group.finish();
+}
+
+// ----------------------------------------------------------------------------
+
+criterion_group!(benches, skewed, borrowed);
+criterion_main!(benches);
@@ -33,17 +33,23 @@ use std::str::FromStr;
 use std::sync::Arc;
 use zrx_path::PathExt;
 
+mod borrowed;
 mod builder;
 mod convert;
 mod error;
 pub mod format;
+mod mask;
 pub mod matcher;
+mod provider;
 pub mod uri;
 
+pub use borrowed::IdRef;
 pub use builder::Builder;
 pub use convert::ToId;
 pub use error::{Error, Result};
 use format::Format;
+pub use mask::ComponentMask;
+pub use provider::Provider;
 use uri::Uri;
 
 // ----------------------------------------------------------------------------
@@ -260,6 +266,289 @@ impl Id {
     pub fn as_uri(&self) -> Uri<'_> {
         Uri::from(self.location())
     }
+
+    /// Returns the `provider` component as a [`Provider`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::error::Error;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use zrx_id::{Id, Provider};
+    ///
+    /// // Create identifier from string
+    /// let id: Id = "zri:file:::docs:index.md:".parse()?;
+    /// assert_eq!(id.to_provider(), Provider::File);
+    ///
+    /// // Create identifier with unknown provider
+    /// let id: Id = "zri:s3:::docs:index.md:".parse()?;
+    /// assert_eq!(id.to_provider(), Provider::Other(String::from("s3")));
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn to_provider(&self) -> Provider {
+        Provider::from(self.provider().as_ref())
+    }
+
+    /// Returns whether the `context` component starts with the given prefix.
+    ///
+    /// This is a literal check, which is cheaper than building a [`Matcher`][]
+    /// for cases where a glob would be overkill.
+    ///
+    /// [`Matcher`]: crate::id::matcher::Matcher
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::error::Error;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use zrx_id::Id;
+    ///
+    /// // Create identifier from string
+    /// let id: Id = "zri:file:::docs:index.md:".parse()?;
+    /// assert!(id.context_starts_with("do"));
+    /// assert!(!id.context_starts_with("src"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn context_starts_with(&self, prefix: &str) -> bool {
+        self.context().starts_with(prefix)
+    }
+
+    /// Returns whether the `context` component ends with the given suffix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::error::Error;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use zrx_id::Id;
+    ///
+    /// // Create identifier from string
+    /// let id: Id = "zri:file:::docs:index.md:".parse()?;
+    /// assert!(id.context_ends_with("cs"));
+    /// assert!(!id.context_ends_with("src"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn context_ends_with(&self, suffix: &str) -> bool {
+        self.context().ends_with(suffix)
+    }
+
+    /// Returns whether the `context` component contains the given string.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::error::Error;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use zrx_id::Id;
+    ///
+    /// // Create identifier from string
+    /// let id: Id = "zri:file:::docs:index.md:".parse()?;
+    /// assert!(id.context_contains("oc"));
+    /// assert!(!id.context_contains("src"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn context_contains(&self, value: &str) -> bool {
+        self.context().contains(value)
+    }
+
+    /// Returns whether the `location` component starts with the given prefix.
+    ///
+    /// This is a literal check, which is cheaper than building a [`Matcher`][]
+    /// for cases where a glob would be overkill.
+    ///
+    /// [`Matcher`]: crate::id::matcher::Matcher
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::error::Error;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use zrx_id::Id;
+    ///
+    /// // Create identifier from string
+    /// let id: Id = "zri:file:::docs:guide/index.md:".parse()?;
+    /// assert!(id.location_starts_with("guide/"));
+    /// assert!(!id.location_starts_with("blog/"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn location_starts_with(&self, prefix: &str) -> bool {
+        self.location().starts_with(prefix)
+    }
+
+    /// Returns whether the `location` component ends with the given suffix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::error::Error;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use zrx_id::Id;
+    ///
+    /// // Create identifier from string
+    /// let id: Id = "zri:file:::docs:guide/index.md:".parse()?;
+    /// assert!(id.location_ends_with(".md"));
+    /// assert!(!id.location_ends_with(".html"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn location_ends_with(&self, suffix: &str) -> bool {
+        self.location().ends_with(suffix)
+    }
+
+    /// Returns whether the `location` component contains the given string.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::error::Error;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use zrx_id::Id;
+    ///
+    /// // Create identifier from string
+    /// let id: Id = "zri:file:::docs:guide/index.md:".parse()?;
+    /// assert!(id.location_contains("/index"));
+    /// assert!(!id.location_contains("blog"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn location_contains(&self, value: &str) -> bool {
+        self.location().contains(value)
+    }
+
+    /// Returns a hash of the identifier that is stable across process runs.
+    ///
+    /// This hash is distinct from the [`Hash`] implementation, which is meant
+    /// for hash maps and hash sets, and makes no guarantees about its values
+    /// across process runs or compiler versions. The stable hash is computed
+    /// with 64-bit FNV-1a over the bytes of all components, each terminated
+    /// with a byte that can't occur in UTF-8, so it's suitable for bucketing
+    /// and cache keys that must be reproducible.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::error::Error;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use zrx_id::Id;
+    ///
+    /// // Create identifiers and compare stable hashes
+    /// let a: Id = "zri:file:::docs:index.md:".parse()?;
+    /// let b: Id = "zri:file:::docs:index.md:".parse()?;
+    /// assert_eq!(a.stable_hash(), b.stable_hash());
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn stable_hash(&self) -> u64 {
+        let mut hash = FNV_OFFSET_BASIS;
+        for index in 1..7 {
+            let component = self.format.get(index);
+            let bytes = component.as_bytes().iter().chain([&0xFF]);
+            for &byte in bytes {
+                hash ^= u64::from(byte);
+                hash = hash.wrapping_mul(FNV_PRIME);
+            }
+        }
+        hash
+    }
+
+    /// Compares two identifiers, only taking the components of the mask into
+    /// account.
+    ///
+    /// This allows to treat identifiers as equal that differ in some of their
+    /// components, e.g., for grouping all variants of a resource, without the
+    /// need to rebuild identifiers with those components removed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::error::Error;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use zrx_id::{ComponentMask, Id};
+    ///
+    /// // Create identifiers from strings
+    /// let a: Id = "zri:file::en:docs:index.md:".parse()?;
+    /// let b: Id = "zri:file::de:docs:index.md:".parse()?;
+    ///
+    /// // Compare identifiers, ignoring variant
+    /// let mask = ComponentMask::ALL.without(ComponentMask::VARIANT);
+    /// assert!(a.eq_ignoring(&b, mask));
+    /// assert!(!a.eq_ignoring(&b, ComponentMask::ALL));
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn eq_ignoring(&self, other: &Id, mask: ComponentMask) -> bool {
+        if mask == ComponentMask::ALL {
+            return self == other;
+        }
+        (0..6)
+            .filter(|&index| mask.contains_index(index))
+            .all(|index| {
+                self.format.get(index + 1) == other.format.get(index + 1)
+            })
+    }
+
+    /// Returns the identifier with normalized path components.
+    ///
+    /// This method canonicalizes the `context` and `location` components by
+    /// removing `.` segments, redundant and trailing slashes, so that paths
+    /// that are spelled differently, but refer to the same artifact, compare
+    /// as equal. Normalization is purely lexical and never touches the file
+    /// system. Note that `..` segments can't occur, since they're rejected
+    /// when identifiers are created. All other components are left as is.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::error::Error;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use zrx_id::Id;
+    ///
+    /// // Create identifier from string
+    /// let id: Id = "zri:file:::docs:./guide//index.md:".parse()?;
+    ///
+    /// // Normalize identifier
+    /// let id = id.normalized();
+    /// assert_eq!(id.as_str(), "zri:file:::docs:guide/index.md:");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn normalized(self) -> Id {
+        let context = normalize(&self.context()).into_owned();
+        let location = normalize(&self.location()).into_owned();
+        if context == self.context() && location == self.location() {
+            return self;
+        }
+
+        // Rebuild identifier with normalized components - as normalization
+        // only ever shortens components, building can't fail, but if it does,
+        // we just return the identifier unchanged
+        let mut builder = self.to_builder();
+        builder.set_context(context);
+        builder.set_location(location);
+        builder.build().unwrap_or(self)
+    }
 }
 
 #[allow(clippy::must_use_candidate)]
@@ -337,27 +626,31 @@ impl FromStr for Id {
     /// # }
     /// ```
     fn from_str(value: &str) -> Result<Self> {
-        let format = Format::from_str(value)?;
-
-        // Ensure prefix is set
-        if format.get(0) != "zri" {
-            Err(Error::Prefix)?;
-        }
-
-        // Ensure provider is set
-        if format.get(1).is_empty() {
-            Err(Error::Component("provider"))?;
-        }
+        IdRef::try_from(value).map(Id::from)
+    }
+}
 
-        // Ensure context is set
-        if format.get(4).is_empty() {
-            Err(Error::Component("context"))?;
-        }
+// ----------------------------------------------------------------------------
 
-        // Ensure location is set
-        if format.get(5).is_empty() {
-            Err(Error::Component("location"))?;
-        }
+impl From<IdRef<'_>> for Id {
+    /// Creates an identifier from a borrowed identifier.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::error::Error;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use zrx_id::{Id, IdRef};
+    ///
+    /// // Create identifier from borrowed identifier
+    /// let id = IdRef::try_from("zri:file:::docs:index.md:")?;
+    /// assert_eq!(Id::from(id).as_str(), "zri:file:::docs:index.md:");
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn from(id: IdRef<'_>) -> Self {
+        let (value, spans, flags) = id.into_parts();
+        let format = Format::from_parts(value, spans, flags);
 
         // Precompute hash for fast hashing
         let hash = {
@@ -367,7 +660,7 @@ impl FromStr for Id {
         };
 
         // No errors occurred
-        Ok(Self { format: Arc::new(format), hash })
+        Self { format: Arc::new(format), hash }
     }
 }
 
@@ -438,3 +731,152 @@ impl fmt::Debug for Id {
             .finish()
     }
 }
+
+// ----------------------------------------------------------------------------
+// Constants
+// ----------------------------------------------------------------------------
+
+/// FNV-1a offset basis for 64-bit hashes.
+const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+
+/// FNV-1a prime for 64-bit hashes.
+const FNV_PRIME: u64 = 0x0100_0000_01b3;
+
+// ----------------------------------------------------------------------------
+// Functions
+// ----------------------------------------------------------------------------
+
+/// Normalizes the given path-like component.
+///
+/// Empty and `.` segments are removed, and if nothing remains, `.` is used,
+/// since the `context` and `location` components must not be empty.
+fn normalize(value: &str) -> Cow<'_, str> {
+    let segments = value
+        .split('/')
+        .filter(|segment| !matches!(*segment, "" | "."))
+        .collect::<Vec<_>>();
+
+    // Only allocate if normalization changed the value
+    let path = if segments.is_empty() {
+        String::from(".")
+    } else {
+        segments.join("/")
+    };
+    if path == value {
+        Cow::Borrowed(value)
+    } else {
+        Cow::Owned(path)
+    }
+}
+
+// ----------------------------------------------------------------------------
+// Tests
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+
+    mod eq_ignoring {
+        use crate::id::{ComponentMask, Id};
+
+        #[test]
+        fn ignores_masked_components() {
+            let a: Id = "zri:file:docs:en:docs:index.md:".parse().unwrap();
+            let b: Id = "zri:file:docs:de:docs:index.md:".parse().unwrap();
+            let mask = ComponentMask::ALL.without(ComponentMask::VARIANT);
+            assert!(a.eq_ignoring(&b, mask));
+            assert!(!a.eq_ignoring(&b, ComponentMask::ALL));
+        }
+
+        #[test]
+        fn compares_unmasked_components() {
+            let a: Id = "zri:file:docs:en:docs:index.md:".parse().unwrap();
+            let b: Id = "zri:file:docs:de:docs:guide.md:".parse().unwrap();
+            let mask = ComponentMask::ALL.without(ComponentMask::VARIANT);
+            assert!(!a.eq_ignoring(&b, mask));
+            assert!(a.eq_ignoring(&b, ComponentMask::NONE));
+        }
+    }
+
+    mod normalized {
+        use crate::id::Id;
+
+        #[test]
+        fn removes_current_dir_segments() {
+            let id: Id =
+                "zri:file:::./docs:./guide/./index.md:".parse().unwrap();
+            assert_eq!(
+                id.normalized().as_str(),
+                "zri:file:::docs:guide/index.md:"
+            );
+        }
+
+        #[test]
+        fn collapses_redundant_slashes() {
+            let id: Id =
+                "zri:file:::docs//api:guide//index.md:".parse().unwrap();
+            assert_eq!(
+                id.normalized().as_str(),
+                "zri:file:::docs/api:guide/index.md:"
+            );
+        }
+
+        #[test]
+        fn removes_trailing_slashes() {
+            let id: Id = "zri:file:::docs/:guide/index.md:".parse().unwrap();
+            assert_eq!(
+                id.normalized().as_str(),
+                "zri:file:::docs:guide/index.md:"
+            );
+        }
+
+        #[test]
+        fn keeps_current_dir_if_empty() {
+            let id: Id = "zri:file:::./:index.md:".parse().unwrap();
+            assert_eq!(id.normalized().as_str(), "zri:file:::.:index.md:");
+        }
+
+        #[test]
+        fn rejects_parent_dir_segments() {
+            let res = "zri:file:::docs:../index.md:".parse::<Id>();
+            assert!(res.is_err());
+        }
+
+        #[test]
+        fn matches_equivalent_identifiers() {
+            let a: Id = "zri:file:::docs:./index.md:".parse().unwrap();
+            let b: Id = "zri:file:::docs:index.md:".parse().unwrap();
+            assert_ne!(a, b);
+            assert_eq!(a.normalized(), b);
+        }
+
+        #[test]
+        fn leaves_other_components_untouched() {
+            let id: Id =
+                "zri:file:.//res:./v:./docs:index.md:a//b".parse().unwrap();
+            assert_eq!(
+                id.normalized().as_str(),
+                "zri:file:.//res:./v:docs:index.md:a//b"
+            );
+        }
+    }
+
+    mod stable_hash {
+        use crate::id::Id;
+
+        #[test]
+        fn is_identical_across_runs() {
+            let id: Id = "zri:file:::docs:index.md:".parse().unwrap();
+
+            // Ensure hash matches the value pinned from a previous run
+            assert_eq!(id.stable_hash(), 9_406_585_118_194_356_619);
+        }
+
+        #[test]
+        fn distinguishes_component_boundaries() {
+            let a: Id = "zri:file:::docs:index.md:".parse().unwrap();
+            let b: Id = "zri:file:::doc:sindex.md:".parse().unwrap();
+            assert_ne!(a.stable_hash(), b.stable_hash());
+        }
+    }
+}
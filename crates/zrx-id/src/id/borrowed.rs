@@ -0,0 +1,273 @@
+// Copyright (c) Zensical LLC <https://zensical.org>
+
+// SPDX-License-Identifier: MIT
+// Third-party contributions licensed under CLA
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+
+// ----------------------------------------------------------------------------
+
+//! Borrowed identifier.
+
+use std::borrow::Cow;
+use std::fmt;
+use std::ops::Range;
+
+use super::error::{Error, Result};
+use super::format;
+use super::Id;
+
+// ----------------------------------------------------------------------------
+// Structs
+// ----------------------------------------------------------------------------
+
+/// Borrowed identifier.
+///
+/// This is the zero-copy counterpart of [`Id`], which references the source
+/// string directly and only records the boundaries of its components. Parsing
+/// validates the string in exactly the same way as [`Id`], but doesn't need to
+/// allocate, which makes borrowed identifiers a good fit for hot loops, e.g.,
+/// when matching many strings with a [`Matcher`][] that are discarded after.
+///
+/// Components are returned as [`Cow`] smart pointers, which borrow from the
+/// source string, unless the component is percent-encoded, in which case it's
+/// decoded into an owned value, exactly like [`Id`] does.
+///
+/// [`Matcher`]: crate::id::matcher::Matcher
+///
+/// # Examples
+///
+/// ```
+/// # use std::error::Error;
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// use zrx_id::IdRef;
+///
+/// // Create borrowed identifier from string
+/// let id = IdRef::try_from("zri:file:::docs:index.md:")?;
+/// assert_eq!(id.location(), "index.md");
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct IdRef<'a> {
+    /// String representation.
+    value: &'a str,
+    /// Set of spans.
+    spans: [Range<u16>; 7],
+    /// Encoding flags.
+    flags: u64,
+}
+
+// ----------------------------------------------------------------------------
+// Implementations
+// ----------------------------------------------------------------------------
+
+impl<'a> IdRef<'a> {
+    /// Returns the string representation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::error::Error;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use zrx_id::IdRef;
+    ///
+    /// // Create borrowed identifier from string
+    /// let id = IdRef::try_from("zri:file:::docs:index.md:")?;
+    ///
+    /// // Obtain string representation
+    /// assert_eq!(id.as_str(), "zri:file:::docs:index.md:");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn as_str(&self) -> &'a str {
+        self.value
+    }
+
+    /// Returns the value of the component at the given index.
+    #[inline]
+    fn get(&self, index: usize) -> Cow<'a, str> {
+        format::component(self.value, &self.spans, self.flags, index)
+    }
+
+    /// Decomposes the borrowed identifier into its parts.
+    #[inline]
+    pub(crate) fn into_parts(self) -> (&'a str, [Range<u16>; 7], u64) {
+        (self.value, self.spans, self.flags)
+    }
+}
+
+#[allow(clippy::must_use_candidate)]
+impl<'a> IdRef<'a> {
+    /// Returns the `provider` component.
+    #[inline]
+    pub fn provider(&self) -> Cow<'a, str> {
+        self.get(1)
+    }
+
+    /// Returns the `resource` component, if any.
+    #[inline]
+    pub fn resource(&self) -> Option<Cow<'a, str>> {
+        Some(self.get(2)).filter(|value| !value.is_empty())
+    }
+
+    /// Returns the `variant` component, if any.
+    #[inline]
+    pub fn variant(&self) -> Option<Cow<'a, str>> {
+        Some(self.get(3)).filter(|value| !value.is_empty())
+    }
+
+    /// Returns the `context` component.
+    #[inline]
+    pub fn context(&self) -> Cow<'a, str> {
+        self.get(4)
+    }
+
+    /// Returns the `location` component.
+    #[inline]
+    pub fn location(&self) -> Cow<'a, str> {
+        self.get(5)
+    }
+
+    /// Returns the `fragment` component, if any.
+    #[inline]
+    pub fn fragment(&self) -> Option<Cow<'a, str>> {
+        Some(self.get(6)).filter(|value| !value.is_empty())
+    }
+}
+
+// ----------------------------------------------------------------------------
+// Trait implementations
+// ----------------------------------------------------------------------------
+
+impl<'a> TryFrom<&'a str> for IdRef<'a> {
+    type Error = Error;
+
+    /// Attempts to create a borrowed identifier from a string.
+    ///
+    /// The string must adhere to the same format as for [`Id`], and include
+    /// exactly six `:` separators, even in case some components are omitted.
+    ///
+    /// ``` text
+    /// zri:<provider>:<resource>:<variant>:<context>:<location>:<fragment>
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// This method returns [`Error::Path`], if a component value contains a
+    /// backslash or traversal, or [`Error::Format`], if the format is invalid.
+    ///
+    /// [`Error::Format`]: crate::id::Error::Format
+    /// [`Error::Path`]: crate::id::Error::Path
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::error::Error;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use zrx_id::IdRef;
+    ///
+    /// // Create borrowed identifier from string
+    /// let id = IdRef::try_from("zri:file:::docs:index.md:")?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn try_from(value: &'a str) -> Result<Self> {
+        let (spans, flags) = format::parse(value)?;
+        let id = Self { value, spans, flags };
+
+        // Ensure prefix is set
+        if id.get(0) != "zri" {
+            Err(Error::Prefix)?;
+        }
+
+        // Ensure provider is set
+        if id.get(1).is_empty() {
+            Err(Error::Component("provider"))?;
+        }
+
+        // Ensure context is set
+        if id.get(4).is_empty() {
+            Err(Error::Component("context"))?;
+        }
+
+        // Ensure location is set
+        if id.get(5).is_empty() {
+            Err(Error::Component("location"))?;
+        }
+
+        // No errors occurred
+        Ok(id)
+    }
+}
+
+impl<'a> From<&'a Id> for IdRef<'a> {
+    /// Creates a borrowed identifier from an identifier.
+    ///
+    /// This conversion is zero-copy, as the identifier was already validated,
+    /// so the borrowed identifier just references its string representation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::error::Error;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use zrx_id::{Id, IdRef};
+    ///
+    /// // Create borrowed identifier from identifier
+    /// let id: Id = "zri:file:::docs:index.md:".parse()?;
+    /// assert_eq!(IdRef::from(&id).as_str(), id.as_str());
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    fn from(id: &'a Id) -> Self {
+        let format = &id.format;
+        Self {
+            value: format.as_str(),
+            spans: format.spans().clone(),
+            flags: format.flags(),
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+impl fmt::Display for IdRef<'_> {
+    /// Formats the borrowed identifier for display.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.value)
+    }
+}
+
+impl fmt::Debug for IdRef<'_> {
+    /// Formats the borrowed identifier for debugging.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("IdRef")
+            .field("provider", &self.provider())
+            .field("resource", &self.resource())
+            .field("variant", &self.variant())
+            .field("context", &self.context())
+            .field("location", &self.location())
+            .field("fragment", &self.fragment())
+            .finish()
+    }
+}
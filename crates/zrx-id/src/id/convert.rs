@@ -26,9 +26,10 @@
 //! Identifier conversions.
 
 use std::borrow::Cow;
+use std::result;
 
-use super::error::Result;
-use super::Id;
+use super::error::{Error, Result};
+use super::{Id, IdRef};
 
 // ----------------------------------------------------------------------------
 // Traits
@@ -42,6 +43,18 @@ pub trait ToId {
     /// Converts to an identifier.
     #[allow(clippy::missing_errors_doc)]
     fn to_id(&self) -> Result<Cow<'_, Id>>;
+
+    /// Converts to a borrowed identifier, if possible.
+    ///
+    /// This method is used in hot paths like matching, where identifiers are
+    /// only inspected, so conversions from strings don't need to allocate. The
+    /// default implementation returns [`None`], in which case callers fall back
+    /// to [`ToId::to_id`], so implementing this method is optional.
+    #[allow(clippy::missing_errors_doc)]
+    #[inline]
+    fn to_id_ref(&self) -> Result<Option<IdRef<'_>>> {
+        Ok(None)
+    }
 }
 
 // ----------------------------------------------------------------------------
@@ -54,6 +67,43 @@ impl ToId for &Id {
     fn to_id(&self) -> Result<Cow<'_, Id>> {
         Ok(Cow::Borrowed(self))
     }
+
+    /// Creates a borrowed identifier from a reference.
+    #[inline]
+    fn to_id_ref(&self) -> Result<Option<IdRef<'_>>> {
+        Ok(Some(IdRef::from(*self)))
+    }
+}
+
+impl ToId for IdRef<'_> {
+    /// Creates an identifier from a borrowed identifier.
+    ///
+    /// Note that this allocates, as it creates an owned identifier, which is
+    /// why [`ToId::to_id_ref`] should be preferred wherever possible.
+    #[inline]
+    fn to_id(&self) -> Result<Cow<'_, Id>> {
+        Ok(Cow::Owned(Id::from(self.clone())))
+    }
+
+    /// Creates a borrowed identifier from a borrowed identifier.
+    #[inline]
+    fn to_id_ref(&self) -> Result<Option<IdRef<'_>>> {
+        Ok(Some(self.clone()))
+    }
+}
+
+impl ToId for &IdRef<'_> {
+    /// Creates an identifier from a borrowed identifier.
+    #[inline]
+    fn to_id(&self) -> Result<Cow<'_, Id>> {
+        (*self).to_id()
+    }
+
+    /// Creates a borrowed identifier from a borrowed identifier.
+    #[inline]
+    fn to_id_ref(&self) -> Result<Option<IdRef<'_>>> {
+        Ok(Some((*self).clone()))
+    }
 }
 
 // ----------------------------------------------------------------------------
@@ -90,4 +140,52 @@ where
     fn to_id(&self) -> Result<Cow<'_, Id>> {
         self.as_ref().parse().map(Cow::Owned)
     }
+
+    /// Creates a borrowed identifier from a string.
+    ///
+    /// # Errors
+    ///
+    /// This method returns [`Error::Path`], if a component value contains a
+    /// backslash or traversal, or [`Error::Format`], if the format is invalid.
+    ///
+    /// [`Error::Format`]: crate::id::Error::Format
+    /// [`Error::Path`]: crate::id::Error::Path
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::error::Error;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use zrx_id::ToId;
+    ///
+    /// // Create borrowed identifier from string
+    /// let id = "zri:file:::docs:index.md:".to_id_ref()?;
+    /// assert!(id.is_some());
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    fn to_id_ref(&self) -> Result<Option<IdRef<'_>>> {
+        IdRef::try_from(self.as_ref()).map(Some)
+    }
+}
+
+// ----------------------------------------------------------------------------
+// Functions
+// ----------------------------------------------------------------------------
+
+/// Invokes the given function with a borrowed identifier.
+///
+/// If the value can't be converted into a borrowed identifier, it's converted
+/// into an identifier first, which is then borrowed for the function call.
+pub(crate) fn with_id_ref<I, F, R, E>(id: &I, f: F) -> result::Result<R, E>
+where
+    I: ToId,
+    F: FnOnce(IdRef<'_>) -> result::Result<R, E>,
+    E: From<Error>,
+{
+    match id.to_id_ref()? {
+        Some(id) => f(id),
+        None => f(IdRef::from(id.to_id()?.as_ref())),
+    }
 }
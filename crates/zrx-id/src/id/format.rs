@@ -186,17 +186,7 @@ impl<const N: usize> Format<N> {
     /// ```
     #[must_use]
     pub fn get(&self, index: usize) -> Cow<'_, str> {
-        let p = self.spans[index].start as usize;
-        let q = self.spans[index].end as usize;
-        if self.flags & (1 << index) == 0 {
-            // SAFETY: The value is guaranteed to be valid UTF-8, as it was
-            // created from a valid UTF-8 string. Additionally, the value is
-            // not percent-encoded, so we can just return a borrowed reference
-            // to the formatted string value, which is the common fast path.
-            unsafe { Cow::Borrowed(from_utf8_unchecked(&self.value[p..q])) }
-        } else {
-            decode(&self.value[p..q])
-        }
+        component(self.as_str(), &self.spans, self.flags, index)
     }
 
     /// Creates an iterator over all components.
@@ -253,6 +243,30 @@ impl<const N: usize> Format<N> {
         // from valid UTF-8 strings, so we can just return a borrowed reference
         unsafe { from_utf8_unchecked(&self.value) }
     }
+
+    /// Creates a formatted string from previously parsed parts.
+    #[inline]
+    pub(crate) fn from_parts(
+        value: &str, spans: [Range<u16>; N], flags: u64,
+    ) -> Self {
+        Self {
+            value: Vec::from(value.as_bytes()),
+            spans,
+            flags,
+        }
+    }
+
+    /// Returns the spans.
+    #[inline]
+    pub(crate) fn spans(&self) -> &[Range<u16>; N] {
+        &self.spans
+    }
+
+    /// Returns the encoding flags.
+    #[inline]
+    pub(crate) fn flags(&self) -> u64 {
+        self.flags
+    }
 }
 
 // ----------------------------------------------------------------------------
@@ -282,66 +296,8 @@ impl<const N: usize> FromStr for Format<N> {
     /// # }
     /// ```
     fn from_str(value: &str) -> Result<Self> {
-        let mut spans = array::from_fn(|_| 0u16..0u16);
-        let mut flags = 0;
-
-        // Initialize start and span counter
-        let mut start = 0u16;
-        let mut index = 0;
-        let mut shift = 1;
-
-        // Compute spans from characters
-        for (i, char) in value.char_indices() {
-            match char {
-                // If the current character is a separator, finalize the span.
-                // It's very unlikely that any conversion here results in an
-                // error, but since identifiers might potentially contain user
-                // data, we handle it and return an error.
-                ':' => {
-                    let end = u16::try_from(i).map_err(|_| Error::Overflow)?;
-                    validate(&value[start.into()..end.into()])?;
-
-                    // Finalize current span
-                    spans[index] = start..end;
-                    index += 1;
-
-                    // Continue after separator
-                    start = end + 1;
-                    shift = 1 << index;
-                }
-
-                // If the current span contains a percent sign, and we haven't
-                // already marked the span as percent-encoded, check if the next
-                // two characters are valid hexadecimal digits. If so, mark it
-                // as percent-encoded. Otherwise, proceed without modification.
-                '%' if flags & shift == 0 => {
-                    let bytes = value.as_bytes();
-                    if let Some(&[b1, b2]) = bytes.get(i + 1..i + 3) {
-                        if b1.is_ascii_hexdigit() && b2.is_ascii_hexdigit() {
-                            flags |= shift;
-                        }
-                    }
-                }
-
-                // Consume all other characters
-                _ => {}
-            }
-        }
-
-        // Finalize last span
-        let end = u16::try_from(value.len()).map_err(|_| Error::Overflow)?;
-        spans[index] = start..end;
-
-        // Return format or error on number mismatch
-        if index == N - 1 {
-            Ok(Format {
-                value: Vec::from(value.as_bytes()),
-                spans,
-                flags,
-            })
-        } else {
-            Err(Error::Mismatch)
-        }
+        let (spans, flags) = parse(value)?;
+        Ok(Format::from_parts(value, spans, flags))
     }
 }
 
@@ -449,3 +405,99 @@ impl<const N: usize> fmt::Debug for Format<N> {
             .finish()
     }
 }
+
+// ----------------------------------------------------------------------------
+// Functions
+// ----------------------------------------------------------------------------
+
+/// Parses the spans and encoding flags of a formatted string.
+///
+/// This function only records the boundaries of the components, and does not
+/// copy the string, which allows to share parsing between [`Format`] and its
+/// borrowed counterparts, which reference the source string directly.
+///
+/// # Errors
+///
+/// If the span number is off, [`Error::Mismatch`] is returned.
+pub(crate) fn parse<const N: usize>(
+    value: &str,
+) -> Result<([Range<u16>; N], u64)> {
+    let mut spans = array::from_fn(|_| 0u16..0u16);
+    let mut flags = 0;
+
+    // Initialize start and span counter
+    let mut start = 0u16;
+    let mut index = 0;
+    let mut shift = 1;
+
+    // Compute spans from characters
+    for (i, char) in value.char_indices() {
+        match char {
+            // If the current character is a separator, finalize the span.
+            // It's very unlikely that any conversion here results in an
+            // error, but since identifiers might potentially contain user
+            // data, we handle it and return an error.
+            ':' => {
+                let end = u16::try_from(i).map_err(|_| Error::Overflow)?;
+                validate(&value[start.into()..end.into()])?;
+
+                // Finalize current span
+                spans[index] = start..end;
+                index += 1;
+
+                // Continue after separator
+                start = end + 1;
+                shift = 1 << index;
+            }
+
+            // If the current span contains a percent sign, and we haven't
+            // already marked the span as percent-encoded, check if the next
+            // two characters are valid hexadecimal digits. If so, mark it
+            // as percent-encoded. Otherwise, proceed without modification.
+            '%' if flags & shift == 0 => {
+                let bytes = value.as_bytes();
+                if let Some(&[b1, b2]) = bytes.get(i + 1..i + 3) {
+                    if b1.is_ascii_hexdigit() && b2.is_ascii_hexdigit() {
+                        flags |= shift;
+                    }
+                }
+            }
+
+            // Consume all other characters
+            _ => {}
+        }
+    }
+
+    // Finalize last span
+    let end = u16::try_from(value.len()).map_err(|_| Error::Overflow)?;
+    spans[index] = start..end;
+
+    // Return spans or error on number mismatch
+    if index == N - 1 {
+        Ok((spans, flags))
+    } else {
+        Err(Error::Mismatch)
+    }
+}
+
+/// Returns the value of the component at the given index of a string.
+///
+/// # Panics
+///
+/// Panics if the index is out of bounds.
+pub(crate) fn component<'a, const N: usize>(
+    value: &'a str, spans: &[Range<u16>; N], flags: u64, index: usize,
+) -> Cow<'a, str> {
+    let p = spans[index].start as usize;
+    let q = spans[index].end as usize;
+    if flags & (1 << index) == 0 {
+        // SAFETY: The spans are guaranteed to start and end at separators or
+        // the ends of the string, which are all character boundaries, since
+        // the separator is an ASCII character. Additionally, the value is not
+        // percent-encoded, so we can just return a borrowed reference to the
+        // string value, which is the common fast path.
+        unsafe { Cow::Borrowed(value.get_unchecked(p..q)) }
+    } else {
+        decode(&value.as_bytes()[p..q])
+    }
+}
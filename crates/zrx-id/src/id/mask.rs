@@ -0,0 +1,142 @@
+// Copyright (c) Zensical LLC <https://zensical.org>
+
+// SPDX-License-Identifier: MIT
+// Third-party contributions licensed under CLA
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+
+// ----------------------------------------------------------------------------
+
+//! Component mask.
+
+use std::ops::BitOr;
+
+// ----------------------------------------------------------------------------
+// Structs
+// ----------------------------------------------------------------------------
+
+/// Component mask.
+///
+/// Masks specify a set of components of an identifier, and are used with
+/// [`Id::eq_ignoring`][] to compare identifiers only by some components, e.g.,
+/// to group identifiers that only differ in their `variant`. Masks can be
+/// combined with `|`, and components can be removed with [`without`][].
+///
+/// [`Id::eq_ignoring`]: crate::id::Id::eq_ignoring
+/// [`without`]: ComponentMask::without
+///
+/// # Examples
+///
+/// ```
+/// use zrx_id::ComponentMask;
+///
+/// // Create mask of all components except variant
+/// let mask = ComponentMask::ALL.without(ComponentMask::VARIANT);
+/// assert!(mask.contains(ComponentMask::LOCATION));
+/// assert!(!mask.contains(ComponentMask::VARIANT));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ComponentMask(u8);
+
+// ----------------------------------------------------------------------------
+// Implementations
+// ----------------------------------------------------------------------------
+
+impl ComponentMask {
+    /// Mask of no components.
+    pub const NONE: Self = Self(0);
+    /// Mask of the `provider` component.
+    pub const PROVIDER: Self = Self(1 << 0);
+    /// Mask of the `resource` component.
+    pub const RESOURCE: Self = Self(1 << 1);
+    /// Mask of the `variant` component.
+    pub const VARIANT: Self = Self(1 << 2);
+    /// Mask of the `context` component.
+    pub const CONTEXT: Self = Self(1 << 3);
+    /// Mask of the `location` component.
+    pub const LOCATION: Self = Self(1 << 4);
+    /// Mask of the `fragment` component.
+    pub const FRAGMENT: Self = Self(1 << 5);
+    /// Mask of all components.
+    pub const ALL: Self = Self(0b11_1111);
+
+    /// Returns the mask without the components of the given mask.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zrx_id::ComponentMask;
+    ///
+    /// // Create mask without fragment
+    /// let mask = ComponentMask::ALL.without(ComponentMask::FRAGMENT);
+    /// assert!(!mask.contains(ComponentMask::FRAGMENT));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn without(self, other: Self) -> Self {
+        Self(self.0 & !other.0)
+    }
+
+    /// Returns whether the mask contains all components of the given mask.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zrx_id::ComponentMask;
+    ///
+    /// // Create mask and check components
+    /// let mask = ComponentMask::CONTEXT | ComponentMask::LOCATION;
+    /// assert!(mask.contains(ComponentMask::LOCATION));
+    /// assert!(!mask.contains(ComponentMask::ALL));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    /// Returns whether the mask contains the component at the given index,
+    /// where `provider` is `0` and `fragment` is `5`.
+    #[inline]
+    pub(crate) const fn contains_index(self, index: usize) -> bool {
+        self.0 & (1 << index) != 0
+    }
+}
+
+// ----------------------------------------------------------------------------
+// Trait implementations
+// ----------------------------------------------------------------------------
+
+impl BitOr for ComponentMask {
+    type Output = Self;
+
+    /// Returns the union of both masks.
+    #[inline]
+    fn bitor(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+}
+
+impl Default for ComponentMask {
+    /// Creates a mask of all components.
+    #[inline]
+    fn default() -> Self {
+        Self::ALL
+    }
+}
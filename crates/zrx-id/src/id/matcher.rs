@@ -26,16 +26,25 @@
 //! Matcher.
 
 use globset::GlobSet;
+use std::array;
+use std::borrow::Cow;
 use std::str::FromStr;
 
-use super::ToId;
+use super::convert::with_id_ref;
+use super::{IdRef, ToId};
 
 mod builder;
+#[cfg(feature = "cache")]
+mod cache;
+mod decision;
 mod error;
+mod explanation;
 mod selector;
 
 pub use builder::Builder;
+pub use decision::Decision;
 pub use error::{Error, Result};
+pub use explanation::{ComponentMatch, MatchExplanation};
 pub use selector::{Selector, ToSelector};
 
 // ----------------------------------------------------------------------------
@@ -54,6 +63,14 @@ pub use selector::{Selector, ToSelector};
 /// identifier in linear time in relation to the length of the input string,
 /// and which return the set of matched selectors.
 ///
+/// Components support the full glob syntax of [`globset`], which includes
+/// alternation groups, so `**/*.{md,markdown}` matches both extensions.
+///
+/// Selectors can also be added as exclusions, see [`Builder::exclude`], which
+/// are ordered by precedence: the last selector that matches an identifier
+/// decides whether it's included or excluded, so later selectors can carve
+/// exceptions out of earlier ones, much like in `.gitignore` files.
+///
 /// # Examples
 ///
 /// ```
@@ -88,6 +105,14 @@ pub struct Matcher {
     location: GlobSet,
     /// Glob set for selector.
     fragment: GlobSet,
+    /// Selectors in order of addition.
+    selectors: Vec<Selector>,
+    /// Whether selectors are exclusions, in order of addition.
+    excluded: Vec<bool>,
+    /// Whether any selector is an exclusion.
+    has_exclusions: bool,
+    /// Component comparison order.
+    order: [usize; 6],
 }
 
 // ----------------------------------------------------------------------------
@@ -118,6 +143,9 @@ impl Matcher {
     /// tries to short-circuits the comparison. Note that empty components are
     /// considered wildcards, so they will always match.
     ///
+    /// If the matcher contains exclusions, this method is equivalent to
+    /// checking whether [`Matcher::classify`] returns [`Decision::Included`].
+    ///
     /// # Errors
     ///
     /// This method returns an error if the given identifier is invalid.
@@ -147,15 +175,18 @@ impl Matcher {
     where
         I: ToId,
     {
-        let id = id.to_id()?;
-
-        // Compare components in descending variability
-        Ok(compare(&self.location, Some(id.location().as_ref()))
-            && compare(&self.context, Some(id.context().as_ref()))
-            && compare(&self.provider, Some(id.provider().as_ref()))
-            && compare(&self.resource, id.resource().as_deref())
-            && compare(&self.fragment, id.fragment().as_deref())
-            && compare(&self.variant, id.variant().as_deref()))
+        with_id_ref(&id, |id| {
+            if self.has_exclusions {
+                return self
+                    .classify(&id)
+                    .map(|decision| decision == Decision::Included);
+            }
+
+            // Compare components in comparison order
+            Ok(self.order.iter().all(|&index| {
+                compare(self.component(index), value(&id, index).as_deref())
+            }))
+        })
     }
 
     /// Returns the match set of the selectors that match the identifier.
@@ -165,6 +196,11 @@ impl Matcher {
     /// returns the indices of the matching selectors in the order they were
     /// added to the [`Matcher`].
     ///
+    /// Exclusions are never part of the match set. Since the last selector that
+    /// matches takes precedence, an exclusion also removes all selectors added
+    /// before it from the match set, so the match set is non-empty if and only
+    /// if [`Matcher::classify`] returns [`Decision::Included`].
+    ///
     /// Components are compared in descending variability and their likelihood
     /// for mismatch, starting with the `location`. This approach effectively
     /// tries to short-circuits the comparison. Note that empty components are
@@ -194,55 +230,307 @@ impl Matcher {
     /// # Ok(())
     /// # }
     /// ```
+    pub fn matches<I>(&self, id: I) -> Result<Vec<usize>>
+    where
+        I: ToId,
+    {
+        self.matches_iter(id).map(Iterator::collect)
+    }
+
+    /// Returns an iterator over the match set of the identifier.
+    ///
+    /// This method is equivalent to [`Matcher::matches`], but yields indices
+    /// of the matching selectors lazily instead of collecting them, which is
+    /// useful for callers that only iterate over the match set once.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error if the given identifier is invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::error::Error;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use zrx_id::{Id, Matcher};
+    ///
+    /// // Create matcher builder and add selectors
+    /// let mut builder = Matcher::builder();
+    /// builder.add("zrs:::::**/*.md:")?;
+    /// builder.add("zrs::::docs::")?;
+    ///
+    /// // Create matcher from builder
+    /// let matcher = builder.build()?;
+    ///
+    /// // Create identifier and iterate over match set
+    /// let id: Id = "zri:file:::docs:index.md:".parse()?;
+    /// for index in matcher.matches_iter(&id)? {
+    ///     println!("{index}");
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn matches_iter<I>(
+        &self, id: I,
+    ) -> Result<impl Iterator<Item = usize> + '_>
+    where
+        I: ToId,
+    {
+        let slots = self.slots(id)?;
+
+        // Obtain match set by yielding the indices of all matching selectors,
+        // which are the slots that match exactly six components, starting
+        // after the last matching exclusion, as it takes precedence
+        let start = self.last_exclusion(&slots).map_or(0, |index| index + 1);
+        let iter = slots.into_iter().enumerate().skip(start);
+        Ok(iter.filter_map(|(index, count)| (count == 6).then_some(index)))
+    }
+
+    /// Classifies the identifier as included, excluded or unmatched.
+    ///
+    /// This method distinguishes identifiers that were explicitly excluded by
+    /// an exclusion from identifiers that no selector matched. Selectors are
+    /// ordered by precedence, so the last selector that matches decides.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error if the given identifier is invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::error::Error;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use zrx_id::matcher::Decision;
+    /// use zrx_id::{Id, Matcher};
+    ///
+    /// // Create matcher builder with selectors and exclusion
+    /// let matcher = Matcher::builder()
+    ///     .with("zrs:::::**/*.md:")?
+    ///     .without("zrs:::::drafts/**:")?
+    ///     .build()?;
+    ///
+    /// // Create identifiers and classify them
+    /// let id: Id = "zri:file:::docs:index.md:".parse()?;
+    /// assert_eq!(matcher.classify(&id)?, Decision::Included);
+    /// let id: Id = "zri:file:::docs:drafts/index.md:".parse()?;
+    /// assert_eq!(matcher.classify(&id)?, Decision::Excluded);
+    /// let id: Id = "zri:file:::docs:index.rs:".parse()?;
+    /// assert_eq!(matcher.classify(&id)?, Decision::Unmatched);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn classify<I>(&self, id: I) -> Result<Decision>
+    where
+        I: ToId,
+    {
+        let slots = self.slots(id)?;
+        Ok(match slots.iter().rposition(|&count| count == 6) {
+            Some(index) if self.excluded[index] => Decision::Excluded,
+            Some(_) => Decision::Included,
+            None => Decision::Unmatched,
+        })
+    }
+
+    /// Returns the selectors that match the identifier.
+    ///
+    /// This method is equivalent to [`Matcher::matches`], but returns the
+    /// matching selectors instead of their indices, which is useful to report
+    /// which selectors matched without keeping a separate lookup table.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error if the given identifier is invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::error::Error;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use zrx_id::{Id, Matcher};
+    ///
+    /// // Create matcher builder and add selectors
+    /// let mut builder = Matcher::builder();
+    /// builder.add("zrs:::::**/*.md:")?;
+    /// builder.add("zrs:::::**/*.rs:")?;
+    /// builder.add("zrs::::docs::")?;
+    ///
+    /// // Create matcher from builder
+    /// let matcher = builder.build()?;
+    ///
+    /// // Create identifier and obtain matched selectors
+    /// let id: Id = "zri:file:::docs:index.md:".parse()?;
+    /// let selectors = matcher.matched_selectors(&id)?;
+    /// for (selector, index) in selectors.iter().zip(matcher.matches(&id)?) {
+    ///     assert_eq!(*selector, &matcher.selectors()[index]);
+    /// }
+    /// assert_eq!(selectors[0].as_str(), "zrs:::::**/*.md:");
+    /// assert_eq!(selectors[1].as_str(), "zrs::::docs::");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn matched_selectors<I>(&self, id: I) -> Result<Vec<&Selector>>
+    where
+        I: ToId,
+    {
+        let iter = self.matches(id)?.into_iter();
+        Ok(iter.map(|index| &self.selectors[index]).collect())
+    }
+
+    /// Explains whether the identifier matches the selector at the given index.
+    ///
+    /// This method compares each component of the identifier against the
+    /// corresponding component of the selector, and returns an explanation
+    /// that includes the pattern and value compared for each component, so
+    /// it's easy to see which component caused a mismatch. In contrast to
+    /// [`Matcher::matches`], comparison doesn't short-circuit, and exclusions
+    /// are not taken into account.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error if the given identifier is invalid, or
+    /// [`Error::Unknown`], if there's no selector at the given index.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::error::Error;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use zrx_id::{Id, Matcher};
+    ///
+    /// // Create matcher from string
+    /// let matcher: Matcher = "zrs::::docs:**/*.md:".parse()?;
+    ///
+    /// // Create identifier and explain match
+    /// let id: Id = "zri:file:::docs:index.md:".parse()?;
+    /// let explanation = matcher.explain(&id, 0)?;
+    /// assert!(explanation.is_match());
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn explain<I>(&self, id: I, index: usize) -> Result<MatchExplanation>
+    where
+        I: ToId,
+    {
+        with_id_ref(&id, |id| {
+            let selector =
+                self.selectors.get(index).ok_or(Error::Unknown(index))?;
+
+            // Compare each component, including the pattern and value compared
+            let names = [
+                "provider", "resource", "variant", "context", "location",
+                "fragment",
+            ];
+            let patterns = [
+                selector.provider(),
+                selector.resource(),
+                selector.variant(),
+                selector.context(),
+                selector.location(),
+                selector.fragment(),
+            ];
+            Ok(MatchExplanation::new(array::from_fn(|n| {
+                let value = value(&id, n);
+
+                // Absent values are compared like in the comparison function,
+                // so wildcards match them, but patterns don't
+                let matched = self
+                    .component(n)
+                    .matches(value.as_deref().unwrap_or("\u{FFFE}"))
+                    .contains(&index);
+                ComponentMatch::new(
+                    names[n],
+                    patterns[n].as_deref().map(str::to_owned),
+                    value.map(Cow::into_owned),
+                    matched,
+                )
+            })))
+        })
+    }
+
+    /// Counts the matching components of each selector for the identifier.
+    ///
+    /// Selectors that match the identifier have a count of six, one for each
+    /// component. If a component doesn't match any selector, the returned
+    /// vector is empty, as no selector can match the identifier.
     #[allow(clippy::if_not_else)]
     #[allow(clippy::needless_pass_by_value)]
-    pub fn matches<I>(&self, id: I) -> Result<Vec<usize>>
+    fn slots<I>(&self, id: I) -> Result<Vec<u8>>
     where
         I: ToId,
     {
-        let id = id.to_id()?;
-
-        // Create a vector and count the matches of each component in the slots
-        // of the vector to find all selectors that match the given identifier
-        let mut slots = vec![0u8; self.provider.len()];
-        for (component, value) in [
-            (&self.location, Some(id.location())),
-            (&self.context, Some(id.context())),
-            (&self.provider, Some(id.provider())),
-            (&self.resource, id.resource()),
-            (&self.fragment, id.fragment()),
-            (&self.variant, id.variant()),
-        ] {
-            if let Some(value) = value {
-                let matches = component.matches(value.as_ref());
-                if !matches.is_empty() {
-                    for index in matches {
-                        slots[index] += 1;
+        with_id_ref(&id, |id| {
+            // Create a vector and count the matches of each component in the
+            // slots of the vector to find all selectors that match the given
+            // identifier
+            let mut slots = vec![0u8; self.provider.len()];
+            for &index in &self.order {
+                let component = self.component(index);
+                if let Some(value) = value(&id, index) {
+                    let matches = component.matches(value.as_ref());
+                    if !matches.is_empty() {
+                        for index in matches {
+                            slots[index] += 1;
+                        }
+
+                    // Short-circuit, as the current component doesn't match, so
+                    // we know the result must be empty and can stop immediately
+                    } else {
+                        slots.clear();
+                        break;
                     }
 
-                // Short-circuit, as the current component doesn't match, so we
-                // know the result must be empty and can return immediately
+                // Wildcard match, which means all slots must be updated
                 } else {
-                    return Ok(Vec::new());
-                }
-
-            // Wildcard match, which means all slots must be updated
-            } else {
-                for count in &mut slots {
-                    *count += 1;
+                    for count in &mut slots {
+                        *count += 1;
+                    }
                 }
             }
+            Ok(slots)
+        })
+    }
+
+    /// Returns the index of the last matching exclusion in the given slots.
+    fn last_exclusion(&self, slots: &[u8]) -> Option<usize> {
+        if !self.has_exclusions {
+            return None;
+        }
+        let mut iter = slots.iter().zip(&self.excluded);
+        iter.rposition(|(&count, &excluded)| excluded && count == 6)
+    }
+
+    /// Returns the glob set for the component at the given index.
+    fn component(&self, index: usize) -> &GlobSet {
+        match index {
+            0 => &self.provider,
+            1 => &self.resource,
+            2 => &self.variant,
+            3 => &self.context,
+            4 => &self.location,
+            5 => &self.fragment,
+            _ => unreachable!(),
         }
+    }
+}
 
-        // Obtain match set by collecting the indices of all matching selectors,
-        // which are the slots that match exactly five components
-        let iter = slots
-            .iter()
-            .enumerate()
-            .filter_map(|(index, &count)| (count == 6).then_some(index));
+#[allow(clippy::must_use_candidate)]
+impl Matcher {
+    /// Returns the selectors in order of addition.
+    #[inline]
+    pub fn selectors(&self) -> &[Selector] {
+        &self.selectors
+    }
 
-        // Return match set
-        Ok(iter.collect())
+    /// Returns the component comparison order.
+    ///
+    /// Components are identified by their index, i.e., `provider` is `0` and
+    /// `fragment` is `5`. Unless the matcher was built in adaptive mode, see
+    /// [`Builder::adaptive`], this is the order of descending variability.
+    #[inline]
+    pub fn order(&self) -> [usize; 6] {
+        self.order
     }
 }
 
@@ -302,3 +590,260 @@ impl FromStr for Matcher {
 fn compare(component: &GlobSet, value: Option<&str>) -> bool {
     component.is_match(value.unwrap_or("\u{FFFE}"))
 }
+
+/// Returns the value of the component at the given index of an identifier.
+fn value<'a>(id: &IdRef<'a>, index: usize) -> Option<Cow<'a, str>> {
+    match index {
+        0 => Some(id.provider()),
+        1 => id.resource(),
+        2 => id.variant(),
+        3 => Some(id.context()),
+        4 => Some(id.location()),
+        5 => id.fragment(),
+        _ => unreachable!(),
+    }
+}
+
+// ----------------------------------------------------------------------------
+// Tests
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+
+    mod is_match {
+        use std::borrow::Cow;
+
+        use crate::id::{Id, IdRef, Result};
+        use crate::{Matcher, ToId};
+
+        struct Custom(Id);
+
+        impl ToId for Custom {
+            fn to_id(&self) -> Result<Cow<'_, Id>> {
+                Ok(Cow::Borrowed(&self.0))
+            }
+        }
+
+        #[test]
+        fn handles_borrowed_like_owned() {
+            let selectors = [
+                "zrs:::::**/*.md:",
+                "zrs:git::::**:",
+                "zrs::::docs::",
+                "zrs:::en:::",
+                "zrs:::::**/a%3Ab.md:",
+                "zrs::::::anchor",
+            ];
+            let ids = [
+                "zri:file:::docs:index.md:",
+                "zri:git:main::src:lib.rs:",
+                "zri:file::en:docs:guide/intro.md:anchor",
+                "zri:file:::docs:a%3Ab.md:",
+                "zri:file:::src:a%3Ab.rs:",
+            ];
+            for selector in selectors {
+                let matcher: Matcher = selector.parse().unwrap();
+                for value in ids {
+                    let id: Id = value.parse().unwrap();
+                    let id_ref = IdRef::try_from(value).unwrap();
+                    assert_eq!(
+                        matcher.is_match(&id).unwrap(),
+                        matcher.is_match(&id_ref).unwrap(),
+                        "{selector} {value}"
+                    );
+                }
+            }
+        }
+
+        #[test]
+        fn handles_alternation_groups() {
+            let matcher: Matcher =
+                "zrs:::::**/*.{md,markdown}:".parse().unwrap();
+            for (id, check) in [
+                ("zri:file:::docs:index.md:", true),
+                ("zri:file:::docs:guide/intro.markdown:", true),
+                ("zri:file:::docs:index.rs:", false),
+                ("zri:file:::docs:index.mdx:", false),
+                ("zri:file:::docs:index.md,markdown:", false),
+            ] {
+                assert_eq!(matcher.is_match(id).unwrap(), check, "{id}");
+            }
+        }
+
+        #[test]
+        fn handles_implementors_without_borrowing() {
+            let matcher: Matcher = "zrs:::::**/*.md:".parse().unwrap();
+            for (value, expected) in [
+                ("zri:file:::docs:index.md:", true),
+                ("zri:file:::src:lib.rs:", false),
+            ] {
+                let id = Custom(value.parse().unwrap());
+                assert_eq!(matcher.is_match(id).unwrap(), expected, "{value}");
+            }
+        }
+    }
+
+    mod classify {
+        use crate::id::matcher::Decision;
+        use crate::Matcher;
+
+        #[test]
+        fn handles_all_decisions() {
+            let matcher = Matcher::builder()
+                .with("zrs:::::**/*.md:")
+                .unwrap()
+                .without("zrs:::::drafts/**:")
+                .unwrap()
+                .with("zrs:::::drafts/keep.md:")
+                .unwrap()
+                .build()
+                .unwrap();
+            for (id, decision) in [
+                ("zri:file:::docs:index.md:", Decision::Included),
+                ("zri:file:::docs:drafts/index.md:", Decision::Excluded),
+                ("zri:file:::docs:drafts/index.rs:", Decision::Excluded),
+                ("zri:file:::docs:drafts/keep.md:", Decision::Included),
+                ("zri:file:::docs:index.rs:", Decision::Unmatched),
+            ] {
+                assert_eq!(matcher.classify(id).unwrap(), decision, "{id}");
+                assert_eq!(
+                    matcher.is_match(id).unwrap(),
+                    decision == Decision::Included,
+                    "{id}"
+                );
+            }
+        }
+    }
+
+    mod matches {
+        use crate::id::{Id, IdRef};
+        use crate::Matcher;
+
+        #[test]
+        fn handles_borrowed_like_owned() {
+            let matcher = Matcher::builder()
+                .with_many([
+                    "zrs:::::**/*.md:",
+                    "zrs:file::::**:",
+                    "zrs::::docs::",
+                    "zrs:::::**/a%3Ab.*:",
+                ])
+                .unwrap()
+                .build()
+                .unwrap();
+            for value in [
+                "zri:file:::docs:index.md:",
+                "zri:git:main::src:lib.rs:",
+                "zri:file:::src:a%3Ab.rs:",
+            ] {
+                let id: Id = value.parse().unwrap();
+                let id_ref = IdRef::try_from(value).unwrap();
+                assert_eq!(
+                    matcher.matches(&id).unwrap(),
+                    matcher.matches(&id_ref).unwrap(),
+                );
+                assert_eq!(
+                    matcher.matches(&id).unwrap(),
+                    matcher.matches(value).unwrap(),
+                );
+            }
+        }
+
+        #[test]
+        fn omits_exclusions() {
+            let matcher = Matcher::builder()
+                .with("zrs:::::**/*.md:")
+                .unwrap()
+                .without("zrs:::::drafts/**:")
+                .unwrap()
+                .with("zrs::::docs::")
+                .unwrap()
+                .build()
+                .unwrap();
+            for (id, matches) in [
+                ("zri:file:::docs:index.md:", vec![0, 2]),
+                ("zri:file:::docs:drafts/index.md:", vec![2]),
+                ("zri:file:::src:drafts/index.md:", vec![]),
+            ] {
+                assert_eq!(matcher.matches(id).unwrap(), matches, "{id}");
+            }
+        }
+    }
+
+    mod matches_iter {
+        use crate::Matcher;
+
+        #[test]
+        fn yields_same_as_matches() {
+            let matcher = Matcher::builder()
+                .with_many([
+                    "zrs:::::**/*.md:",
+                    "zrs:file::::**:",
+                    "zrs::::docs::",
+                    "zrs:git::::**:",
+                ])
+                .unwrap()
+                .build()
+                .unwrap();
+            for id in [
+                "zri:file:::docs:index.md:",
+                "zri:git:main::src:lib.rs:",
+                "zri:file:::src:lib.rs:",
+                "zri:http:::src:lib.rs:",
+            ] {
+                let iter = matcher.matches_iter(id).unwrap();
+                assert_eq!(
+                    iter.collect::<Vec<_>>(),
+                    matcher.matches(id).unwrap(),
+                    "{id}"
+                );
+            }
+        }
+    }
+
+    mod explain {
+        use crate::id::matcher::ComponentMatch;
+        use crate::Matcher;
+
+        #[test]
+        fn identifies_location_mismatch() {
+            let matcher = Matcher::builder()
+                .with_many(["zrs::::docs:**/*.md:", "zrs:file::::**:"])
+                .unwrap()
+                .build()
+                .unwrap();
+
+            // Ensure location is identified as the failing component
+            let id = "zri:file:::docs:index.rs:";
+            let explanation = matcher.explain(id, 0).unwrap();
+            assert!(!explanation.is_match());
+            let mismatch = explanation.mismatch().unwrap();
+            assert_eq!(mismatch.name(), "location");
+            assert_eq!(mismatch.pattern(), Some("**/*.md"));
+            assert_eq!(mismatch.value(), Some("index.rs"));
+
+            // Ensure all other components matched
+            let names = explanation
+                .components()
+                .iter()
+                .filter(|component| component.is_match())
+                .map(ComponentMatch::name)
+                .collect::<Vec<_>>();
+            assert_eq!(
+                names,
+                ["provider", "resource", "variant", "context", "fragment"]
+            );
+
+            // Ensure explanation agrees with matches
+            assert!(matcher.explain(id, 1).unwrap().is_match());
+            assert_eq!(matcher.matches(id).unwrap(), [1]);
+        }
+
+        #[test]
+        fn rejects_unknown_selector() {
+            let matcher: Matcher = "zrs:::::**/*.md:".parse().unwrap();
+            assert!(matcher.explain("zri:file:::docs:index.md:", 1).is_err());
+        }
+    }
+}
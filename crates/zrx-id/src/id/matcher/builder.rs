@@ -25,10 +25,17 @@
 
 //! Matcher builder.
 
-use globset::{Glob, GlobSetBuilder};
+#[cfg(not(feature = "cache"))]
+use globset::GlobSetBuilder;
+use globset::{Glob, GlobSet};
+use std::borrow::Cow;
+use std::cmp::Reverse;
+use std::collections::BTreeSet;
 
-use super::error::Result;
-use super::selector::ToSelector;
+#[cfg(feature = "cache")]
+use super::cache;
+use super::error::{Error, Result};
+use super::selector::{Selector, ToSelector};
 use super::Matcher;
 
 // ----------------------------------------------------------------------------
@@ -38,18 +45,15 @@ use super::Matcher;
 /// Matcher builder.
 #[derive(Clone, Debug)]
 pub struct Builder {
-    /// Glob set builder for provider.
-    provider: GlobSetBuilder,
-    /// Glob set builder for resource.
-    resource: GlobSetBuilder,
-    /// Glob set builder for variant.
-    variant: GlobSetBuilder,
-    /// Glob set builder for context.
-    context: GlobSetBuilder,
-    /// Glob set builder for location.
-    location: GlobSetBuilder,
-    /// Glob set builder for fragment.
-    fragment: GlobSetBuilder,
+    /// Glob set builders, in order of components.
+    #[cfg(not(feature = "cache"))]
+    builders: [GlobSetBuilder; 6],
+    /// Selectors in order of addition.
+    selectors: Vec<Selector>,
+    /// Whether selectors are exclusions, in order of addition.
+    excluded: Vec<bool>,
+    /// Whether to use adaptive component order.
+    adaptive: bool,
 }
 
 // ----------------------------------------------------------------------------
@@ -73,21 +77,71 @@ impl Builder {
     #[must_use]
     pub fn new() -> Self {
         Self {
-            provider: GlobSetBuilder::new(),
-            resource: GlobSetBuilder::new(),
-            variant: GlobSetBuilder::new(),
-            context: GlobSetBuilder::new(),
-            location: GlobSetBuilder::new(),
-            fragment: GlobSetBuilder::new(),
+            #[cfg(not(feature = "cache"))]
+            builders: std::array::from_fn(|_| GlobSetBuilder::new()),
+            selectors: Vec::new(),
+            excluded: Vec::new(),
+            adaptive: false,
         }
     }
 
+    /// Enables or disables adaptive component order.
+    ///
+    /// By default, components are compared in the order of their descending
+    /// variability, starting with the `location`, which is a good heuristic
+    /// for most selector sets. In adaptive mode, [`Builder::build`] measures
+    /// the selectivity of each component across all selectors, and compares
+    /// the most selective components first, in order to short-circuit early.
+    /// This can considerably speed up matching of skewed selector sets, e.g.,
+    /// when most selectors only constrain the `provider`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::error::Error;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use zrx_id::{Id, Matcher};
+    ///
+    /// // Create selectors only constraining provider
+    /// let selectors = ["zrs:git:::::", "zrs:http:::::", "zrs:s3::::**/*.md:"];
+    ///
+    /// // Create matchers with default and adaptive order
+    /// let default = Matcher::builder().with_many(selectors)?.build()?;
+    /// let adaptive = Matcher::builder()
+    ///     .adaptive(true)
+    ///     .with_many(selectors)?
+    ///     .build()?;
+    /// assert_eq!(adaptive.order()[0], 0);
+    ///
+    /// // Ensure both matchers produce the same results
+    /// for id in [
+    ///     "zri:file:::docs:index.md:",
+    ///     "zri:git:::docs:index.md:",
+    ///     "zri:s3:::docs:index.md:",
+    ///     "zri:s3:::docs:index.rs:",
+    /// ] {
+    ///     let id: Id = id.parse()?;
+    ///     assert_eq!(default.matches(&id)?, adaptive.matches(&id)?);
+    ///     assert_eq!(default.is_match(&id)?, adaptive.is_match(&id)?);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn adaptive(mut self, value: bool) -> Self {
+        self.adaptive = value;
+        self
+    }
+
     /// Extends the matcher with the given selector.
     ///
     /// This method adds a [`Selector`][] to the matcher, creating a [`Glob`]
     /// from each component, adding it to the corresponding [`GlobSetBuilder`].
     /// If a component is empty, it is coerced to `**`, as the counts of all
     /// components must match for correct intersection in [`Matcher::matches`].
+    /// With the `cache` feature, components are only validated, as glob sets
+    /// are compiled from the selectors, or taken from the cache, when building.
     ///
     /// [`Selector`]: crate::id::matcher::Selector
     ///
@@ -117,6 +171,57 @@ impl Builder {
         Ok(self)
     }
 
+    /// Extends the matcher with the given selectors.
+    ///
+    /// This method adds all selectors of the given iterator to the matcher in
+    /// order, which is the natural way to construct a matcher from a list of
+    /// rules, e.g., a [`Vec`] of strings obtained from a configuration file.
+    ///
+    /// # Errors
+    ///
+    /// This method returns [`Error::Selector`] with the index of the first
+    /// selector that is invalid, wrapping the underlying error, and stops
+    /// adding further selectors, consuming the builder in the process.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::error::Error;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use zrx_id::{matcher, Id, Matcher};
+    ///
+    /// // Create matcher builder with selectors
+    /// let builder = Matcher::builder().with_many([
+    ///     "zrs:::::**/*.md:",
+    ///     "zrs:::::**/*.rs:",
+    ///     "zrs:git::::**/*.toml:",
+    /// ])?;
+    ///
+    /// // Create matcher from builder
+    /// let matcher = builder.build()?;
+    ///
+    /// // Create identifier and obtain matched selectors
+    /// let id: Id = "zri:file:::docs:index.md:".parse()?;
+    /// assert_eq!(matcher.matches(&id)?, [0]);
+    ///
+    /// // Create matcher builder with invalid selector
+    /// let res = Matcher::builder().with_many(["zrs:::::**/*.md:", "zri"]);
+    /// assert!(matches!(res, Err(matcher::Error::Selector(1, _))));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_many<I, S>(mut self, selectors: I) -> Result<Self>
+    where
+        I: IntoIterator<Item = S>,
+        S: ToSelector,
+    {
+        for (index, selector) in selectors.into_iter().enumerate() {
+            self.add(selector)
+                .map_err(|err| Error::Selector(index, Box::new(err)))?;
+        }
+        Ok(self)
+    }
+
     /// Adds a selector to the matcher.
     ///
     /// Note that [`Builder::with`] offers better ergonomics to create matchers
@@ -149,20 +254,98 @@ impl Builder {
     {
         let selector = selector.to_selector()?;
 
-        // Compile and add each component of the given selector
-        self.provider.add(parse(selector.provider().as_deref())?);
-        self.resource.add(parse(selector.resource().as_deref())?);
-        self.variant.add(parse(selector.variant().as_deref())?);
-        self.context.add(parse(selector.context().as_deref())?);
-        self.location.add(parse(selector.location().as_deref())?);
-        self.fragment.add(parse(selector.fragment().as_deref())?);
+        // Compile and add each component of the given selector, or only check
+        // that it's valid, if glob sets are taken from the cache when building
+        for index in 0..6 {
+            let value = component(&selector, index);
+            #[cfg(not(feature = "cache"))]
+            self.builders[index].add(parse(value.as_deref())?);
+            #[cfg(feature = "cache")]
+            parse(value.as_deref())?;
+        }
+
+        // Retain selector, so matches can be mapped back to it
+        self.selectors.push(selector.into_owned());
+        self.excluded.push(false);
 
         // Return matcher for chaining
         Ok(self)
     }
 
+    /// Extends the matcher with the given selector as an exclusion.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error if the given selector is invalid, or if a
+    /// component cannot successfully be parsed into a valid [`Glob`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::error::Error;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use zrx_id::Matcher;
+    ///
+    /// // Create matcher builder with selector and exclusion
+    /// let mut builder = Matcher::builder()
+    ///     .with("zrs:::::**/*.md:")?
+    ///     .without("zrs:::::drafts/**:")?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn without<S>(mut self, selector: S) -> Result<Self>
+    where
+        S: ToSelector,
+    {
+        self.exclude(selector)?;
+        Ok(self)
+    }
+
+    /// Adds a selector to the matcher as an exclusion.
+    ///
+    /// Exclusions are compiled like any other selector, but identifiers they
+    /// match are classified as [`Decision::Excluded`][], unless a selector that
+    /// was added later matches as well, as the last matching selector wins.
+    ///
+    /// [`Decision::Excluded`]: crate::id::matcher::Decision::Excluded
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error if the given selector is invalid, or if a
+    /// component cannot successfully be parsed into a valid [`Glob`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::error::Error;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use zrx_id::Matcher;
+    ///
+    /// // Create matcher builder and add selector and exclusion
+    /// let mut builder = Matcher::builder();
+    /// builder.add("zrs:::::**/*.md:")?;
+    /// builder.exclude("zrs:::::drafts/**:")?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn exclude<S>(&mut self, selector: S) -> Result<&mut Self>
+    where
+        S: ToSelector,
+    {
+        self.add(selector)?;
+        if let Some(excluded) = self.excluded.last_mut() {
+            *excluded = true;
+        }
+        Ok(self)
+    }
+
     /// Builds the matcher.
     ///
+    /// When the `cache` feature is enabled, compiled glob sets are cached for
+    /// the lifetime of the process, and reused by subsequent builds with the
+    /// same patterns for a component, which cuts the cost of rebuilds.
+    ///
     /// # Errors
     ///
     /// This method returns an error if the [`GlobSet`][] that is associated
@@ -187,13 +370,51 @@ impl Builder {
     /// # }
     /// ```
     pub fn build(self) -> Result<Matcher> {
+        let order = if self.adaptive {
+            adaptive_order(&self.selectors)
+        } else {
+            DEFAULT_ORDER
+        };
+
+        // Compile glob sets, reusing cached glob sets if possible
+        #[cfg(feature = "cache")]
+        let [provider, resource, variant, context, location, fragment] = {
+            let mut sets: [GlobSet; 6] = Default::default();
+            for (index, set) in sets.iter_mut().enumerate() {
+                let iter = self.selectors.iter();
+                let patterns = iter
+                    .map(|selector| component(selector, index))
+                    .map(|value| {
+                        value.map_or_else(|| "**".into(), Cow::into_owned)
+                    })
+                    .collect();
+                *set = cache::get_or_compile(patterns)?;
+            }
+            sets
+        };
+
+        // Compile glob sets
+        #[cfg(not(feature = "cache"))]
+        let [provider, resource, variant, context, location, fragment] = {
+            let mut sets: [GlobSet; 6] = Default::default();
+            for (set, builder) in sets.iter_mut().zip(&self.builders) {
+                *set = builder.build()?;
+            }
+            sets
+        };
+
+        // Return matcher
         Ok(Matcher {
-            provider: self.provider.build()?,
-            resource: self.resource.build()?,
-            variant: self.variant.build()?,
-            context: self.context.build()?,
-            location: self.location.build()?,
-            fragment: self.fragment.build()?,
+            provider,
+            resource,
+            variant,
+            context,
+            location,
+            fragment,
+            selectors: self.selectors,
+            has_exclusions: self.excluded.contains(&true),
+            excluded: self.excluded,
+            order,
         })
     }
 }
@@ -219,16 +440,70 @@ impl Default for Builder {
     }
 }
 
+// ----------------------------------------------------------------------------
+// Constants
+// ----------------------------------------------------------------------------
+
+/// Default component order, i.e., descending variability.
+const DEFAULT_ORDER: [usize; 6] = [4, 3, 0, 1, 5, 2];
+
 // ----------------------------------------------------------------------------
 // Functions
 // ----------------------------------------------------------------------------
 
+/// Computes the component order from the selectivity of the given selectors.
+///
+/// The selectivity of a component is the number of selectors that constrain
+/// it, i.e., that don't use a wildcard, followed by the number of distinct
+/// patterns. Components are sorted by descending selectivity, and ties are
+/// resolved by falling back to the default order.
+fn adaptive_order(selectors: &[Selector]) -> [usize; 6] {
+    let mut selectivity = [(0, 0); 6];
+    for (index, entry) in selectivity.iter_mut().enumerate() {
+        let mut patterns = BTreeSet::new();
+        for selector in selectors {
+            let value = component(selector, index);
+
+            // Wildcards match everything, so they don't add selectivity
+            if let Some(value) = value.filter(|value| value != "**") {
+                entry.0 += 1;
+                patterns.insert(value);
+            }
+        }
+        entry.1 = patterns.len();
+    }
+
+    // Sort components by descending selectivity, which is stable, so ties
+    // are resolved by the position of the component in the default order
+    let mut order = DEFAULT_ORDER;
+    order.sort_by_key(|&index| Reverse(selectivity[index]));
+    order
+}
+
+/// Returns the component of the selector at the given index.
+///
+/// Indices follow the order of components in the string representation, i.e.,
+/// `provider`, `resource`, `variant`, `context`, `location` and `fragment`.
+fn component(selector: &Selector, index: usize) -> Option<Cow<'_, str>> {
+    match index {
+        0 => selector.provider(),
+        1 => selector.resource(),
+        2 => selector.variant(),
+        3 => selector.context(),
+        4 => selector.location(),
+        _ => selector.fragment(),
+    }
+}
+
 /// Parses a component into a glob.
 ///
 /// Note that wildcards are implicit, which means that empty components are
 /// coerced to `**` to provide an ergonomic API for creating selectors. We must
 /// create a selector for each component, or the component count of selectors
 /// will not be coherent, which is essential for correct matching.
-fn parse(component: Option<&str>) -> Result<Glob> {
+///
+/// Alternation groups, e.g., `**/*.{md,markdown}`, are always enabled by the
+/// [`Glob`] parser, so a single component can match multiple alternatives.
+pub(super) fn parse(component: Option<&str>) -> Result<Glob> {
     Ok(Glob::new(component.unwrap_or("**"))?)
 }
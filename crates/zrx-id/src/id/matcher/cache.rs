@@ -0,0 +1,186 @@
+// Copyright (c) Zensical LLC <https://zensical.org>
+
+// SPDX-License-Identifier: MIT
+// Third-party contributions licensed under CLA
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+
+// ----------------------------------------------------------------------------
+
+//! Matcher cache.
+
+use globset::{GlobSet, GlobSetBuilder};
+use std::collections::HashMap;
+use std::sync::{Mutex, OnceLock, PoisonError};
+
+use super::error::Result;
+
+// ----------------------------------------------------------------------------
+// Structs
+// ----------------------------------------------------------------------------
+
+/// Cache of compiled glob sets, evicting the least recently used.
+#[derive(Default)]
+struct Cache {
+    /// Glob sets and the time of their last use, keyed by their patterns.
+    entries: HashMap<Vec<String>, (GlobSet, u64)>,
+    /// Logical clock, incremented on every access.
+    clock: u64,
+}
+
+// ----------------------------------------------------------------------------
+// Implementations
+// ----------------------------------------------------------------------------
+
+impl Cache {
+    /// Returns the glob set for the given patterns and marks it as used.
+    fn get(&mut self, patterns: &[String]) -> Option<GlobSet> {
+        self.clock += 1;
+        let (set, used) = self.entries.get_mut(patterns)?;
+        *used = self.clock;
+        Some(set.clone())
+    }
+
+    /// Inserts the glob set for the given patterns, evicting the least
+    /// recently used glob set if the cache is full.
+    fn insert(&mut self, patterns: Vec<String>, set: GlobSet) {
+        if self.entries.len() >= CAPACITY
+            && !self.entries.contains_key(&patterns)
+        {
+            let iter = self.entries.iter();
+            let lru = iter
+                .min_by_key(|(_, (_, used))| *used)
+                .map(|(patterns, _)| patterns.clone());
+            if let Some(patterns) = lru {
+                self.entries.remove(&patterns);
+            }
+        }
+        self.clock += 1;
+        self.entries.insert(patterns, (set, self.clock));
+    }
+}
+
+// ----------------------------------------------------------------------------
+// Constants
+// ----------------------------------------------------------------------------
+
+/// Maximum number of cached glob sets.
+const CAPACITY: usize = 64;
+
+// ----------------------------------------------------------------------------
+// Statics
+// ----------------------------------------------------------------------------
+
+/// Compiled glob sets, keyed by their patterns.
+static CACHE: OnceLock<Mutex<Cache>> = OnceLock::new();
+
+#[cfg(test)]
+thread_local! {
+    /// Number of compiled glob sets on the current thread.
+    static COMPILED: std::cell::Cell<usize> = const { std::cell::Cell::new(0) };
+}
+
+// ----------------------------------------------------------------------------
+// Functions
+// ----------------------------------------------------------------------------
+
+/// Returns the glob set for the given patterns, compiling it if necessary.
+///
+/// Glob sets are cached for the lifetime of the process, which is ideal for
+/// hot-reload scenarios, where matchers are rebuilt from identical selector
+/// sets repeatedly. The cache holds at most [`CAPACITY`] glob sets, evicting
+/// the least recently used, so it doesn't grow with the number of distinct
+/// pattern lists in use.
+pub fn get_or_compile(patterns: Vec<String>) -> Result<GlobSet> {
+    let cache = CACHE.get_or_init(Mutex::default);
+    if let Some(set) = lock(cache).get(&patterns) {
+        return Ok(set);
+    }
+
+    // Compile glob set outside of the lock, as this might take a while, and
+    // only insert it into the cache if it was compiled successfully
+    let mut builder = GlobSetBuilder::new();
+    for pattern in &patterns {
+        builder.add(super::builder::parse(Some(pattern))?);
+    }
+    let set = builder.build()?;
+    #[cfg(test)]
+    COMPILED.with(|compiled| compiled.set(compiled.get() + 1));
+
+    // Insert glob set into cache and return it
+    lock(cache).insert(patterns, set.clone());
+    Ok(set)
+}
+
+/// Locks the cache, recovering from poisoning, as the cache can't be left in
+/// an inconsistent state by a panicking thread.
+fn lock<T>(mutex: &Mutex<T>) -> std::sync::MutexGuard<'_, T> {
+    mutex.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+// ----------------------------------------------------------------------------
+// Tests
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+
+    mod cache {
+        use globset::GlobSet;
+
+        use crate::id::matcher::cache::{Cache, CAPACITY};
+
+        #[test]
+        fn evicts_least_recently_used() {
+            let mut cache = Cache::default();
+            for n in 0..CAPACITY {
+                cache.insert(vec![n.to_string()], GlobSet::empty());
+            }
+
+            // Use first glob set, so the second is evicted on insertion
+            assert!(cache.get(&["0".into()]).is_some());
+            cache.insert(vec![CAPACITY.to_string()], GlobSet::empty());
+            assert_eq!(cache.entries.len(), CAPACITY);
+            assert!(cache.get(&["0".into()]).is_some());
+            assert!(cache.get(&["1".into()]).is_none());
+        }
+    }
+
+    mod get_or_compile {
+        use std::cell::Cell;
+
+        use crate::id::matcher::cache::COMPILED;
+        use crate::id::matcher::Result;
+        use crate::{Id, Matcher};
+
+        #[test]
+        fn reuses_compiled_glob_sets() -> Result {
+            let selectors = ["zrs::cache:::**/*.md:", "zrs::cache:::**/*.rs:"];
+            let a = Matcher::builder().with_many(selectors)?.build()?;
+            let compiled = COMPILED.with(Cell::get);
+            let b = Matcher::builder().with_many(selectors)?.build()?;
+            assert_eq!(COMPILED.with(Cell::get), compiled);
+
+            // Ensure both matchers produce the same results
+            let id: Id = "zri:file:cache::docs:index.md:".parse()?;
+            assert_eq!(a.matches(&id)?, b.matches(&id)?);
+            Ok(())
+        }
+    }
+}
@@ -52,6 +52,14 @@ pub enum Error {
     /// Invalid prefix.
     #[error("invalid prefix")]
     Prefix,
+
+    /// Unknown selector at index.
+    #[error("unknown selector at index {0}")]
+    Unknown(usize),
+
+    /// Invalid selector at index.
+    #[error("invalid selector at index {0}: {1}")]
+    Selector(usize, #[source] Box<Error>),
 }
 
 // ----------------------------------------------------------------------------
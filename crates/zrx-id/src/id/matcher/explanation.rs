@@ -0,0 +1,144 @@
+// Copyright (c) Zensical LLC <https://zensical.org>
+
+// SPDX-License-Identifier: MIT
+// Third-party contributions licensed under CLA
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+
+// ----------------------------------------------------------------------------
+
+//! Matcher explanation.
+
+// ----------------------------------------------------------------------------
+// Structs
+// ----------------------------------------------------------------------------
+
+/// Matcher explanation.
+///
+/// Explanations are returned by [`Matcher::explain`][], and report for each
+/// component of a selector, whether it matched the corresponding component of
+/// an identifier, together with the pattern and value that were compared. This
+/// allows to find out why an identifier didn't match a selector.
+///
+/// [`Matcher::explain`]: crate::id::matcher::Matcher::explain
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MatchExplanation {
+    /// Comparisons of components.
+    components: [ComponentMatch; 6],
+}
+
+/// Comparison of a component.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ComponentMatch {
+    /// Component name.
+    name: &'static str,
+    /// Pattern of the selector, if any.
+    pattern: Option<String>,
+    /// Value of the identifier, if any.
+    value: Option<String>,
+    /// Whether the component matched.
+    matched: bool,
+}
+
+// ----------------------------------------------------------------------------
+// Implementations
+// ----------------------------------------------------------------------------
+
+impl MatchExplanation {
+    /// Creates a match explanation.
+    pub(crate) fn new(components: [ComponentMatch; 6]) -> Self {
+        Self { components }
+    }
+
+    /// Returns the first component that didn't match, if any.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::error::Error;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use zrx_id::{Id, Matcher};
+    ///
+    /// // Create matcher from string
+    /// let matcher: Matcher = "zrs::::docs:**/*.md:".parse()?;
+    ///
+    /// // Create identifier and explain match
+    /// let id: Id = "zri:file:::docs:index.rs:".parse()?;
+    /// let explanation = matcher.explain(&id, 0)?;
+    /// assert_eq!(explanation.mismatch().unwrap().name(), "location");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn mismatch(&self) -> Option<&ComponentMatch> {
+        self.components.iter().find(|component| !component.matched)
+    }
+}
+
+#[allow(clippy::must_use_candidate)]
+impl MatchExplanation {
+    /// Returns whether all components matched.
+    #[inline]
+    pub fn is_match(&self) -> bool {
+        self.components.iter().all(|component| component.matched)
+    }
+
+    /// Returns the comparisons of components, in order of components.
+    #[inline]
+    pub fn components(&self) -> &[ComponentMatch] {
+        &self.components
+    }
+}
+
+impl ComponentMatch {
+    /// Creates a comparison of a component.
+    pub(crate) fn new(
+        name: &'static str, pattern: Option<String>, value: Option<String>,
+        matched: bool,
+    ) -> Self {
+        Self { name, pattern, value, matched }
+    }
+}
+
+#[allow(clippy::must_use_candidate)]
+impl ComponentMatch {
+    /// Returns the component name.
+    #[inline]
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Returns the pattern of the selector, or [`None`] for wildcards.
+    #[inline]
+    pub fn pattern(&self) -> Option<&str> {
+        self.pattern.as_deref()
+    }
+
+    /// Returns the value of the identifier, if any.
+    #[inline]
+    pub fn value(&self) -> Option<&str> {
+        self.value.as_deref()
+    }
+
+    /// Returns whether the component matched.
+    #[inline]
+    pub fn is_match(&self) -> bool {
+        self.matched
+    }
+}
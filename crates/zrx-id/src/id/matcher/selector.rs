@@ -305,6 +305,26 @@ impl Hash for Selector {
 
 impl fmt::Display for Selector {
     /// Formats the selector for display.
+    ///
+    /// The selector is formatted as its structured string representation, so
+    /// the output is the exact inverse of [`Selector::from_str`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::error::Error;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use zrx_id::Selector;
+    ///
+    /// // Create selector from string
+    /// let selector: Selector = "zrs:::::**/*.md:".parse()?;
+    ///
+    /// // Format selector and parse it again
+    /// let string = selector.to_string();
+    /// assert_eq!(string.parse::<Selector>()?, selector);
+    /// # Ok(())
+    /// # }
+    /// ```
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         self.format.fmt(f)
     }
@@ -323,3 +343,65 @@ impl fmt::Debug for Selector {
             .finish()
     }
 }
+
+// ----------------------------------------------------------------------------
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Selector {
+    /// Serializes the selector as its string representation.
+    fn serialize<S>(
+        &self, serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Selector {
+    /// Deserializes the selector from its string representation.
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let value = <Cow<'de, str>>::deserialize(deserializer)?;
+        value.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+// ----------------------------------------------------------------------------
+// Tests
+// ----------------------------------------------------------------------------
+
+#[cfg(all(test, feature = "serde"))]
+mod tests {
+
+    mod serde {
+        use crate::id::matcher::selector::Selector;
+
+        #[test]
+        fn round_trips_through_string() {
+            for value in [
+                "zrs::::::",
+                "zrs:::::**/*.md:",
+                "zrs:file:docs:en:blog:**/index.md:intro",
+                "zrs:git::main:::",
+            ] {
+                let selector: Selector = value.parse().unwrap();
+                let json = serde_json::to_string(&selector).unwrap();
+                assert_eq!(json, format!("\"{value}\""));
+                let parsed: Selector = serde_json::from_str(&json).unwrap();
+                assert_eq!(parsed, selector);
+                assert_eq!(parsed.to_string(), value);
+            }
+        }
+
+        #[test]
+        fn rejects_invalid_string() {
+            let result = serde_json::from_str::<Selector>("\"zri::::::\"");
+            assert!(result.is_err());
+        }
+    }
+}
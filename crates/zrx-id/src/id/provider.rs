@@ -0,0 +1,168 @@
+// Copyright (c) Zensical LLC <https://zensical.org>
+
+// SPDX-License-Identifier: MIT
+// Third-party contributions licensed under CLA
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+
+// ----------------------------------------------------------------------------
+
+//! Provider.
+
+use std::borrow::Cow;
+use std::fmt;
+
+// ----------------------------------------------------------------------------
+// Enums
+// ----------------------------------------------------------------------------
+
+/// Provider of an identifier.
+///
+/// Providers are stored as strings in the `provider` component of an [`Id`][],
+/// which makes it easy to introduce typos when constructing identifiers or
+/// matching on them. This enum models the common providers as variants, and
+/// falls back to [`Provider::Other`] for all providers that are not known.
+///
+/// Since providers can be converted into a [`Cow`], they can be passed to the
+/// [`Builder::with_provider`][] and [`Builder::set_provider`][] methods.
+///
+/// [`Builder::set_provider`]: crate::id::Builder::set_provider
+/// [`Builder::with_provider`]: crate::id::Builder::with_provider
+/// [`Id`]: crate::id::Id
+///
+/// # Examples
+///
+/// ```
+/// # use std::error::Error;
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// use zrx_id::{Id, Provider};
+///
+/// // Create identifier builder with known provider
+/// let mut builder = Id::builder().with_provider(Provider::Git);
+/// builder.set_context("docs");
+/// builder.set_location("index.md");
+///
+/// // Create identifier from builder and obtain provider
+/// let id = builder.build()?;
+/// assert_eq!(id.to_provider(), Provider::Git);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Provider {
+    /// File system.
+    File,
+    /// Git repository.
+    Git,
+    /// HTTP resource.
+    Http,
+    /// Unknown provider.
+    Other(String),
+}
+
+// ----------------------------------------------------------------------------
+// Implementations
+// ----------------------------------------------------------------------------
+
+impl Provider {
+    /// Returns the string representation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zrx_id::Provider;
+    ///
+    /// // Obtain string representation
+    /// assert_eq!(Provider::File.as_str(), "file");
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        match self {
+            Provider::File => "file",
+            Provider::Git => "git",
+            Provider::Http => "http",
+            Provider::Other(value) => value,
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------
+// Trait implementations
+// ----------------------------------------------------------------------------
+
+impl AsRef<str> for Provider {
+    /// Returns the string representation.
+    #[inline]
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+impl From<&str> for Provider {
+    /// Creates a provider from a string.
+    ///
+    /// Known providers are mapped to their corresponding variants, while all
+    /// other values are retained as part of [`Provider::Other`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zrx_id::Provider;
+    ///
+    /// // Create provider from string
+    /// assert_eq!(Provider::from("file"), Provider::File);
+    /// assert_eq!(
+    ///     Provider::from("s3"),
+    ///     Provider::Other(String::from("s3")),
+    /// );
+    /// ```
+    fn from(value: &str) -> Self {
+        match value {
+            "file" => Provider::File,
+            "git" => Provider::Git,
+            "http" => Provider::Http,
+            _ => Provider::Other(value.to_string()),
+        }
+    }
+}
+
+impl From<Provider> for Cow<'_, str> {
+    /// Creates a string from a provider.
+    #[inline]
+    fn from(provider: Provider) -> Self {
+        match provider {
+            Provider::File => Cow::Borrowed("file"),
+            Provider::Git => Cow::Borrowed("git"),
+            Provider::Http => Cow::Borrowed("http"),
+            Provider::Other(value) => Cow::Owned(value),
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+impl fmt::Display for Provider {
+    /// Formats the provider for display.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
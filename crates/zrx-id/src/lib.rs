@@ -30,4 +30,6 @@ mod id;
 pub use id::format;
 pub use id::matcher::{self, Matcher, Selector, ToSelector};
 pub use id::uri;
-pub use id::{Builder, Error, Id, Result, ToId};
+pub use id::{
+    Builder, ComponentMask, Error, Id, IdRef, Provider, Result, ToId,
+};
@@ -71,6 +71,8 @@ where
     timers: Timers<I>,
     /// Total items processed.
     total: usize,
+    /// Whether completion is pending.
+    completing: bool,
 }
 
 // ----------------------------------------------------------------------------
@@ -117,6 +119,7 @@ where
             tasks: Tasks::new(executor),
             timers: Timers::new(),
             total: 0,
+            completing: false,
         }
     }
 
@@ -175,6 +178,41 @@ where
         Tick::new(Some(Instant::now() + timeout)).run(self)
     }
 
+    /// Recomputes the given changed actions and their descendants.
+    ///
+    /// This method re-executes the actions with the given indices for every
+    /// item that reached them, and propagates their outputs to all descendant
+    /// actions, following the topological order of the action graph. Actions
+    /// receive the same inputs as during their last execution, as the outputs
+    /// of their dependencies are retained. Actions that don't depend on any of
+    /// the changed actions are not executed again. Use this method when the
+    /// state of an action changed, e.g., due to a change in configuration.
+    ///
+    /// Recomputing requires outputs to be retained, which must be enabled with
+    /// [`Scheduler::set_retain`] before items are submitted. Otherwise, this
+    /// method does nothing, as no identifiers are known. Changed sources are
+    /// replaced with their dependents, as sources are never executed.
+    ///
+    /// Note that work is only scheduled, so ticks must be run as usual.
+    #[inline]
+    pub fn recompute<T>(&mut self, changed: T)
+    where
+        T: IntoIterator<Item = usize>,
+    {
+        self.executor.recompute(changed);
+    }
+
+    /// Sets whether outputs are retained for recomputation.
+    ///
+    /// When enabled, the latest output of every action with dependents is
+    /// retained for every identifier, so [`Scheduler::recompute`] can restore
+    /// the inputs of actions. As memory grows with the number of items, this
+    /// is disabled by default, and disabling it drops all retained outputs.
+    #[inline]
+    pub fn set_retain(&mut self, value: bool) {
+        self.executor.set_retain(value);
+    }
+
     /// Handles the given message.
     ///
     /// This method processes the given message received from a session, either
@@ -186,10 +224,28 @@ where
             }
             Message::Drop(id) => {
                 self.sessions.remove(id);
+                self.completing = self.sessions.is_empty();
             }
         }
     }
 
+    /// Handles completion of all sessions.
+    ///
+    /// This method notifies the executor about completion once all sessions
+    /// were dropped and all items, tasks and timers have been processed, so
+    /// interested actions can flush their state exactly once.
+    fn handle_complete(&mut self) {
+        if self.completing
+            && self.executor.is_empty()
+            && self.connector.is_empty()
+            && self.tasks.is_empty()
+            && self.timers.is_empty()
+        {
+            self.completing = false;
+            self.executor.complete();
+        }
+    }
+
     /// Handles the given output.
     ///
     /// This method processes the output of a task, timer, or other effect, and
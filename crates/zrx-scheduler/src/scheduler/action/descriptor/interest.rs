@@ -44,4 +44,11 @@ pub enum Interest {
     /// that create barriers or other synchronization points, as they need to
     /// be aware of all items that are currently in the system.
     Submit,
+
+    /// Completion.
+    ///
+    /// This interest indicates that the action wants to be notified when all
+    /// sessions were dropped and all items were processed. This is typically
+    /// used for actions that need to run finalization, like flushing buffers.
+    Complete,
 }
@@ -48,4 +48,9 @@
 pub enum Signal<'a, I> {
     /// Identifier submission signal.
     Submit(&'a I),
+    /// Completion signal.
+    ///
+    /// This signal is sent once all sessions were dropped, and all submitted
+    /// items were processed, which means no further items are to be expected.
+    Complete,
 }
@@ -28,6 +28,7 @@
 use ahash::HashMap;
 use slab::Slab;
 use std::collections::VecDeque;
+use std::rc::Rc;
 use zrx_graph::Graph;
 
 use super::action::output::OutputItem;
@@ -63,6 +64,10 @@ where
     interests: HashMap<Interest, Vec<usize>>,
     /// Task concurrency.
     concurrency: Vec<usize>,
+    /// Latest outputs of actions with dependents, by identifier.
+    outputs: Vec<HashMap<I, Rc<dyn Value>>>,
+    /// Whether outputs are retained.
+    retain: bool,
 }
 
 #[derive(Debug)]
@@ -95,6 +100,21 @@ where
             frontiers: Slab::default(),
             interests,
             concurrency: vec![0; len],
+            outputs: vec![HashMap::default(); len],
+            retain: false,
+        }
+    }
+
+    /// Sets whether outputs are retained for recomputation.
+    ///
+    /// Retaining outputs keeps the latest output of every action with
+    /// dependents for every identifier alive, which is necessary to restore
+    /// the inputs of actions when recomputing, but is disabled by default, as
+    /// memory grows with the number of items. Disabling it drops all outputs.
+    pub fn set_retain(&mut self, value: bool) {
+        self.retain = value;
+        if !value {
+            self.outputs.iter_mut().for_each(HashMap::clear);
         }
     }
 
@@ -107,7 +127,11 @@ where
         let mut frontier =
             Frontier::new(self.graph.topology(), initial.clone());
         let n = frontier.take().unwrap();
-        frontier.complete(Node { id: n, data: item.data }).unwrap();
+
+        // Retain output, so it can be restored when recomputing
+        let data = item.data.map(Rc::from);
+        self.do_retain(n, &item.id, data.as_ref());
+        frontier.complete(Node { id: n, data }).unwrap();
 
         // Insert new frontier
         let id = item.id;
@@ -128,6 +152,97 @@ where
         }
     }
 
+    /// Notifies interested parties about completion.
+    pub fn complete(&mut self) {
+        if let Some(indices) = self.interests.get(&Interest::Complete) {
+            for &node in indices {
+                let action = &mut self.graph[node];
+                let _ = action.execute(Input::Signal(Signal::Complete));
+            }
+        }
+    }
+
+    /// Recomputes the given changed nodes and their descendants.
+    ///
+    /// For each identifier of an item that reached the changed nodes, a new
+    /// frontier is seeded with the changed nodes, and the retained outputs of
+    /// all nodes that feed into the traversal are restored, so actions receive
+    /// the same inputs as before. Only the changed nodes and their descendants
+    /// are executed again, while all other nodes are left alone. Changed nodes
+    /// that are descendants of other changed nodes are dropped from the set of
+    /// initial nodes, as they're visited as part of the traversal.
+    ///
+    /// Sources are never executed, so changed sources are replaced with their
+    /// dependents, which receive the retained items submitted to the sources.
+    /// If retention is disabled, no identifiers are known, and thus nothing
+    /// is recomputed, which is why [`Executor::set_retain`] must be enabled.
+    pub fn recompute<T>(&mut self, changed: T)
+    where
+        T: IntoIterator<Item = usize>,
+    {
+        let topology = self.graph.topology();
+        let mut changed = changed
+            .into_iter()
+            .flat_map(|node| match topology.in_neighbors(node) {
+                [] => topology.out_neighbors(node).to_vec(),
+                _ => vec![node],
+            })
+            .collect::<Vec<_>>();
+        changed.sort_unstable();
+        changed.dedup();
+
+        // Only keep changed nodes that aren't reachable from any other changed
+        // node, or they would be visited twice during the traversal
+        let distance = topology.distance();
+        let initial = changed
+            .iter()
+            .copied()
+            .filter(|&node| {
+                let mut iter = changed.iter();
+                iter.all(|&n| n == node || distance[n][node] == u8::MAX)
+            })
+            .collect::<Vec<_>>();
+
+        // Determine all nodes outside of the traversal that feed into it, as
+        // their outputs must be restored for the traversal
+        let reachable =
+            |node: usize| initial.iter().any(|&n| distance[n][node] != u8::MAX);
+        let inputs = (0..self.graph.len())
+            .filter(|&node| {
+                !reachable(node)
+                    && topology
+                        .out_neighbors(node)
+                        .iter()
+                        .any(|&n| reachable(n))
+            })
+            .collect::<Vec<_>>();
+
+        // Collect the identifiers of all items with retained inputs
+        let mut ids = inputs
+            .iter()
+            .flat_map(|&node| self.outputs[node].keys().cloned())
+            .collect::<Vec<_>>();
+        ids.sort_unstable();
+        ids.dedup();
+
+        // Create a frontier for each identifier, restore the inputs, and add
+        // initial nodes to the queue, just like for newly submitted items
+        let Some(&node) = initial.first() else { return };
+        for id in ids {
+            let mut frontier =
+                Frontier::new(self.graph.topology(), initial.clone());
+            for &n in &inputs {
+                if let Some(data) = self.outputs[n].get(&id) {
+                    frontier.restore(Node { id: n, data: Rc::clone(data) });
+                }
+            }
+            let f = self
+                .frontiers
+                .insert(Entry { id, frontier: Some(frontier) });
+            self.do_take(Token { frontier: f, node });
+        }
+    }
+
     // Update the frontier with new items
     pub fn update(&mut self, token: Token, items: Vec<OutputItem<I>>) {
         let mut f = token.frontier;
@@ -247,9 +362,13 @@ where
         let f = token.frontier;
         let mut n = token.node;
 
-        // Obtain frontier and identifier
+        // Retain output, so it can be restored when recomputing
+        let data = data.map(Rc::from);
+        let id = self.frontiers[f].id.clone();
+        self.do_retain(n, &id, data.as_ref());
+
+        // Obtain frontier
         let entry = &mut self.frontiers[f];
-        let id = entry.id.clone();
 
         // Handle completion
         let mut to_add = Vec::new();
@@ -284,6 +403,23 @@ where
         }
     }
 
+    /// Retains the output of the given node for the given identifier.
+    ///
+    /// Outputs are only retained for nodes with dependents, since only those
+    /// need to be restored when recomputing. If there's no output, e.g., due
+    /// to a deletion, the retained output is removed.
+    fn do_retain(&mut self, node: usize, id: &I, data: Option<&Rc<dyn Value>>) {
+        if !self.retain || self.graph.topology().out_neighbors(node).is_empty()
+        {
+            return;
+        }
+        if let Some(data) = data {
+            self.outputs[node].insert(id.clone(), Rc::clone(data));
+        } else {
+            self.outputs[node].remove(id);
+        }
+    }
+
     /// Takes the next node from the frontier and adds it to the queue.
     fn do_take(&mut self, token: Token) {
         let f = token.frontier;
@@ -334,3 +470,192 @@ where
 
 /// Executor job.
 pub type Job<I> = (Token, Result<Outputs<I>>);
+
+// ----------------------------------------------------------------------------
+// Tests
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+
+    mod recompute {
+        use std::cell::{Cell, RefCell};
+        use std::rc::Rc;
+        use zrx_graph::Graph;
+
+        use crate::scheduler::action::{Action, Input, Output, Report, Result};
+        use crate::scheduler::effect::Item;
+        use crate::scheduler::executor::Executor;
+        use crate::scheduler::graph::Marker;
+
+        /// Creates an action that counts its executions.
+        fn counter(count: &Rc<Cell<usize>>) -> Box<dyn Action<&'static str>> {
+            let count = Rc::clone(count);
+            Box::new(move |_: Input<&'static str>| count.set(count.get() + 1))
+        }
+
+        /// Creates an action that scales its input by the given factor.
+        fn scale(factor: &Rc<Cell<i32>>) -> Box<dyn Action<&'static str>> {
+            let factor = Rc::clone(factor);
+            Box::new(move |input: Input<&'static str>| -> Result<_> {
+                let Input::Item(item) = input else {
+                    unreachable!()
+                };
+                let item = item.downcast::<&i32>()?;
+                let data = item.data * factor.get();
+                Ok(Report::new(Item::new(*item.id, Some(data))))
+            })
+        }
+
+        /// Creates an action that records its inputs.
+        fn record(
+            values: &Rc<RefCell<Vec<(&'static str, i32)>>>,
+        ) -> Box<dyn Action<&'static str>> {
+            let values = Rc::clone(values);
+            Box::new(move |input: Input<&'static str>| -> Result {
+                let Input::Item(item) = input else {
+                    unreachable!()
+                };
+                let item = item.downcast::<&i32>()?;
+                values.borrow_mut().push((*item.id, *item.data));
+                Ok(Report::new(()))
+            })
+        }
+
+        /// Runs the executor until no more jobs are available.
+        fn run(executor: &mut Executor<&'static str>) {
+            loop {
+                let jobs = executor.take();
+                if jobs.is_empty() {
+                    break;
+                }
+                for (token, res) in jobs {
+                    let items =
+                        res.unwrap().data.into_iter().filter_map(|output| {
+                            match output {
+                                Output::Item(item) => Some(item),
+                                _ => None,
+                            }
+                        });
+                    executor.update(token, items.collect());
+                }
+            }
+        }
+
+        #[test]
+        fn restores_inputs() {
+            let factor = Rc::new(Cell::new(2));
+            let values = Rc::new(RefCell::new(Vec::new()));
+
+            // Create a chain of a source, a scaling and a recording action
+            let mut builder = Graph::builder();
+            let a = builder.add_node(Box::new(Marker) as Box<dyn Action<_>>);
+            let b = builder.add_node(scale(&factor));
+            let c = builder.add_node(record(&values));
+            builder.add_edge(a, b, ()).unwrap();
+            builder.add_edge(b, c, ()).unwrap();
+
+            // Submit items to the source
+            let mut executor = Executor::new(builder.build());
+            executor.set_retain(true);
+            executor.submit(Item::new("x", Some(Box::new(1))), [a]);
+            executor.submit(Item::new("y", Some(Box::new(2))), [a]);
+            run(&mut executor);
+            assert_eq!(values.take(), [("x", 2), ("y", 4)]);
+
+            // Ensure recomputation receives the retained inputs
+            factor.set(10);
+            executor.recompute([b]);
+            run(&mut executor);
+            assert_eq!(values.take(), [("x", 10), ("y", 20)]);
+            assert!(executor.is_empty());
+
+            // Ensure deleted items are not recomputed
+            executor.submit(Item::new("x", None), [a]);
+            run(&mut executor);
+            executor.recompute([b]);
+            run(&mut executor);
+            assert_eq!(values.take(), [("y", 20)]);
+        }
+
+        #[test]
+        fn skips_independent_subgraph() {
+            let left = Rc::new(Cell::new(0));
+            let right = Rc::new(Cell::new(0));
+
+            // Create two independent subgraphs with a source and an action
+            let mut builder = Graph::builder();
+            let a = builder.add_node(Box::new(Marker) as Box<dyn Action<_>>);
+            let b = builder.add_node(counter(&left));
+            let c = builder.add_node(Box::new(Marker));
+            let d = builder.add_node(counter(&right));
+            builder.add_edge(a, b, ()).unwrap();
+            builder.add_edge(c, d, ()).unwrap();
+
+            // Submit items to both subgraphs
+            let mut executor = Executor::new(builder.build());
+            executor.set_retain(true);
+            executor.submit(Item::new("x", Some(Box::new(1))), [a]);
+            executor.submit(Item::new("y", Some(Box::new(2))), [c]);
+            run(&mut executor);
+            assert_eq!((left.get(), right.get()), (1, 1));
+
+            // Recompute left subgraph, which only received the first item
+            executor.recompute([b]);
+            run(&mut executor);
+            assert_eq!((left.get(), right.get()), (2, 1));
+            assert!(executor.is_empty());
+        }
+
+        #[test]
+        fn replaces_source_with_dependents() {
+            let factor = Rc::new(Cell::new(2));
+            let values = Rc::new(RefCell::new(Vec::new()));
+
+            // Create a chain of a source, a scaling and a recording action
+            let mut builder = Graph::builder();
+            let a = builder.add_node(Box::new(Marker) as Box<dyn Action<_>>);
+            let b = builder.add_node(scale(&factor));
+            let c = builder.add_node(record(&values));
+            builder.add_edge(a, b, ()).unwrap();
+            builder.add_edge(b, c, ()).unwrap();
+
+            // Submit items to the source
+            let mut executor = Executor::new(builder.build());
+            executor.set_retain(true);
+            executor.submit(Item::new("x", Some(Box::new(1))), [a]);
+            run(&mut executor);
+            assert_eq!(values.take(), [("x", 2)]);
+
+            // Ensure recomputing the source recomputes its dependents
+            factor.set(10);
+            executor.recompute([a]);
+            run(&mut executor);
+            assert_eq!(values.take(), [("x", 10)]);
+            assert!(executor.is_empty());
+        }
+
+        #[test]
+        fn skips_without_retention() {
+            let count = Rc::new(Cell::new(0));
+
+            // Create a chain of a source and an action
+            let mut builder = Graph::builder();
+            let a = builder.add_node(Box::new(Marker) as Box<dyn Action<_>>);
+            let b = builder.add_node(counter(&count));
+            builder.add_edge(a, b, ()).unwrap();
+
+            // Submit item to the source without retaining outputs
+            let mut executor = Executor::new(builder.build());
+            executor.submit(Item::new("x", Some(Box::new(1))), [a]);
+            run(&mut executor);
+            assert_eq!(count.get(), 1);
+
+            // Ensure nothing is recomputed, as no identifiers are known
+            executor.recompute([b]);
+            run(&mut executor);
+            assert_eq!(count.get(), 1);
+            assert!(executor.is_empty());
+        }
+    }
+}
@@ -25,6 +25,7 @@
 
 //! Frontier.
 
+use std::rc::Rc;
 use zrx_graph::{Topology, Traversal};
 
 use crate::scheduler::value::{Value, Values};
@@ -112,8 +113,8 @@ impl Frontier {
 
     /// Marks the given node as visited and stores its value, if any.
     pub fn complete(
-        &mut self, node: Node<Option<Box<dyn Value>>>,
-    ) -> Result<(), Option<Box<dyn Value>>> {
+        &mut self, node: Node<Option<Rc<dyn Value>>>,
+    ) -> Result<(), Option<Rc<dyn Value>>> {
         match self.traversal.complete(node.id) {
             Ok(()) => {}
             Err(_) => return Err(node.data),
@@ -148,6 +149,19 @@ impl Frontier {
         Ok(())
     }
 
+    /// Restores the value of a node outside of the traversal.
+    ///
+    /// This is necessary when the traversal doesn't start at the sources of
+    /// the graph, as the values of all nodes that precede it must be made
+    /// available to their dependents. Values of nodes without dependents in
+    /// the traversal are ignored, since they would never be removed.
+    pub fn restore(&mut self, node: Node<Rc<dyn Value>>) {
+        if self.dependents[node.id] != 0 && self.storage.get(node.id).is_none()
+        {
+            self.storage.append(node.id, node.data);
+        }
+    }
+
     /// Selects the node with the given identifier and returns its values.
     pub fn select(&mut self, id: usize) -> Node<Values<'_>> {
         let incoming = self.traversal.topology().incoming();
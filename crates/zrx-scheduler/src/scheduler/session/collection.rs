@@ -122,3 +122,12 @@ impl Sessions {
         self.items.remove(&id);
     }
 }
+
+#[allow(clippy::must_use_candidate)]
+impl Sessions {
+    /// Returns whether there are any active sessions.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+}
@@ -87,6 +87,9 @@ where
         } else {
             self.waiting(scheduler);
         }
+
+        // Notify interested parties, once all items were processed
+        scheduler.handle_complete();
     }
 
     /// Processes tasks.
@@ -31,8 +31,9 @@ mod store;
 pub use store::behavior;
 pub use store::decorator;
 pub use store::order::{self, Comparator};
+pub use store::util;
 pub use store::{
-    Key, Store, StoreFromIterator, StoreIntoIterator, StoreIterable,
-    StoreIterableMut, StoreKeys, StoreMut, StoreMutRef, StoreRange,
-    StoreValues,
+    DynStore, Key, Store, StoreCapacity, StoreFromIterator, StoreIntoIterator,
+    StoreIterable, StoreIterableMut, StoreKeys, StoreMut, StoreMutRef,
+    StoreRange, StoreValues,
 };
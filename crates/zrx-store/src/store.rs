@@ -26,7 +26,7 @@
 //! Store abstractions and implementations with specific characteristics.
 
 use std::borrow::Borrow;
-use std::ops::RangeBounds;
+use std::ops::{Bound, RangeBounds};
 
 pub mod behavior;
 mod collection;
@@ -58,6 +58,7 @@ pub use key::Key;
 /// - [`StoreKeys`]: Immutable store that is iterable over its keys
 /// - [`StoreValues`]: Immutable store that is iterable over its values
 /// - [`StoreRange`]: Immutable store that is iterable over a range
+/// - [`StoreCapacity`]: Mutable store with capacity controls
 ///
 /// This trait is implemented for [`HashMap`][] and [`BTreeMap`][], as well as
 /// for the third-party [`litemap`] crate, the latter of which is available when
@@ -149,6 +150,19 @@ where
             .is_some()
     }
 
+    /// Inserts the value identified by the key if it changed, returning the
+    /// prior value.
+    ///
+    /// The outer [`Option`] denotes whether the value changed, and the inner
+    /// [`Option`] contains the value that was displaced, if any.
+    fn insert_if_changed_get(&mut self, key: &K, value: &V) -> Option<Option<V>>
+    where
+        V: Clone + Eq,
+    {
+        (self.get(key) != Some(value))
+            .then(|| self.insert(key.clone(), value.clone()))
+    }
+
     /// Removes the value identified by the key.
     fn remove<Q>(&mut self, key: &Q) -> Option<V>
     where
@@ -157,6 +171,43 @@ where
 
     /// Clears the store, removing all items.
     fn clear(&mut self);
+
+    /// Merges the items of the given iterator into the store, resolving
+    /// conflicts with the given function.
+    ///
+    /// Items with keys that are not present in the store are inserted as is.
+    /// If a key is present, the resolver is called with the key, the existing
+    /// and the incoming value, and its result replaces the existing value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use zrx_store::{Store, StoreMut};
+    ///
+    /// // Create store and initial state
+    /// let mut store = HashMap::from([("a", 1), ("b", 2)]);
+    ///
+    /// // Merge other store, summing values on conflict
+    /// let other = HashMap::from([("b", 3), ("c", 4)]);
+    /// store.merge_with(other, |_, existing, incoming| existing + incoming);
+    /// assert_eq!(store.get(&"a"), Some(&1));
+    /// assert_eq!(store.get(&"b"), Some(&5));
+    /// assert_eq!(store.get(&"c"), Some(&4));
+    /// ```
+    fn merge_with<T, F>(&mut self, other: T, mut resolver: F)
+    where
+        T: StoreIntoIterator<K, V>,
+        F: FnMut(&K, V, V) -> V,
+    {
+        for (key, value) in other {
+            let value = match self.remove(&key) {
+                Some(existing) => resolver(&key, existing, value),
+                None => value,
+            };
+            self.insert(key, value);
+        }
+    }
 }
 
 /// Mutable store that can return mutable references.
@@ -347,6 +398,165 @@ where
         R: RangeBounds<K>,
         K: 'a,
         V: 'a;
+
+    /// Returns an iterator over at most `limit` items at or after the key.
+    ///
+    /// In contrast to [`StoreRange::range`], which selects a range of keys,
+    /// this method selects a number of items, which allows for pagination.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::BTreeMap;
+    /// use zrx_store::{StoreMut, StoreRange};
+    ///
+    /// // Create store and initial state
+    /// let mut store = BTreeMap::new();
+    /// store.insert("a", 1);
+    /// store.insert("b", 2);
+    /// store.insert("c", 3);
+    /// store.insert("d", 4);
+    /// store.insert("e", 5);
+    ///
+    /// // Obtain pages of two items
+    /// let page = store.range_from_limited(&"a", 2).collect::<Vec<_>>();
+    /// assert_eq!(page, [(&"a", &1), (&"b", &2)]);
+    /// let page = store.range_from_limited(&"c", 2).collect::<Vec<_>>();
+    /// assert_eq!(page, [(&"c", &3), (&"d", &4)]);
+    /// let page = store.range_from_limited(&"e", 2).collect::<Vec<_>>();
+    /// assert_eq!(page, [(&"e", &5)]);
+    /// ```
+    #[inline]
+    fn range_from_limited<'a>(
+        &'a self, start: &K, limit: usize,
+    ) -> impl Iterator<Item = (&'a K, &'a V)>
+    where
+        K: 'a,
+        V: 'a,
+    {
+        let range = (Bound::Included(start.clone()), Bound::Unbounded);
+        self.range(range).take(limit)
+    }
+}
+
+/// Mutable store with capacity controls.
+///
+/// This trait extends [`StoreMut`], adding the possibility to reserve memory
+/// ahead of bursts, and to release it after many removals, which matters for
+/// operators that hold large stores during long runs. Stores that allocate on
+/// demand, like [`BTreeMap`][], report their length as their capacity.
+///
+/// [`BTreeMap`]: std::collections::BTreeMap
+///
+/// # Examples
+///
+/// ```
+/// use std::collections::HashMap;
+/// use zrx_store::{StoreCapacity, StoreMut};
+///
+/// // Create store and initial state
+/// let mut store = HashMap::new();
+/// for n in 0..1024 {
+///     store.insert(n, n);
+/// }
+///
+/// // Remove most items and release memory
+/// let capacity = store.capacity();
+/// for n in 0..1000 {
+///     store.remove(&n);
+/// }
+/// store.shrink_to_fit();
+/// assert!(store.capacity() < capacity);
+/// ```
+pub trait StoreCapacity<K, V>: StoreMut<K, V>
+where
+    K: Key,
+{
+    /// Returns the number of items the store can hold without reallocating.
+    fn capacity(&self) -> usize;
+
+    /// Reserves capacity for at least the given number of additional items.
+    fn reserve(&mut self, additional: usize);
+
+    /// Shrinks the capacity of the store as much as possible.
+    fn shrink_to_fit(&mut self);
+}
+
+// ----------------------------------------------------------------------------
+
+/// Object-safe immutable store.
+///
+/// The store traits use generic methods and return `impl Iterator`, which is
+/// efficient, but makes them unusable as trait objects. This trait is a thin
+/// facade over [`StoreIterable`] with concrete key types and boxed iterators,
+/// so store backends can be selected at runtime behind a `Box<dyn DynStore>`.
+/// It's implemented for all stores implementing [`StoreIterable`].
+///
+/// # Examples
+///
+/// ```
+/// use std::collections::{BTreeMap, HashMap};
+/// use zrx_store::DynStore;
+///
+/// // Create stores with different backends
+/// let stores: Vec<Box<dyn DynStore<&str, i32>>> = vec![
+///     Box::new(HashMap::from([("a", 1), ("b", 2)])),
+///     Box::new(BTreeMap::from([("a", 1), ("b", 2)])),
+/// ];
+///
+/// // Obtain values from stores
+/// for store in &stores {
+///     assert_eq!(store.get(&"a"), Some(&1));
+///     assert!(store.contains_key(&"b"));
+///     assert_eq!(store.len(), 2);
+///
+///     // Obtain sorted items from store
+///     let mut items = store.iter().collect::<Vec<_>>();
+///     items.sort();
+///     assert_eq!(items, [(&"a", &1), (&"b", &2)]);
+/// }
+/// ```
+pub trait DynStore<K, V> {
+    /// Returns a reference to the value identified by the key.
+    fn get(&self, key: &K) -> Option<&V>;
+
+    /// Returns whether the store contains the key.
+    fn contains_key(&self, key: &K) -> bool;
+
+    /// Returns the number of items in the store.
+    fn len(&self) -> usize;
+
+    /// Returns whether the store is empty.
+    #[inline]
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Creates an iterator over the store.
+    fn iter<'a>(&'a self) -> Box<dyn Iterator<Item = (&'a K, &'a V)> + 'a>
+    where
+        K: 'a,
+        V: 'a;
+
+    /// Creates an iterator over the keys of the store.
+    #[inline]
+    fn keys<'a>(&'a self) -> Box<dyn Iterator<Item = &'a K> + 'a>
+    where
+        K: 'a,
+        V: 'a,
+    {
+        Box::new(self.iter().map(|(key, _)| key))
+    }
+
+    /// Creates an iterator over the values of the store.
+    #[inline]
+    fn values<'a>(&'a self) -> Box<dyn Iterator<Item = &'a V> + 'a>
+    where
+        K: 'a,
+        V: 'a,
+    {
+        Box::new(self.iter().map(|(_, value)| value))
+    }
 }
 
 // ----------------------------------------------------------------------------
@@ -370,3 +580,35 @@ where
 impl<K, V, T> StoreIntoIterator<K, V> for T
 where
     T: IntoIterator<Item = (K, V)> {}
+
+// ----------------------------------------------------------------------------
+
+impl<K, V, S> DynStore<K, V> for S
+where
+    K: Key,
+    S: StoreIterable<K, V>,
+{
+    #[inline]
+    fn get(&self, key: &K) -> Option<&V> {
+        Store::get(self, key)
+    }
+
+    #[inline]
+    fn contains_key(&self, key: &K) -> bool {
+        Store::contains_key(self, key)
+    }
+
+    #[inline]
+    fn len(&self) -> usize {
+        Store::len(self)
+    }
+
+    #[inline]
+    fn iter<'a>(&'a self) -> Box<dyn Iterator<Item = (&'a K, &'a V)> + 'a>
+    where
+        K: 'a,
+        V: 'a,
+    {
+        Box::new(StoreIterable::iter(self))
+    }
+}
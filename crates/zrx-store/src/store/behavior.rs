@@ -26,5 +26,7 @@
 //! Store behaviors.
 
 mod delta;
+mod log;
 
 pub use delta::StoreDelta;
+pub use log::Log;
@@ -0,0 +1,351 @@
+// Copyright (c) Zensical LLC <https://zensical.org>
+
+// SPDX-License-Identifier: MIT
+// Third-party contributions licensed under CLA
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+
+// ----------------------------------------------------------------------------
+
+//! Append-only log behavior.
+
+use ahash::HashMap;
+use std::borrow::Borrow;
+use std::fmt;
+use std::marker::PhantomData;
+
+use crate::store::{Key, Store, StoreIterable, StoreMut, StoreMutRef};
+
+// ----------------------------------------------------------------------------
+// Structs
+// ----------------------------------------------------------------------------
+
+/// Append-only log behavior.
+///
+/// This is a thin wrapper around [`StoreMut`] with vectors as values, where
+/// values accumulate into a log per key instead of being overwritten, which is
+/// useful for event sourcing, where the full history of changes is relevant.
+/// Inserting appends to the log, and [`Store::get`] returns the history of the
+/// key in insertion order, while [`Log::latest`] returns the last value.
+///
+/// # Examples
+///
+/// ```
+/// use zrx_store::behavior::Log;
+/// use zrx_store::Store;
+///
+/// // Create log and append values
+/// let mut log = Log::default();
+/// log.insert("key", 1);
+/// log.insert("key", 2);
+/// log.insert("key", 3);
+///
+/// // Obtain history and latest value
+/// assert_eq!(log.get(&"key"), Some(&vec![1, 2, 3]));
+/// assert_eq!(log.latest(&"key"), Some(&3));
+/// ```
+pub struct Log<K, V, S = HashMap<K, Vec<V>>>
+where
+    K: Key,
+    S: StoreMut<K, Vec<V>>,
+{
+    /// Underlying store.
+    store: S,
+    /// Type marker.
+    marker: PhantomData<(K, V)>,
+}
+
+// ----------------------------------------------------------------------------
+// Implementations
+// ----------------------------------------------------------------------------
+
+impl<K, V, S> Log<K, V, S>
+where
+    K: Key,
+    S: StoreMut<K, Vec<V>> + StoreMutRef<K, Vec<V>>,
+{
+    /// Creates an append-only log.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use zrx_store::behavior::Log;
+    ///
+    /// // Create log and append value
+    /// let mut log = Log::<_, _, HashMap<_, _>>::new();
+    /// log.insert("key", 42);
+    /// ```
+    #[must_use]
+    pub fn new() -> Self
+    where
+        S: Default,
+    {
+        Self::from(S::default())
+    }
+
+    /// Appends the value to the log of the key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zrx_store::behavior::Log;
+    /// use zrx_store::Store;
+    ///
+    /// // Create log and append values
+    /// let mut log = Log::default();
+    /// log.insert("key", 1);
+    /// log.insert("key", 2);
+    ///
+    /// // Obtain history
+    /// assert_eq!(log.get(&"key"), Some(&vec![1, 2]));
+    /// ```
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    pub fn insert(&mut self, key: K, value: V) {
+        self.store.get_or_insert_default(&key).push(value);
+    }
+
+    /// Returns a reference to the latest value of the key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zrx_store::behavior::Log;
+    ///
+    /// // Create log and append values
+    /// let mut log = Log::default();
+    /// log.insert("key", 1);
+    /// log.insert("key", 2);
+    ///
+    /// // Obtain latest value
+    /// assert_eq!(log.latest(&"key"), Some(&2));
+    /// assert_eq!(log.latest(&"other"), None);
+    /// ```
+    #[inline]
+    pub fn latest<Q>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Key,
+    {
+        self.store.get(key).and_then(|values| values.last())
+    }
+
+    /// Removes the log of the key, returning its history.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zrx_store::behavior::Log;
+    ///
+    /// // Create log and append values
+    /// let mut log = Log::default();
+    /// log.insert("key", 1);
+    /// log.insert("key", 2);
+    ///
+    /// // Remove history
+    /// assert_eq!(log.remove(&"key"), Some(vec![1, 2]));
+    /// assert_eq!(log.latest(&"key"), None);
+    /// ```
+    #[inline]
+    pub fn remove<Q>(&mut self, key: &Q) -> Option<Vec<V>>
+    where
+        K: Borrow<Q>,
+        Q: Key,
+    {
+        self.store.remove(key)
+    }
+
+    /// Removes all logs.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.store.clear();
+    }
+
+    /// Returns the underlying store.
+    #[inline]
+    #[must_use]
+    pub fn into_inner(self) -> S {
+        self.store
+    }
+}
+
+// ----------------------------------------------------------------------------
+// Trait implementations
+// ----------------------------------------------------------------------------
+
+impl<K, V, S> Store<K, Vec<V>> for Log<K, V, S>
+where
+    K: Key,
+    S: StoreMut<K, Vec<V>>,
+{
+    /// Returns a reference to the history of the key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zrx_store::behavior::Log;
+    /// use zrx_store::Store;
+    ///
+    /// // Create log and append value
+    /// let mut log = Log::default();
+    /// log.insert("key", 42);
+    ///
+    /// // Obtain history
+    /// let values = log.get(&"key");
+    /// assert_eq!(values, Some(&vec![42]));
+    /// ```
+    #[inline]
+    fn get<Q>(&self, key: &Q) -> Option<&Vec<V>>
+    where
+        K: Borrow<Q>,
+        Q: Key,
+    {
+        self.store.get(key)
+    }
+
+    /// Returns whether the log contains the key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zrx_store::behavior::Log;
+    /// use zrx_store::Store;
+    ///
+    /// // Create log and append value
+    /// let mut log = Log::default();
+    /// log.insert("key", 42);
+    ///
+    /// // Ensure presence of key
+    /// let check = log.contains_key(&"key");
+    /// assert_eq!(check, true);
+    /// ```
+    #[inline]
+    fn contains_key<Q>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Key,
+    {
+        self.store.contains_key(key)
+    }
+
+    /// Returns the number of keys in the log.
+    #[inline]
+    fn len(&self) -> usize {
+        self.store.len()
+    }
+}
+
+impl<K, V, S> StoreIterable<K, Vec<V>> for Log<K, V, S>
+where
+    K: Key,
+    S: StoreMut<K, Vec<V>> + StoreIterable<K, Vec<V>>,
+{
+    /// Creates an iterator over the log.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zrx_store::behavior::Log;
+    /// use zrx_store::StoreIterable;
+    ///
+    /// // Create log and append value
+    /// let mut log = Log::default();
+    /// log.insert("key", 42);
+    ///
+    /// // Create iterator over the log
+    /// for (key, values) in log.iter() {
+    ///     println!("{key}: {values:?}");
+    /// }
+    /// ```
+    #[inline]
+    fn iter<'a>(&'a self) -> impl Iterator<Item = (&'a K, &'a Vec<V>)>
+    where
+        K: 'a,
+        V: 'a,
+    {
+        self.store.iter()
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+impl<K, V, S> From<S> for Log<K, V, S>
+where
+    K: Key,
+    S: StoreMut<K, Vec<V>>,
+{
+    /// Creates an append-only log from a store.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use zrx_store::behavior::Log;
+    ///
+    /// // Create log from store
+    /// let log: Log<_, _, _> = HashMap::from([("key", vec![42])]).into();
+    /// assert_eq!(log.latest(&"key"), Some(&42));
+    /// ```
+    #[inline]
+    fn from(store: S) -> Self {
+        Self { store, marker: PhantomData }
+    }
+}
+
+#[allow(clippy::implicit_hasher)]
+impl<K, V> Default for Log<K, V, HashMap<K, Vec<V>>>
+where
+    K: Key,
+{
+    /// Creates an append-only log with [`HashMap::default`] as a store.
+    ///
+    /// Note that this method does not allow to customize the [`BuildHasher`][],
+    /// but uses [`ahash`] by default, which is the fastest known hasher.
+    ///
+    /// [`BuildHasher`]: std::hash::BuildHasher
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zrx_store::behavior::Log;
+    ///
+    /// // Create log and append value
+    /// let mut log = Log::default();
+    /// log.insert("key", 42);
+    /// ```
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+impl<K, V, S> fmt::Debug for Log<K, V, S>
+where
+    K: Key + fmt::Debug,
+    V: fmt::Debug,
+    S: StoreMut<K, Vec<V>> + fmt::Debug,
+{
+    /// Formats the append-only log for debugging.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Log").field("store", &self.store).finish()
+    }
+}
@@ -28,3 +28,4 @@
 mod core;
 #[cfg(feature = "litemap")]
 mod litemap;
+mod reference;
@@ -30,10 +30,10 @@ use std::collections::{BTreeMap, HashMap};
 use std::hash::BuildHasher;
 use std::ops::RangeBounds;
 
-use crate::store::util::update_if_changed;
+use crate::store::util::{replace_if_changed, update_if_changed};
 use crate::store::{
-    Key, Store, StoreIterable, StoreIterableMut, StoreKeys, StoreMut,
-    StoreMutRef, StoreRange, StoreValues,
+    Key, Store, StoreCapacity, StoreIterable, StoreIterableMut, StoreKeys,
+    StoreMut, StoreMutRef, StoreRange, StoreValues,
 };
 
 // ----------------------------------------------------------------------------
@@ -160,6 +160,43 @@ where
             })
     }
 
+    /// Inserts the value identified by the key if it changed, returning the
+    /// prior value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use zrx_store::StoreMut;
+    ///
+    /// // Create store
+    /// let mut store = HashMap::new();
+    ///
+    /// // Insert value
+    /// let prior = store.insert_if_changed_get(&"key", &42);
+    /// assert_eq!(prior, Some(None));
+    ///
+    /// // Ignore unchanged value
+    /// let prior = store.insert_if_changed_get(&"key", &42);
+    /// assert_eq!(prior, None);
+    ///
+    /// // Update value
+    /// let prior = store.insert_if_changed_get(&"key", &84);
+    /// assert_eq!(prior, Some(Some(42)));
+    /// ```
+    #[inline]
+    fn insert_if_changed_get(&mut self, key: &K, value: &V) -> Option<Option<V>>
+    where
+        V: Clone + Eq,
+    {
+        HashMap::get_mut(self, key)
+            .map(|check| replace_if_changed(check, value).map(Some))
+            .unwrap_or_else(|| {
+                HashMap::insert(self, key.clone(), value.clone());
+                Some(None)
+            })
+    }
+
     /// Removes the value identified by the key.
     ///
     /// # Examples
@@ -388,6 +425,42 @@ where
     }
 }
 
+impl<K, V, S> StoreCapacity<K, V> for HashMap<K, V, S>
+where
+    K: Key,
+    S: BuildHasher,
+{
+    /// Returns the number of items the store can hold without reallocating.
+    #[inline]
+    fn capacity(&self) -> usize {
+        HashMap::capacity(self)
+    }
+
+    /// Reserves capacity for at least the given number of additional items.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use zrx_store::StoreCapacity;
+    ///
+    /// // Create store and reserve capacity
+    /// let mut store = HashMap::<&str, i32>::new();
+    /// store.reserve(100);
+    /// assert!(store.capacity() >= 100);
+    /// ```
+    #[inline]
+    fn reserve(&mut self, additional: usize) {
+        HashMap::reserve(self, additional);
+    }
+
+    /// Shrinks the capacity of the store as much as possible.
+    #[inline]
+    fn shrink_to_fit(&mut self) {
+        HashMap::shrink_to_fit(self);
+    }
+}
+
 // ----------------------------------------------------------------------------
 
 impl<K, V> Store<K, V> for BTreeMap<K, V>
@@ -508,6 +581,43 @@ where
             })
     }
 
+    /// Inserts the value identified by the key if it changed, returning the
+    /// prior value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::BTreeMap;
+    /// use zrx_store::StoreMut;
+    ///
+    /// // Create store
+    /// let mut store = BTreeMap::new();
+    ///
+    /// // Insert value
+    /// let prior = store.insert_if_changed_get(&"key", &42);
+    /// assert_eq!(prior, Some(None));
+    ///
+    /// // Ignore unchanged value
+    /// let prior = store.insert_if_changed_get(&"key", &42);
+    /// assert_eq!(prior, None);
+    ///
+    /// // Update value
+    /// let prior = store.insert_if_changed_get(&"key", &84);
+    /// assert_eq!(prior, Some(Some(42)));
+    /// ```
+    #[inline]
+    fn insert_if_changed_get(&mut self, key: &K, value: &V) -> Option<Option<V>>
+    where
+        V: Clone + Eq,
+    {
+        BTreeMap::get_mut(self, key)
+            .map(|check| replace_if_changed(check, value).map(Some))
+            .unwrap_or_else(|| {
+                BTreeMap::insert(self, key.clone(), value.clone());
+                Some(None)
+            })
+    }
+
     /// Removes the value identified by the key.
     ///
     /// # Examples
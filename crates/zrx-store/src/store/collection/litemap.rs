@@ -28,7 +28,7 @@
 use litemap::{store, LiteMap};
 use std::borrow::Borrow;
 
-use crate::store::util::update_if_changed;
+use crate::store::util::{replace_if_changed, update_if_changed};
 use crate::store::{
     Key, Store, StoreIterable, StoreIterableMut, StoreKeys, StoreMut,
     StoreMutRef, StoreValues,
@@ -158,6 +158,43 @@ where
             })
     }
 
+    /// Inserts the value identified by the key if it changed, returning the
+    /// prior value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use litemap::LiteMap;
+    /// use zrx_store::StoreMut;
+    ///
+    /// // Create store
+    /// let mut store = LiteMap::new_vec();
+    ///
+    /// // Insert value
+    /// let prior = store.insert_if_changed_get(&"key", &42);
+    /// assert_eq!(prior, Some(None));
+    ///
+    /// // Ignore unchanged value
+    /// let prior = store.insert_if_changed_get(&"key", &42);
+    /// assert_eq!(prior, None);
+    ///
+    /// // Update value
+    /// let prior = store.insert_if_changed_get(&"key", &84);
+    /// assert_eq!(prior, Some(Some(42)));
+    /// ```
+    #[inline]
+    fn insert_if_changed_get(&mut self, key: &K, value: &V) -> Option<Option<V>>
+    where
+        V: Clone + Eq,
+    {
+        LiteMap::get_mut(self, key)
+            .map(|check| replace_if_changed(check, value).map(Some))
+            .unwrap_or_else(|| {
+                LiteMap::insert(self, key.clone(), value.clone());
+                Some(None)
+            })
+    }
+
     /// Removes the value identified by the key.
     ///
     /// # Examples
@@ -0,0 +1,247 @@
+// Copyright (c) Zensical LLC <https://zensical.org>
+
+// SPDX-License-Identifier: MIT
+// Third-party contributions licensed under CLA
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+
+// ----------------------------------------------------------------------------
+
+//! Store implementations for references.
+
+use std::borrow::Borrow;
+
+use crate::store::{
+    Key, Store, StoreIterable, StoreKeys, StoreMut, StoreMutRef, StoreValues,
+};
+
+// ----------------------------------------------------------------------------
+// Trait implementations
+// ----------------------------------------------------------------------------
+
+impl<K, V, S> Store<K, V> for &S
+where
+    K: Key,
+    S: Store<K, V> + ?Sized,
+{
+    /// Returns a reference to the value identified by the key.
+    #[inline]
+    fn get<Q>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Key,
+    {
+        (**self).get(key)
+    }
+
+    /// Returns whether the store contains the key.
+    #[inline]
+    fn contains_key<Q>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Key,
+    {
+        (**self).contains_key(key)
+    }
+
+    /// Returns the number of items in the store.
+    #[inline]
+    fn len(&self) -> usize {
+        (**self).len()
+    }
+}
+
+impl<K, V, S> StoreIterable<K, V> for &S
+where
+    K: Key,
+    S: StoreIterable<K, V> + ?Sized,
+{
+    /// Creates an iterator over the store.
+    #[inline]
+    fn iter<'a>(&'a self) -> impl Iterator<Item = (&'a K, &'a V)>
+    where
+        K: 'a,
+        V: 'a,
+    {
+        (**self).iter()
+    }
+}
+
+impl<K, V, S> StoreKeys<K, V> for &S
+where
+    K: Key,
+    S: StoreKeys<K, V> + ?Sized,
+{
+    /// Creates a key iterator over the store.
+    #[inline]
+    fn keys<'a>(&'a self) -> impl Iterator<Item = &'a K>
+    where
+        K: 'a,
+    {
+        (**self).keys()
+    }
+}
+
+impl<K, V, S> StoreValues<K, V> for &S
+where
+    K: Key,
+    S: StoreValues<K, V> + ?Sized,
+{
+    /// Creates a value iterator over the store.
+    #[inline]
+    fn values<'a>(&'a self) -> impl Iterator<Item = &'a V>
+    where
+        V: 'a,
+    {
+        (**self).values()
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+impl<K, V, S> Store<K, V> for &mut S
+where
+    K: Key,
+    S: Store<K, V> + ?Sized,
+{
+    /// Returns a reference to the value identified by the key.
+    #[inline]
+    fn get<Q>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Key,
+    {
+        (**self).get(key)
+    }
+
+    /// Returns whether the store contains the key.
+    #[inline]
+    fn contains_key<Q>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Key,
+    {
+        (**self).contains_key(key)
+    }
+
+    /// Returns the number of items in the store.
+    #[inline]
+    fn len(&self) -> usize {
+        (**self).len()
+    }
+}
+
+impl<K, V, S> StoreMut<K, V> for &mut S
+where
+    K: Key,
+    S: StoreMut<K, V> + ?Sized,
+{
+    /// Inserts the value identified by the key.
+    #[inline]
+    fn insert(&mut self, key: K, value: V) -> Option<V> {
+        (**self).insert(key, value)
+    }
+
+    /// Removes the value identified by the key.
+    #[inline]
+    fn remove<Q>(&mut self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: Key,
+    {
+        (**self).remove(key)
+    }
+
+    /// Clears the store, removing all items.
+    #[inline]
+    fn clear(&mut self) {
+        (**self).clear();
+    }
+}
+
+impl<K, V, S> StoreMutRef<K, V> for &mut S
+where
+    K: Key,
+    S: StoreMutRef<K, V> + ?Sized,
+{
+    /// Returns a mutable reference to the value identified by the key.
+    #[inline]
+    fn get_mut<Q>(&mut self, key: &Q) -> Option<&mut V>
+    where
+        K: Borrow<Q>,
+        Q: Key,
+    {
+        (**self).get_mut(key)
+    }
+
+    /// Returns a mutable reference to the value or creates the default.
+    #[inline]
+    fn get_or_insert_default(&mut self, key: &K) -> &mut V
+    where
+        V: Default,
+    {
+        (**self).get_or_insert_default(key)
+    }
+}
+
+impl<K, V, S> StoreIterable<K, V> for &mut S
+where
+    K: Key,
+    S: StoreIterable<K, V> + ?Sized,
+{
+    /// Creates an iterator over the store.
+    #[inline]
+    fn iter<'a>(&'a self) -> impl Iterator<Item = (&'a K, &'a V)>
+    where
+        K: 'a,
+        V: 'a,
+    {
+        (**self).iter()
+    }
+}
+
+impl<K, V, S> StoreKeys<K, V> for &mut S
+where
+    K: Key,
+    S: StoreKeys<K, V> + ?Sized,
+{
+    /// Creates a key iterator over the store.
+    #[inline]
+    fn keys<'a>(&'a self) -> impl Iterator<Item = &'a K>
+    where
+        K: 'a,
+    {
+        (**self).keys()
+    }
+}
+
+impl<K, V, S> StoreValues<K, V> for &mut S
+where
+    K: Key,
+    S: StoreValues<K, V> + ?Sized,
+{
+    /// Creates a value iterator over the store.
+    #[inline]
+    fn values<'a>(&'a self) -> impl Iterator<Item = &'a V>
+    where
+        V: 'a,
+    {
+        (**self).values()
+    }
+}
@@ -26,7 +26,13 @@
 //! Store decorators.
 
 mod indexed;
+mod namespaced;
 mod ordered;
+mod read_only;
+mod transactional;
 
 pub use indexed::Indexed;
+pub use namespaced::Namespaced;
 pub use ordered::Ordered;
+pub use read_only::ReadOnly;
+pub use transactional::Transactional;
@@ -0,0 +1,429 @@
+// Copyright (c) Zensical LLC <https://zensical.org>
+
+// SPDX-License-Identifier: MIT
+// Third-party contributions licensed under CLA
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+
+// ----------------------------------------------------------------------------
+
+//! Namespacing decorator, multiplexing stores onto a backing store.
+
+use std::borrow::Borrow;
+use std::marker::PhantomData;
+
+use crate::store::{
+    Key, Store, StoreIterable, StoreKeys, StoreMut, StoreMutRef, StoreValues,
+};
+
+// ----------------------------------------------------------------------------
+// Structs
+// ----------------------------------------------------------------------------
+
+/// Namespacing decorator, multiplexing stores onto a backing store.
+///
+/// This decorator allows to share a single backing store among several logical
+/// stores, e.g., for multiple operators, by qualifying each key with the given
+/// namespace. The backing store maps each namespace to the store holding the
+/// items of that namespace, so each key is effectively prefixed with the
+/// namespace, while lookups don't need to construct qualified keys, which
+/// would not be possible when borrowed keys are used.
+///
+/// Reads and iteration only ever see the items of the current namespace, and
+/// writes only ever affect them, so namespaces can't interfere with one another.
+/// Since stores are implemented for references, the backing store can also be
+/// borrowed instead of owned, so any number of namespaces can be read at once,
+/// while writes happen through a mutable borrow of the backing store.
+///
+/// # Examples
+///
+/// ```
+/// use std::collections::HashMap;
+/// use zrx_store::decorator::Namespaced;
+/// use zrx_store::{Store, StoreMut};
+///
+/// // Create backing store and write to two namespaces
+/// let mut store = HashMap::<_, HashMap<_, _>>::new();
+/// Namespaced::new(&mut store, "a").insert("key", 42);
+/// Namespaced::new(&mut store, "b").insert("key", 84);
+///
+/// // Ensure namespaces don't see each other's keys
+/// let a = Namespaced::new(&store, "a");
+/// let b = Namespaced::new(&store, "b");
+/// assert_eq!(a.get(&"key"), Some(&42));
+/// assert_eq!(b.get(&"key"), Some(&84));
+/// assert_eq!(a.len(), 1);
+/// ```
+#[derive(Clone, Debug)]
+pub struct Namespaced<N, T, S> {
+    /// Underlying store.
+    store: S,
+    /// Namespace.
+    namespace: N,
+    /// Type marker.
+    marker: PhantomData<T>,
+}
+
+// ----------------------------------------------------------------------------
+// Implementations
+// ----------------------------------------------------------------------------
+
+impl<N, T, S> Namespaced<N, T, S>
+where
+    N: Key,
+    S: Store<N, T>,
+{
+    /// Creates a namespacing decorator over a store.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use zrx_store::decorator::Namespaced;
+    ///
+    /// // Create namespaced store
+    /// let store = HashMap::<_, HashMap<&str, i32>>::new();
+    /// let store = Namespaced::new(store, "a");
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn new(store: S, namespace: N) -> Self {
+        Self {
+            store,
+            namespace,
+            marker: PhantomData,
+        }
+    }
+
+    /// Returns the underlying store, including all namespaces.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use zrx_store::decorator::Namespaced;
+    /// use zrx_store::{Store, StoreMut};
+    ///
+    /// // Create namespaced store and unwrap it
+    /// let store = HashMap::<_, HashMap<_, _>>::new();
+    /// let mut store = Namespaced::new(store, "a");
+    /// store.insert("key", 42);
+    /// let store = store.into_inner();
+    /// assert_eq!(store[&"a"].get(&"key"), Some(&42));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn into_inner(self) -> S {
+        self.store
+    }
+}
+
+#[allow(clippy::must_use_candidate)]
+impl<N, T, S> Namespaced<N, T, S> {
+    /// Returns a reference to the namespace.
+    #[inline]
+    pub fn namespace(&self) -> &N {
+        &self.namespace
+    }
+}
+
+// ----------------------------------------------------------------------------
+// Trait implementations
+// ----------------------------------------------------------------------------
+
+impl<K, V, N, T, S> Store<K, V> for Namespaced<N, T, S>
+where
+    K: Key,
+    N: Key,
+    T: Store<K, V>,
+    S: Store<N, T>,
+{
+    /// Returns a reference to the value identified by the key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use zrx_store::decorator::Namespaced;
+    /// use zrx_store::{Store, StoreMut};
+    ///
+    /// // Create store and initial state
+    /// let store = HashMap::<_, HashMap<_, _>>::new();
+    /// let mut store = Namespaced::new(store, "a");
+    /// store.insert("key", 42);
+    ///
+    /// // Obtain reference to value
+    /// let value = store.get(&"key");
+    /// assert_eq!(value, Some(&42));
+    /// ```
+    #[inline]
+    fn get<Q>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Key,
+    {
+        self.store
+            .get(&self.namespace)
+            .and_then(|store| store.get(key))
+    }
+
+    /// Returns whether the store contains the key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use zrx_store::decorator::Namespaced;
+    /// use zrx_store::{Store, StoreMut};
+    ///
+    /// // Create store and initial state
+    /// let store = HashMap::<_, HashMap<_, _>>::new();
+    /// let mut store = Namespaced::new(store, "a");
+    /// store.insert("key", 42);
+    ///
+    /// // Ensure presence of key
+    /// let check = store.contains_key(&"key");
+    /// assert_eq!(check, true);
+    /// ```
+    #[inline]
+    fn contains_key<Q>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Key,
+    {
+        self.store
+            .get(&self.namespace)
+            .is_some_and(|store| store.contains_key(key))
+    }
+
+    /// Returns the number of items in the namespace.
+    #[inline]
+    fn len(&self) -> usize {
+        self.store.get(&self.namespace).map_or(0, Store::len)
+    }
+}
+
+impl<K, V, N, T, S> StoreMut<K, V> for Namespaced<N, T, S>
+where
+    K: Key,
+    N: Key,
+    T: StoreMut<K, V> + Default,
+    S: StoreMut<N, T> + StoreMutRef<N, T>,
+{
+    /// Inserts the value identified by the key.
+    ///
+    /// If the namespace doesn't exist in the backing store yet, it's created.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use zrx_store::decorator::Namespaced;
+    /// use zrx_store::StoreMut;
+    ///
+    /// // Create store and initial state
+    /// let store = HashMap::<_, HashMap<_, _>>::new();
+    /// let mut store = Namespaced::new(store, "a");
+    /// store.insert("key", 42);
+    ///
+    /// // Insert value
+    /// let value = store.insert("key", 84);
+    /// assert_eq!(value, Some(42));
+    /// ```
+    #[inline]
+    fn insert(&mut self, key: K, value: V) -> Option<V> {
+        self.store
+            .get_or_insert_default(&self.namespace)
+            .insert(key, value)
+    }
+
+    /// Removes the value identified by the key.
+    ///
+    /// If the namespace is empty afterwards, it's removed from the backing
+    /// store, so no empty stores are left behind.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use zrx_store::decorator::Namespaced;
+    /// use zrx_store::StoreMut;
+    ///
+    /// // Create store and initial state
+    /// let store = HashMap::<_, HashMap<_, _>>::new();
+    /// let mut store = Namespaced::new(store, "a");
+    /// store.insert("key", 42);
+    ///
+    /// // Remove value
+    /// let value = store.remove(&"key");
+    /// assert_eq!(value, Some(42));
+    /// ```
+    #[inline]
+    fn remove<Q>(&mut self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: Key,
+    {
+        let store = self.store.get_mut(&self.namespace)?;
+        let value = store.remove(key);
+        if store.is_empty() {
+            self.store.remove(&self.namespace);
+        }
+        value
+    }
+
+    /// Clears the namespace, removing it from the backing store.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use zrx_store::decorator::Namespaced;
+    /// use zrx_store::{Store, StoreMut};
+    ///
+    /// // Create store and initial state
+    /// let mut store = HashMap::<_, HashMap<_, _>>::new();
+    /// let mut namespace = Namespaced::new(&mut store, "a");
+    /// namespace.insert("key", 42);
+    ///
+    /// // Clear namespace
+    /// namespace.clear();
+    /// assert!(namespace.is_empty());
+    /// assert!(store.is_empty());
+    /// ```
+    #[inline]
+    fn clear(&mut self) {
+        self.store.remove(&self.namespace);
+    }
+}
+
+impl<K, V, N, T, S> StoreIterable<K, V> for Namespaced<N, T, S>
+where
+    K: Key,
+    N: Key,
+    T: StoreIterable<K, V>,
+    S: Store<N, T>,
+{
+    /// Creates an iterator over the namespace.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use zrx_store::decorator::Namespaced;
+    /// use zrx_store::{StoreIterable, StoreMut};
+    ///
+    /// // Create store and initial state
+    /// let store = HashMap::<_, HashMap<_, _>>::new();
+    /// let mut store = Namespaced::new(store, "a");
+    /// store.insert("key", 42);
+    ///
+    /// // Create iterator over the store
+    /// for (key, value) in store.iter() {
+    ///     println!("{key}: {value}");
+    /// }
+    /// ```
+    #[inline]
+    fn iter<'a>(&'a self) -> impl Iterator<Item = (&'a K, &'a V)>
+    where
+        K: 'a,
+        V: 'a,
+    {
+        self.store
+            .get(&self.namespace)
+            .into_iter()
+            .flat_map(StoreIterable::iter)
+    }
+}
+
+impl<K, V, N, T, S> StoreKeys<K, V> for Namespaced<N, T, S>
+where
+    K: Key,
+    N: Key,
+    T: StoreKeys<K, V>,
+    S: Store<N, T>,
+{
+    /// Creates a key iterator over the namespace.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use zrx_store::decorator::Namespaced;
+    /// use zrx_store::{StoreKeys, StoreMut};
+    ///
+    /// // Create store and initial state
+    /// let store = HashMap::<_, HashMap<_, _>>::new();
+    /// let mut store = Namespaced::new(store, "a");
+    /// store.insert("key", 42);
+    ///
+    /// // Create iterator over the store
+    /// for key in store.keys() {
+    ///     println!("{key}");
+    /// }
+    /// ```
+    #[inline]
+    fn keys<'a>(&'a self) -> impl Iterator<Item = &'a K>
+    where
+        K: 'a,
+    {
+        self.store
+            .get(&self.namespace)
+            .into_iter()
+            .flat_map(StoreKeys::keys)
+    }
+}
+
+impl<K, V, N, T, S> StoreValues<K, V> for Namespaced<N, T, S>
+where
+    K: Key,
+    N: Key,
+    T: StoreValues<K, V>,
+    S: Store<N, T>,
+{
+    /// Creates a value iterator over the namespace.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use zrx_store::decorator::Namespaced;
+    /// use zrx_store::{StoreMut, StoreValues};
+    ///
+    /// // Create store and initial state
+    /// let store = HashMap::<_, HashMap<_, _>>::new();
+    /// let mut store = Namespaced::new(store, "a");
+    /// store.insert("key", 42);
+    ///
+    /// // Create iterator over the store
+    /// for value in store.values() {
+    ///     println!("{value}");
+    /// }
+    /// ```
+    #[inline]
+    fn values<'a>(&'a self) -> impl Iterator<Item = &'a V>
+    where
+        V: 'a,
+    {
+        self.store
+            .get(&self.namespace)
+            .into_iter()
+            .flat_map(StoreValues::values)
+    }
+}
@@ -0,0 +1,346 @@
+// Copyright (c) Zensical LLC <https://zensical.org>
+
+// SPDX-License-Identifier: MIT
+// Third-party contributions licensed under CLA
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+
+// ----------------------------------------------------------------------------
+
+//! Read-only decorator, restricting a store to immutable access.
+
+use std::borrow::Borrow;
+use std::ops::RangeBounds;
+
+use crate::store::{
+    Key, Store, StoreIterable, StoreKeys, StoreRange, StoreValues,
+};
+
+// ----------------------------------------------------------------------------
+// Structs
+// ----------------------------------------------------------------------------
+
+/// Read-only decorator, restricting a store to immutable access.
+///
+/// This is a thin wrapper around [`Store`], which only implements the traits
+/// for immutable access, i.e., [`Store`], [`StoreIterable`], [`StoreKeys`],
+/// [`StoreValues`] and [`StoreRange`], so the store can be handed to code that
+/// must not be able to mutate it. This is enforced at the type level.
+///
+/// # Examples
+///
+/// ```
+/// use std::collections::HashMap;
+/// use zrx_store::decorator::ReadOnly;
+/// use zrx_store::{Store, StoreMut};
+///
+/// // Create store and initial state
+/// let mut store = HashMap::new();
+/// store.insert("key", 42);
+///
+/// // Create read-only store and obtain value
+/// let store = ReadOnly::new(store);
+/// assert_eq!(store.get(&"key"), Some(&42));
+/// ```
+///
+/// Mutation is not possible:
+///
+/// ```compile_fail
+/// use std::collections::HashMap;
+/// use zrx_store::decorator::ReadOnly;
+/// use zrx_store::StoreMut;
+///
+/// // Create read-only store and try to insert value
+/// let mut store = ReadOnly::new(HashMap::<&str, i32>::new());
+/// store.insert("key", 42);
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct ReadOnly<S> {
+    /// Underlying store.
+    store: S,
+}
+
+// ----------------------------------------------------------------------------
+// Implementations
+// ----------------------------------------------------------------------------
+
+impl<S> ReadOnly<S> {
+    /// Creates a read-only decorator over a store.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use zrx_store::decorator::ReadOnly;
+    ///
+    /// // Create read-only store
+    /// let store = ReadOnly::new(HashMap::<&str, i32>::new());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn new(store: S) -> Self {
+        Self { store }
+    }
+
+    /// Returns the underlying store, giving up the read-only guarantee.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use zrx_store::decorator::ReadOnly;
+    /// use zrx_store::StoreMut;
+    ///
+    /// // Create read-only store and unwrap it
+    /// let store = ReadOnly::new(HashMap::new());
+    /// let mut store = store.into_inner();
+    /// store.insert("key", 42);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn into_inner(self) -> S {
+        self.store
+    }
+}
+
+// ----------------------------------------------------------------------------
+// Trait implementations
+// ----------------------------------------------------------------------------
+
+impl<K, V, S> Store<K, V> for ReadOnly<S>
+where
+    K: Key,
+    S: Store<K, V>,
+{
+    /// Returns a reference to the value identified by the key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use zrx_store::decorator::ReadOnly;
+    /// use zrx_store::{Store, StoreMut};
+    ///
+    /// // Create store and initial state
+    /// let mut store = HashMap::new();
+    /// store.insert("key", 42);
+    ///
+    /// // Obtain reference to value
+    /// let store = ReadOnly::new(store);
+    /// let value = store.get(&"key");
+    /// assert_eq!(value, Some(&42));
+    /// ```
+    #[inline]
+    fn get<Q>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Key,
+    {
+        self.store.get(key)
+    }
+
+    /// Returns whether the store contains the key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use zrx_store::decorator::ReadOnly;
+    /// use zrx_store::{Store, StoreMut};
+    ///
+    /// // Create store and initial state
+    /// let mut store = HashMap::new();
+    /// store.insert("key", 42);
+    ///
+    /// // Ensure presence of key
+    /// let store = ReadOnly::new(store);
+    /// let check = store.contains_key(&"key");
+    /// assert_eq!(check, true);
+    /// ```
+    #[inline]
+    fn contains_key<Q>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Key,
+    {
+        self.store.contains_key(key)
+    }
+
+    /// Returns the number of items in the store.
+    #[inline]
+    fn len(&self) -> usize {
+        self.store.len()
+    }
+}
+
+impl<K, V, S> StoreIterable<K, V> for ReadOnly<S>
+where
+    K: Key,
+    S: StoreIterable<K, V>,
+{
+    /// Creates an iterator over the store.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use zrx_store::decorator::ReadOnly;
+    /// use zrx_store::{StoreIterable, StoreMut};
+    ///
+    /// // Create store and initial state
+    /// let mut store = HashMap::new();
+    /// store.insert("key", 42);
+    ///
+    /// // Create iterator over the store
+    /// let store = ReadOnly::new(store);
+    /// for (key, value) in store.iter() {
+    ///     println!("{key}: {value}");
+    /// }
+    /// ```
+    #[inline]
+    fn iter<'a>(&'a self) -> impl Iterator<Item = (&'a K, &'a V)>
+    where
+        K: 'a,
+        V: 'a,
+    {
+        self.store.iter()
+    }
+}
+
+impl<K, V, S> StoreKeys<K, V> for ReadOnly<S>
+where
+    K: Key,
+    S: StoreKeys<K, V>,
+{
+    /// Creates a key iterator over the store.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use zrx_store::decorator::ReadOnly;
+    /// use zrx_store::{StoreKeys, StoreMut};
+    ///
+    /// // Create store and initial state
+    /// let mut store = HashMap::new();
+    /// store.insert("key", 42);
+    ///
+    /// // Create iterator over the store
+    /// let store = ReadOnly::new(store);
+    /// for key in store.keys() {
+    ///     println!("{key}");
+    /// }
+    /// ```
+    #[inline]
+    fn keys<'a>(&'a self) -> impl Iterator<Item = &'a K>
+    where
+        K: 'a,
+    {
+        self.store.keys()
+    }
+}
+
+impl<K, V, S> StoreValues<K, V> for ReadOnly<S>
+where
+    K: Key,
+    S: StoreValues<K, V>,
+{
+    /// Creates a value iterator over the store.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use zrx_store::decorator::ReadOnly;
+    /// use zrx_store::{StoreMut, StoreValues};
+    ///
+    /// // Create store and initial state
+    /// let mut store = HashMap::new();
+    /// store.insert("key", 42);
+    ///
+    /// // Create iterator over the store
+    /// let store = ReadOnly::new(store);
+    /// for value in store.values() {
+    ///     println!("{value}");
+    /// }
+    /// ```
+    #[inline]
+    fn values<'a>(&'a self) -> impl Iterator<Item = &'a V>
+    where
+        V: 'a,
+    {
+        self.store.values()
+    }
+}
+
+impl<K, V, S> StoreRange<K, V> for ReadOnly<S>
+where
+    K: Key,
+    S: StoreRange<K, V>,
+{
+    /// Returns a range iterator over the store.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::BTreeMap;
+    /// use zrx_store::decorator::ReadOnly;
+    /// use zrx_store::{StoreMut, StoreRange};
+    ///
+    /// // Create store and initial state
+    /// let mut store = BTreeMap::new();
+    /// store.insert("a", 42);
+    /// store.insert("b", 84);
+    ///
+    /// // Create iterator over the store
+    /// let store = ReadOnly::new(store);
+    /// for (key, value) in store.range("b"..) {
+    ///     println!("{key}: {value}");
+    /// }
+    /// ```
+    #[inline]
+    fn range<'a, R>(&'a self, range: R) -> impl Iterator<Item = (&'a K, &'a V)>
+    where
+        R: RangeBounds<K>,
+        K: 'a,
+        V: 'a,
+    {
+        self.store.range(range)
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+impl<S> From<S> for ReadOnly<S> {
+    /// Creates a read-only decorator from a store.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use zrx_store::decorator::ReadOnly;
+    ///
+    /// // Create read-only store from store
+    /// let store: ReadOnly<_> = HashMap::<&str, i32>::new().into();
+    /// ```
+    #[inline]
+    fn from(store: S) -> Self {
+        Self { store }
+    }
+}
@@ -0,0 +1,603 @@
+// Copyright (c) Zensical LLC <https://zensical.org>
+
+// SPDX-License-Identifier: MIT
+// Third-party contributions licensed under CLA
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+
+// ----------------------------------------------------------------------------
+
+//! Transactional decorator, adding commit and rollback to a store.
+
+use ahash::HashMap;
+use std::borrow::Borrow;
+use std::collections::hash_map::Entry;
+use std::fmt;
+
+use crate::store::{
+    Key, Store, StoreIterable, StoreKeys, StoreMut, StoreValues,
+};
+
+// ----------------------------------------------------------------------------
+// Structs
+// ----------------------------------------------------------------------------
+
+/// Transactional decorator, adding commit and rollback to a store.
+///
+/// This decorator allows for speculative computation over a store, as all
+/// mutations after [`Transactional::begin`] are buffered in an overlay, and
+/// leave the underlying store untouched. Reads see the overlay first, and then
+/// the underlying store, so the transaction observes its own mutations. Then,
+/// [`Transactional::commit`] applies the overlay to the underlying store, and
+/// [`Transactional::rollback`] discards it, restoring the prior contents.
+///
+/// Outside of a transaction, all mutations are applied to the underlying store
+/// directly. Transactions can't be nested, so beginning a transaction while a
+/// transaction is active continues the active transaction.
+///
+/// __Warning__: removals of keys that are only present in the underlying store
+/// require to find the owned key in the underlying store by iterating over its
+/// keys, yielding a complexity of O(n), as the overlay must record a removal.
+/// The same holds for clearing the store, as all keys must be recorded.
+///
+/// # Examples
+///
+/// ```
+/// use zrx_store::decorator::Transactional;
+/// use zrx_store::{Store, StoreMut};
+///
+/// // Create store and initial state
+/// let mut store = Transactional::default();
+/// store.insert("a", 1);
+///
+/// // Begin transaction and mutate store
+/// store.begin();
+/// store.insert("a", 2);
+/// store.insert("b", 3);
+/// assert_eq!(store.get(&"a"), Some(&2));
+///
+/// // Roll back transaction
+/// store.rollback();
+/// assert_eq!(store.get(&"a"), Some(&1));
+/// assert_eq!(store.get(&"b"), None);
+/// ```
+pub struct Transactional<K, V, S = HashMap<K, V>>
+where
+    K: Key,
+    S: Store<K, V>,
+{
+    /// Underlying store.
+    store: S,
+    /// Overlay of buffered mutations, if a transaction is active.
+    overlay: Option<HashMap<K, Option<V>>>,
+}
+
+// ----------------------------------------------------------------------------
+// Implementations
+// ----------------------------------------------------------------------------
+
+impl<K, V, S> Transactional<K, V, S>
+where
+    K: Key,
+    S: Store<K, V>,
+{
+    /// Creates a transactional decorator over a store.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use zrx_store::decorator::Transactional;
+    ///
+    /// // Create transactional store
+    /// let store = Transactional::new(HashMap::<&str, i32>::new());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn new(store: S) -> Self {
+        Self { store, overlay: None }
+    }
+
+    /// Begins a transaction.
+    ///
+    /// If a transaction is already active, it's continued, since transactions
+    /// can't be nested, so all mutations end up in the same overlay.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zrx_store::decorator::Transactional;
+    /// use zrx_store::StoreMut;
+    ///
+    /// // Create store and begin transaction
+    /// let mut store = Transactional::default();
+    /// store.begin();
+    /// store.insert("key", 42);
+    /// # let _: &Transactional<_, i32> = &store;
+    /// assert!(store.is_active());
+    /// ```
+    #[inline]
+    pub fn begin(&mut self) {
+        self.overlay.get_or_insert_with(HashMap::default);
+    }
+
+    /// Commits the active transaction, applying the overlay to the store.
+    ///
+    /// If no transaction is active, this method does nothing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zrx_store::decorator::Transactional;
+    /// use zrx_store::{Store, StoreMut};
+    ///
+    /// // Create store and initial state
+    /// let mut store = Transactional::default();
+    /// store.insert("a", 1);
+    ///
+    /// // Begin transaction, mutate store and commit
+    /// store.begin();
+    /// store.remove(&"a");
+    /// store.insert("b", 2);
+    /// store.commit();
+    ///
+    /// // Obtain underlying store
+    /// let store = store.into_inner();
+    /// assert_eq!(store.get(&"a"), None);
+    /// assert_eq!(store.get(&"b"), Some(&2));
+    /// ```
+    pub fn commit(&mut self)
+    where
+        S: StoreMut<K, V>,
+    {
+        for (key, value) in self.overlay.take().into_iter().flatten() {
+            if let Some(value) = value {
+                self.store.insert(key, value);
+            } else {
+                self.store.remove(&key);
+            }
+        }
+    }
+
+    /// Rolls back the active transaction, discarding the overlay.
+    ///
+    /// If no transaction is active, this method does nothing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::BTreeMap;
+    /// use zrx_store::decorator::Transactional;
+    /// use zrx_store::{Store, StoreIterable, StoreMut};
+    ///
+    /// // Create store and initial state
+    /// let mut store = Transactional::default();
+    /// store.insert("a", 1);
+    /// store.insert("b", 2);
+    /// store.insert("c", 3);
+    ///
+    /// // Obtain contents before transaction
+    /// let before = store.iter()
+    ///     .map(|(&key, &value)| (key, value))
+    ///     .collect::<BTreeMap<_, _>>();
+    ///
+    /// // Begin transaction, mutate store and roll back
+    /// store.begin();
+    /// store.insert("a", 4);
+    /// store.remove(&"b");
+    /// store.insert("d", 5);
+    /// store.clear();
+    /// store.insert("e", 6);
+    /// store.rollback();
+    ///
+    /// // Ensure contents are restored exactly
+    /// let after = store.iter()
+    ///     .map(|(&key, &value)| (key, value))
+    ///     .collect::<BTreeMap<_, _>>();
+    /// assert_eq!(after, before);
+    /// assert_eq!(store.len(), 3);
+    /// ```
+    #[inline]
+    pub fn rollback(&mut self) {
+        self.overlay = None;
+    }
+
+    /// Returns the underlying store, discarding the active transaction.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zrx_store::decorator::Transactional;
+    /// use zrx_store::{Store, StoreMut};
+    ///
+    /// // Create store and initial state
+    /// let mut store = Transactional::default();
+    /// store.insert("key", 42);
+    ///
+    /// // Obtain underlying store
+    /// let store = store.into_inner();
+    /// assert_eq!(store.get(&"key"), Some(&42));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn into_inner(self) -> S {
+        self.store
+    }
+
+    /// Returns the owned key of the underlying store matching the given key.
+    fn find<Q>(&self, key: &Q) -> Option<K>
+    where
+        K: Borrow<Q>,
+        Q: Key,
+        S: StoreKeys<K, V>,
+    {
+        let mut iter = self.store.keys();
+        iter.find(|candidate| (*candidate).borrow() == key).cloned()
+    }
+}
+
+#[allow(clippy::must_use_candidate)]
+impl<K, V, S> Transactional<K, V, S>
+where
+    K: Key,
+    S: Store<K, V>,
+{
+    /// Returns whether a transaction is active.
+    #[inline]
+    pub fn is_active(&self) -> bool {
+        self.overlay.is_some()
+    }
+}
+
+// ----------------------------------------------------------------------------
+// Trait implementations
+// ----------------------------------------------------------------------------
+
+impl<K, V, S> Store<K, V> for Transactional<K, V, S>
+where
+    K: Key,
+    S: Store<K, V>,
+{
+    /// Returns a reference to the value identified by the key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zrx_store::decorator::Transactional;
+    /// use zrx_store::{Store, StoreMut};
+    ///
+    /// // Create store and initial state
+    /// let mut store = Transactional::default();
+    /// store.insert("key", 42);
+    ///
+    /// // Obtain reference to value
+    /// let value = store.get(&"key");
+    /// assert_eq!(value, Some(&42));
+    /// ```
+    #[inline]
+    fn get<Q>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Key,
+    {
+        match self.overlay.as_ref().and_then(|overlay| overlay.get(key)) {
+            Some(value) => value.as_ref(),
+            None => self.store.get(key),
+        }
+    }
+
+    /// Returns whether the store contains the key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zrx_store::decorator::Transactional;
+    /// use zrx_store::{Store, StoreMut};
+    ///
+    /// // Create store and initial state
+    /// let mut store = Transactional::default();
+    /// store.insert("key", 42);
+    ///
+    /// // Ensure presence of key
+    /// let check = store.contains_key(&"key");
+    /// assert_eq!(check, true);
+    /// ```
+    #[inline]
+    fn contains_key<Q>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Key,
+    {
+        self.get(key).is_some()
+    }
+
+    /// Returns the number of items in the store.
+    fn len(&self) -> usize {
+        let iter = self.overlay.iter().flatten();
+        iter.fold(self.store.len(), |len, (key, value)| {
+            match (value.is_some(), self.store.contains_key(key)) {
+                (true, false) => len + 1,
+                (false, true) => len - 1,
+                _ => len,
+            }
+        })
+    }
+}
+
+impl<K, V, S> StoreMut<K, V> for Transactional<K, V, S>
+where
+    K: Key,
+    V: Clone,
+    S: StoreMut<K, V> + StoreKeys<K, V>,
+{
+    /// Inserts the value identified by the key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zrx_store::decorator::Transactional;
+    /// use zrx_store::StoreMut;
+    ///
+    /// // Create store and initial state
+    /// let mut store = Transactional::default();
+    /// store.insert("key", 42);
+    ///
+    /// // Insert value in transaction
+    /// store.begin();
+    /// let value = store.insert("key", 84);
+    /// assert_eq!(value, Some(42));
+    /// ```
+    fn insert(&mut self, key: K, value: V) -> Option<V> {
+        let Some(overlay) = self.overlay.as_mut() else {
+            return self.store.insert(key, value);
+        };
+
+        // Buffer insertion in overlay, and return the prior value, which is
+        // either buffered in the overlay, or present in the underlying store
+        match overlay.entry(key) {
+            Entry::Occupied(mut entry) => entry.insert(Some(value)),
+            Entry::Vacant(entry) => {
+                let prior = self.store.get(entry.key()).cloned();
+                entry.insert(Some(value));
+                prior
+            }
+        }
+    }
+
+    /// Removes the value identified by the key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zrx_store::decorator::Transactional;
+    /// use zrx_store::{Store, StoreMut};
+    ///
+    /// // Create store and initial state
+    /// let mut store = Transactional::default();
+    /// store.insert("key", 42);
+    ///
+    /// // Remove value in transaction
+    /// store.begin();
+    /// let value = store.remove(&"key");
+    /// assert_eq!(value, Some(42));
+    /// assert_eq!(store.get(&"key"), None);
+    /// ```
+    fn remove<Q>(&mut self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: Key,
+    {
+        let Some(overlay) = self.overlay.as_mut() else {
+            return self.store.remove(key);
+        };
+
+        // If the key is buffered in the overlay, replace it with a removal and
+        // return the buffered value, since the underlying store is shadowed
+        if let Some(value) = overlay.get_mut(key) {
+            return value.take();
+        }
+
+        // Otherwise, record a removal for the owned key of the underlying
+        // store, and return a copy of its value, as it's left untouched
+        let prior = self.store.get(key).cloned()?;
+        let key = self.find(key)?;
+        if let Some(overlay) = self.overlay.as_mut() {
+            overlay.insert(key, None);
+        }
+        Some(prior)
+    }
+
+    /// Clears the store, removing all items.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zrx_store::decorator::Transactional;
+    /// use zrx_store::{Store, StoreMut};
+    ///
+    /// // Create store and initial state
+    /// let mut store = Transactional::default();
+    /// store.insert("key", 42);
+    ///
+    /// // Clear store in transaction
+    /// store.begin();
+    /// store.clear();
+    /// assert!(store.is_empty());
+    /// ```
+    fn clear(&mut self) {
+        if let Some(overlay) = self.overlay.as_mut() {
+            overlay.clear();
+            for key in self.store.keys() {
+                overlay.insert(key.clone(), None);
+            }
+        } else {
+            self.store.clear();
+        }
+    }
+}
+
+impl<K, V, S> StoreIterable<K, V> for Transactional<K, V, S>
+where
+    K: Key,
+    S: StoreIterable<K, V>,
+{
+    /// Creates an iterator over the store.
+    ///
+    /// Items buffered in the overlay are yielded first, followed by the items
+    /// of the underlying store that are not shadowed by the overlay.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zrx_store::decorator::Transactional;
+    /// use zrx_store::{StoreIterable, StoreMut};
+    ///
+    /// // Create store and initial state
+    /// let mut store = Transactional::default();
+    /// store.insert("key", 42);
+    ///
+    /// // Create iterator over the store
+    /// for (key, value) in store.iter() {
+    ///     println!("{key}: {value}");
+    /// }
+    /// ```
+    #[inline]
+    fn iter<'a>(&'a self) -> impl Iterator<Item = (&'a K, &'a V)>
+    where
+        K: 'a,
+        V: 'a,
+    {
+        let overlay = self.overlay.iter().flatten();
+        let buffered = overlay.filter_map(|(key, value)| {
+            value.as_ref().map(|value| (key, value))
+        });
+        buffered.chain(self.store.iter().filter(|(key, _)| {
+            !self.overlay.as_ref().is_some_and(|overlay| {
+                overlay.contains_key(*key) // fmt
+            })
+        }))
+    }
+}
+
+impl<K, V, S> StoreKeys<K, V> for Transactional<K, V, S>
+where
+    K: Key,
+    S: StoreIterable<K, V>,
+{
+    /// Creates a key iterator over the store.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zrx_store::decorator::Transactional;
+    /// use zrx_store::{StoreKeys, StoreMut};
+    ///
+    /// // Create store and initial state
+    /// let mut store = Transactional::default();
+    /// store.insert("key", 42);
+    ///
+    /// // Create iterator over the store
+    /// for key in store.keys() {
+    ///     println!("{key}");
+    /// }
+    /// ```
+    #[inline]
+    fn keys<'a>(&'a self) -> impl Iterator<Item = &'a K>
+    where
+        K: 'a,
+    {
+        self.iter().map(|(key, _)| key)
+    }
+}
+
+impl<K, V, S> StoreValues<K, V> for Transactional<K, V, S>
+where
+    K: Key,
+    S: StoreIterable<K, V>,
+{
+    /// Creates a value iterator over the store.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zrx_store::decorator::Transactional;
+    /// use zrx_store::{StoreMut, StoreValues};
+    ///
+    /// // Create store and initial state
+    /// let mut store = Transactional::default();
+    /// store.insert("key", 42);
+    ///
+    /// // Create iterator over the store
+    /// for value in store.values() {
+    ///     println!("{value}");
+    /// }
+    /// ```
+    #[inline]
+    fn values<'a>(&'a self) -> impl Iterator<Item = &'a V>
+    where
+        V: 'a,
+    {
+        self.iter().map(|(_, value)| value)
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+#[allow(clippy::implicit_hasher)]
+impl<K, V> Default for Transactional<K, V, HashMap<K, V>>
+where
+    K: Key,
+{
+    /// Creates a transactional decorator with [`HashMap::default`] as a store.
+    ///
+    /// Note that this method does not allow to customize the [`BuildHasher`][],
+    /// but uses [`ahash`] by default, which is the fastest known hasher.
+    ///
+    /// [`BuildHasher`]: std::hash::BuildHasher
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zrx_store::decorator::Transactional;
+    /// use zrx_store::StoreMut;
+    ///
+    /// // Create store and initial state
+    /// let mut store = Transactional::default();
+    /// store.insert("key", 42);
+    /// ```
+    #[inline]
+    fn default() -> Self {
+        Self::new(HashMap::default())
+    }
+}
+
+impl<K, V, S> fmt::Debug for Transactional<K, V, S>
+where
+    K: Key + fmt::Debug,
+    V: fmt::Debug,
+    S: Store<K, V> + fmt::Debug,
+{
+    /// Formats the transactional decorator for debugging.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Transactional")
+            .field("store", &self.store)
+            .field("overlay", &self.overlay)
+            .finish()
+    }
+}
@@ -41,8 +41,49 @@ use std::hash::Hash;
 /// Thus, keys must implement [`Clone`], [`Eq`], [`Hash`] and [`Ord`], which we
 /// consider a reasonable requirement and a good trade-off for a generic API.
 ///
+/// This trait is implemented for all types satisfying those bounds, so it never
+/// needs to be implemented manually. Tuples of keys are keys, as the standard
+/// library implements all of the traits for tuples, and so are newtypes, which
+/// only need to derive the required traits.
+///
 /// [`Borrow`]: std::borrow::Borrow
 /// [`Store`]: crate::store::Store
+///
+/// # Examples
+///
+/// Use a tuple as a key:
+///
+/// ```
+/// use std::collections::HashMap;
+/// use zrx_store::{Store, StoreMut};
+///
+/// // Create store and initial state
+/// let mut store = HashMap::new();
+/// StoreMut::insert(&mut store, ("docs", 1), 42);
+///
+/// // Obtain reference to value
+/// let value = Store::get(&store, &("docs", 1));
+/// assert_eq!(value, Some(&42));
+/// ```
+///
+/// Use a newtype as a key:
+///
+/// ```
+/// use std::collections::HashMap;
+/// use zrx_store::{Store, StoreMut};
+///
+/// // Create newtype for key
+/// #[derive(Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+/// struct Name(String);
+///
+/// // Create store and initial state
+/// let mut store = HashMap::new();
+/// StoreMut::insert(&mut store, Name("key".into()), 42);
+///
+/// // Obtain reference to value
+/// let value = Store::get(&store, &Name("key".into()));
+/// assert_eq!(value, Some(&42));
+/// ```
 pub trait Key: Clone + Eq + Hash + Ord {}
 
 // ----------------------------------------------------------------------------
@@ -25,6 +25,28 @@
 
 //! Store utilities.
 
+use std::cmp::Ordering;
+use std::{iter, mem};
+#[cfg(feature = "graph")]
+use zrx_graph::Graph;
+
+#[cfg(feature = "graph")]
+use crate::store::StoreFromIterator;
+use crate::store::{Key, StoreRange};
+
+// ----------------------------------------------------------------------------
+// Enums
+// ----------------------------------------------------------------------------
+
+/// Side of a merge, denoting which store an entry originates from.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum MergeSide {
+    /// Entry from the left store.
+    Left,
+    /// Entry from the right store.
+    Right,
+}
+
 // ----------------------------------------------------------------------------
 // Functions
 // ----------------------------------------------------------------------------
@@ -42,3 +64,124 @@ where
         true
     }
 }
+
+/// Replaces the prior value if it has changed, returning it.
+#[inline]
+pub fn replace_if_changed<V>(prior: &mut V, value: &V) -> Option<V>
+where
+    V: Clone + Eq,
+{
+    if prior == value {
+        None
+    } else {
+        Some(mem::replace(prior, value.clone()))
+    }
+}
+
+/// Creates an iterator merging the entries of two stores in key order.
+///
+/// Both stores are iterated lazily via [`StoreRange::range`], which yields
+/// entries in ascending key order, so neither store must be materialized. Each
+/// entry is tagged with the [`MergeSide`] it originates from, and if both stores
+/// contain the same key, the entry of the left store is yielded first, which
+/// allows to implement merge joins on top of this function.
+///
+/// # Examples
+///
+/// ```
+/// use std::collections::BTreeMap;
+/// use zrx_store::util::{merge_sorted, MergeSide};
+/// use zrx_store::StoreMut;
+///
+/// // Create stores and initial state
+/// let mut a = BTreeMap::new();
+/// a.insert("a", 1);
+/// a.insert("c", 3);
+/// let mut b = BTreeMap::new();
+/// b.insert("b", 2);
+/// b.insert("c", 4);
+/// b.insert("d", 5);
+///
+/// // Merge stores in key order
+/// let entries = merge_sorted(&a, &b).collect::<Vec<_>>();
+/// assert_eq!(entries, [
+///     (MergeSide::Left, &"a", &1),
+///     (MergeSide::Right, &"b", &2),
+///     (MergeSide::Left, &"c", &3),
+///     (MergeSide::Right, &"c", &4),
+///     (MergeSide::Right, &"d", &5),
+/// ]);
+/// ```
+pub fn merge_sorted<'a, K, V, S1, S2>(
+    a: &'a S1, b: &'a S2,
+) -> impl Iterator<Item = (MergeSide, &'a K, &'a V)>
+where
+    K: Key + 'a,
+    V: 'a,
+    S1: StoreRange<K, V>,
+    S2: StoreRange<K, V>,
+{
+    let mut a = a.range(..).peekable();
+    let mut b = b.range(..).peekable();
+
+    // Yield the entry with the smaller key, preferring the left store if the
+    // keys are equal, until both iterators are exhausted
+    iter::from_fn(move || {
+        let side = match (a.peek(), b.peek()) {
+            (Some((x, _)), Some((y, _))) => match x.cmp(y) {
+                Ordering::Less | Ordering::Equal => MergeSide::Left,
+                Ordering::Greater => MergeSide::Right,
+            },
+            (Some(_), None) => MergeSide::Left,
+            (None, Some(_)) => MergeSide::Right,
+            (None, None) => return None,
+        };
+
+        // Advance the iterator of the chosen side
+        let (key, value) = match side {
+            MergeSide::Left => a.next()?,
+            MergeSide::Right => b.next()?,
+        };
+        Some((side, key, value))
+    })
+}
+
+/// Creates a store from the data of a graph, keyed by node index.
+///
+/// # Examples
+///
+/// ```
+/// # use std::error::Error;
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// use std::collections::HashMap;
+/// use zrx_graph::Graph;
+/// use zrx_store::util::from_graph;
+/// use zrx_store::Store;
+///
+/// // Create graph builder and add nodes
+/// let mut builder = Graph::builder();
+/// let a = builder.add_node("a");
+/// let b = builder.add_node("b");
+/// let c = builder.add_node("c");
+///
+/// // Create edges between nodes
+/// builder.add_edge(a, b, 0)?;
+/// builder.add_edge(b, c, 0)?;
+///
+/// // Create store from graph
+/// let store: HashMap<_, _> = from_graph(builder.build());
+/// assert_eq!(store.get(&a), Some(&"a"));
+/// assert_eq!(store.get(&b), Some(&"b"));
+/// assert_eq!(store.get(&c), Some(&"c"));
+/// # Ok(())
+/// # }
+/// ```
+#[cfg(feature = "graph")]
+#[inline]
+#[must_use]
+pub fn from_graph<T, S>(graph: Graph<T>) -> S
+where
+    S: StoreFromIterator<usize, T>,
+{
+    graph.into_iter().enumerate().collect()
+}
@@ -36,17 +36,21 @@ use zrx_scheduler::{Id, Value};
 
 use super::Stream;
 
+mod aggregate;
 mod audit;
+mod buffer_bounded;
 mod chunks;
 mod coalesce;
 mod count;
 mod debounce;
+mod delta_coalesce;
 mod delta_count;
 mod delta_filter;
 mod delta_filter_map;
 mod delta_map;
 mod delta_reduce;
 mod difference;
+mod distinct_by;
 mod fill;
 mod filter;
 mod filter_map;
@@ -59,14 +63,30 @@ mod join_filter_map;
 mod join_map;
 mod lift;
 mod map;
+mod materialize;
+mod on_complete;
+mod partition;
 mod product;
 mod reduce;
+mod replay;
 mod sample;
 mod select;
 mod sort;
+mod start_with;
+mod switch_map;
 mod throttle;
+mod throttle_latest;
 mod transpose;
+mod tumbling_window;
 mod union;
+mod with_latest_from;
+
+#[cfg(test)]
+mod testing;
+
+pub use buffer_bounded::BufferOverflow;
+pub use materialize::Materialized;
+pub use replay::Replayed;
 
 // ----------------------------------------------------------------------------
 // Traits
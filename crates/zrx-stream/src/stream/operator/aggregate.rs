@@ -0,0 +1,179 @@
+// Copyright (c) Zensical LLC <https://zensical.org>
+
+// SPDX-License-Identifier: MIT
+// Third-party contributions licensed under CLA
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+
+// ----------------------------------------------------------------------------
+
+//! Aggregate operator.
+
+use ahash::HashMap;
+use zrx_scheduler::action::descriptor::Property;
+use zrx_scheduler::action::output::IntoOutputs;
+use zrx_scheduler::action::Descriptor;
+use zrx_scheduler::effect::Item;
+use zrx_scheduler::{Id, Value};
+
+use crate::stream::Stream;
+
+use super::{Operator, OperatorExt};
+
+// ----------------------------------------------------------------------------
+// Structs
+// ----------------------------------------------------------------------------
+
+/// Aggregate operator.
+struct Aggregate<I, T, A, F, G> {
+    /// Identifier.
+    id: I,
+    /// Current value of the aggregate.
+    value: A,
+    /// Function to add an item to the aggregate.
+    add: F,
+    /// Function to remove an item from the aggregate.
+    remove: G,
+    /// Store of items.
+    items: HashMap<I, T>,
+}
+
+// ----------------------------------------------------------------------------
+// Implementations
+// ----------------------------------------------------------------------------
+
+impl<I, T> Stream<I, T>
+where
+    I: Id,
+    T: Value + Clone + Eq,
+{
+    pub fn aggregate<A, F, G>(
+        &self, id: I, init: A, add: F, remove: G,
+    ) -> Stream<I, A>
+    where
+        A: Value + Clone,
+        F: Fn(&mut A, &T) + 'static,
+        G: Fn(&mut A, &T) + 'static,
+    {
+        self.with_operator(Aggregate {
+            id,
+            value: init,
+            add,
+            remove,
+            items: HashMap::default(),
+        })
+    }
+}
+
+// ----------------------------------------------------------------------------
+// Trait implementations
+// ----------------------------------------------------------------------------
+
+impl<I, T, A, F, G> Operator<I, T> for Aggregate<I, T, A, F, G>
+where
+    I: Id,
+    T: Value + Clone + Eq,
+    A: Value + Clone,
+    F: Fn(&mut A, &T),
+    G: Fn(&mut A, &T),
+{
+    type Item<'a> = Item<&'a I, Option<&'a T>>;
+
+    /// Handles the given item.
+    ///
+    /// In contrast to reductions, the aggregate is updated incrementally, so
+    /// the operator function doesn't need to be invoked on the entire store.
+    /// Insertions are added to the aggregate, and deletions are removed from
+    /// it, while updates first remove the prior item, and then add the new
+    /// one. This is why the operator must retain the current items, so they
+    /// can be removed again. The aggregate is emitted for the identifier of
+    /// the operator whenever it changed, and unchanged items are ignored.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(level = "debug", skip_all, fields(id = %item.id))
+    )]
+    fn handle(&mut self, item: Self::Item<'_>) -> impl IntoOutputs<I> {
+        let prior = match item.data {
+            Some(data) => {
+                if self.items.get(item.id) == Some(data) {
+                    return None;
+                }
+                (self.add)(&mut self.value, data);
+                self.items.insert(item.id.clone(), data.clone())
+            }
+            None => Some(self.items.remove(item.id)?),
+        };
+
+        // Remove prior item from aggregate, and emit the aggregate
+        if let Some(prior) = prior {
+            (self.remove)(&mut self.value, &prior);
+        }
+        Some(Item::new(self.id.clone(), Some(self.value.clone())))
+    }
+
+    /// Returns the descriptor.
+    #[inline]
+    fn descriptor(&self) -> Descriptor {
+        Descriptor::builder() // fmt
+            .property(Property::Flush)
+            .build()
+    }
+}
+
+// ----------------------------------------------------------------------------
+// Tests
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+
+    mod aggregate {
+        use ahash::HashMap;
+        use zrx_scheduler::effect::Item;
+
+        use crate::stream::operator::aggregate::Aggregate;
+        use crate::stream::operator::testing::{handle, items};
+
+        #[test]
+        fn counts_inserts_and_removals() {
+            let mut operator = Aggregate {
+                id: "count",
+                value: 0usize,
+                add: |count: &mut usize, _: &i32| *count += 1,
+                remove: |count: &mut usize, _: &i32| *count -= 1,
+                items: HashMap::default(),
+            };
+            for (id, data, check) in [
+                ("a", Some(1), Some(1)),
+                ("b", Some(2), Some(2)),
+                ("a", Some(3), Some(2)),
+                ("a", Some(3), None),
+                ("c", None, None),
+                ("a", None, Some(1)),
+                ("b", None, Some(0)),
+                ("b", Some(4), Some(1)),
+            ] {
+                let item = Item::new(&id, data.as_ref());
+                let outputs = items::<_, usize>(handle(&mut operator, item));
+                let check = check.map(|count| ("count", Some(count)));
+                assert_eq!(outputs.into_iter().next(), check, "{id} {data:?}");
+            }
+        }
+    }
+}
@@ -0,0 +1,244 @@
+// Copyright (c) Zensical LLC <https://zensical.org>
+
+// SPDX-License-Identifier: MIT
+// Third-party contributions licensed under CLA
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+
+// ----------------------------------------------------------------------------
+
+//! Bounded buffer operator.
+
+use ahash::HashMap;
+use std::collections::BTreeMap;
+use zrx_scheduler::action::output::IntoOutputs;
+use zrx_scheduler::action::Descriptor;
+use zrx_scheduler::effect::Item;
+use zrx_scheduler::{Id, Value};
+
+use crate::stream::Stream;
+
+use super::{Operator, OperatorExt};
+
+// ----------------------------------------------------------------------------
+// Structs
+// ----------------------------------------------------------------------------
+
+/// Bounded buffer operator.
+struct BufferBounded<I> {
+    /// Maximum number of items.
+    capacity: usize,
+    /// Overflow policy.
+    overflow: BufferOverflow,
+    /// Sequence number of the next item.
+    sequence: u64,
+    /// Identifiers of items, ordered by sequence number.
+    order: BTreeMap<u64, I>,
+    /// Sequence numbers of items.
+    items: HashMap<I, u64>,
+}
+
+// ----------------------------------------------------------------------------
+// Enums
+// ----------------------------------------------------------------------------
+
+/// Buffer overflow policy.
+///
+/// The overflow policy determines how [`Stream::buffer_bounded`] behaves if an
+/// item is inserted while the buffer is at capacity. Since the scheduler pushes
+/// items through the stream, backpressure can't be applied to the source, so
+/// one of the items must be dropped.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum BufferOverflow {
+    /// Evict the least recently inserted or updated item.
+    #[default]
+    DropOldest,
+    /// Drop the inserted item.
+    DropNewest,
+}
+
+// ----------------------------------------------------------------------------
+// Implementations
+// ----------------------------------------------------------------------------
+
+impl<I, T> Stream<I, T>
+where
+    I: Id,
+    T: Value + Clone,
+{
+    pub fn buffer_bounded(
+        &self, capacity: usize, overflow: BufferOverflow,
+    ) -> Stream<I, T> {
+        self.with_operator(BufferBounded {
+            capacity,
+            overflow,
+            sequence: 0,
+            order: BTreeMap::new(),
+            items: HashMap::default(),
+        })
+    }
+}
+
+impl<I> BufferBounded<I>
+where
+    I: Id,
+{
+    /// Inserts or refreshes the item with the given identifier.
+    fn touch(&mut self, id: I) {
+        if let Some(sequence) = self.items.insert(id.clone(), self.sequence) {
+            self.order.remove(&sequence);
+        }
+        self.order.insert(self.sequence, id);
+        self.sequence += 1;
+    }
+}
+
+// ----------------------------------------------------------------------------
+// Trait implementations
+// ----------------------------------------------------------------------------
+
+impl<I, T> Operator<I, T> for BufferBounded<I>
+where
+    I: Id,
+    T: Value + Clone,
+{
+    type Item<'a> = Item<&'a I, Option<&'a T>>;
+
+    /// Handles the given item.
+    ///
+    /// The operator bounds the number of items that are present downstream by
+    /// the capacity, tracking the identifiers of all forwarded items in order
+    /// of insertion or update. When an item is inserted while the buffer is
+    /// full, the overflow policy decides whether the oldest item is evicted,
+    /// which is emitted as a deletion, or the inserted item is dropped. Updates
+    /// of present items are forwarded, as they don't require more space, and
+    /// deletions are only forwarded for present items.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(level = "debug", skip_all, fields(id = %item.id))
+    )]
+    fn handle(&mut self, item: Self::Item<'_>) -> impl IntoOutputs<I> {
+        let mut outputs = Vec::new();
+        if let Some(data) = item.data {
+            if !self.items.contains_key(item.id)
+                && self.items.len() >= self.capacity
+            {
+                match self.overflow {
+                    BufferOverflow::DropNewest => return outputs,
+                    BufferOverflow::DropOldest => {
+                        if let Some((_, id)) = self.order.pop_first() {
+                            self.items.remove(&id);
+                            outputs.push(Item::new(id, None));
+                        }
+                    }
+                }
+            }
+
+            // Forward item, unless the buffer has no capacity at all
+            if self.capacity > 0 {
+                self.touch(item.id.clone());
+                outputs.push(Item::new(item.id.clone(), Some(data.clone())));
+            }
+        } else if let Some(sequence) = self.items.remove(item.id) {
+            self.order.remove(&sequence);
+            outputs.push(Item::new(item.id.clone(), None));
+        }
+
+        // Return items
+        outputs
+    }
+
+    /// Returns the descriptor.
+    #[inline]
+    fn descriptor(&self) -> Descriptor {
+        Descriptor::default()
+    }
+}
+
+// ----------------------------------------------------------------------------
+// Tests
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+
+    mod buffer_bounded {
+        use ahash::HashMap;
+        use std::collections::BTreeMap;
+        use zrx_scheduler::effect::Item;
+
+        use crate::stream::operator::buffer_bounded::{
+            BufferBounded, BufferOverflow,
+        };
+        use crate::stream::operator::testing;
+
+        fn handle(
+            operator: &mut BufferBounded<&'static str>, id: &'static str,
+            data: Option<i32>,
+        ) -> Vec<(&'static str, Option<i32>)> {
+            let item = Item::new(&id, data.as_ref());
+            testing::items(testing::handle::<_, i32, _>(operator, item))
+        }
+
+        fn operator(overflow: BufferOverflow) -> BufferBounded<&'static str> {
+            BufferBounded {
+                capacity: 2,
+                overflow,
+                sequence: 0,
+                order: BTreeMap::new(),
+                items: HashMap::default(),
+            }
+        }
+
+        #[test]
+        fn evicts_oldest_item() {
+            let mut operator = operator(BufferOverflow::DropOldest);
+            assert_eq!(handle(&mut operator, "a", Some(1)), [("a", Some(1))]);
+            assert_eq!(handle(&mut operator, "b", Some(2)), [("b", Some(2))]);
+
+            // Ensure updates refresh items without eviction
+            assert_eq!(handle(&mut operator, "a", Some(3)), [("a", Some(3))]);
+
+            // Ensure exceeding capacity evicts the oldest item
+            assert_eq!(
+                handle(&mut operator, "c", Some(4)),
+                [("b", None), ("c", Some(4))]
+            );
+
+            // Ensure deletions of evicted items are not forwarded
+            assert_eq!(handle(&mut operator, "b", None), []);
+            assert_eq!(handle(&mut operator, "a", None), [("a", None)]);
+        }
+
+        #[test]
+        fn drops_newest_item() {
+            let mut operator = operator(BufferOverflow::DropNewest);
+            assert_eq!(handle(&mut operator, "a", Some(1)), [("a", Some(1))]);
+            assert_eq!(handle(&mut operator, "b", Some(2)), [("b", Some(2))]);
+
+            // Ensure exceeding capacity drops the inserted item
+            assert_eq!(handle(&mut operator, "c", Some(3)), []);
+            assert_eq!(handle(&mut operator, "c", None), []);
+
+            // Ensure capacity is available again after deletion
+            assert_eq!(handle(&mut operator, "a", None), [("a", None)]);
+            assert_eq!(handle(&mut operator, "c", Some(4)), [("c", Some(4))]);
+        }
+    }
+}
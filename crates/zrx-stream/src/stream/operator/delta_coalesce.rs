@@ -0,0 +1,203 @@
+// Copyright (c) Zensical LLC <https://zensical.org>
+
+// SPDX-License-Identifier: MIT
+// Third-party contributions licensed under CLA
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+
+// ----------------------------------------------------------------------------
+
+//! Delta coalesce operator.
+
+use ahash::{HashMap, HashSet};
+use zrx_scheduler::action::descriptor::Property;
+use zrx_scheduler::action::output::IntoOutputs;
+use zrx_scheduler::action::Descriptor;
+use zrx_scheduler::effect::Item;
+use zrx_scheduler::{Id, Value};
+use zrx_store::StoreMutRef;
+
+use crate::stream::value::Delta;
+use crate::stream::Stream;
+
+use super::{Operator, OperatorExt};
+
+// ----------------------------------------------------------------------------
+// Structs
+// ----------------------------------------------------------------------------
+
+/// Delta coalesce operator.
+struct DeltaCoalesce<I> {
+    /// Identifiers of present items, by identifier.
+    present: HashMap<I, HashSet<I>>,
+}
+
+// ----------------------------------------------------------------------------
+// Implementations
+// ----------------------------------------------------------------------------
+
+impl<I, T> Stream<I, Delta<I, T>>
+where
+    I: Id,
+    T: Value + Clone,
+{
+    pub fn delta_coalesce(&self) -> Stream<I, Delta<I, T>> {
+        self.with_operator(DeltaCoalesce { present: HashMap::default() })
+    }
+}
+
+// ----------------------------------------------------------------------------
+// Trait implementations
+// ----------------------------------------------------------------------------
+
+impl<I, T> Operator<I, Delta<I, T>> for DeltaCoalesce<I>
+where
+    I: Id,
+    T: Value + Clone,
+{
+    type Item<'a> = Item<&'a I, &'a Delta<I, T>>;
+
+    /// Handles the given item.
+    ///
+    /// Coalescing reduces the items of a delta to their net change, keeping
+    /// only the last item for each identifier at the position of its first
+    /// occurrence. Deletions of items that were never emitted are dropped,
+    /// so an insertion followed by a deletion of the same identifier within
+    /// the same delta cancels out. If nothing remains, nothing is emitted.
+    /// Present items are tracked separately for each identifier of a delta,
+    /// as every identifier denotes a distinct collection.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(level = "debug", skip_all, fields(id = %item.id))
+    )]
+    fn handle(&mut self, item: Self::Item<'_>) -> impl IntoOutputs<I> {
+        let mut positions = HashMap::default();
+        let mut parts: Vec<Item<I, Option<T>>> = Vec::new();
+
+        // Replace prior items with the same identifier by the latest item, as
+        // only the last change of each identifier is relevant downstream
+        for part in item.data {
+            if let Some(&index) = positions.get(&part.id) {
+                parts[index] = part.clone();
+            } else {
+                positions.insert(part.id.clone(), parts.len());
+                parts.push(part.clone());
+            }
+        }
+
+        // Drop deletions of items that are not present downstream, and keep
+        // track of the identifiers of all items that are present downstream
+        let present = self.present.get_or_insert_default(item.id);
+        parts.retain(|part| {
+            if part.data.is_some() {
+                present.insert(part.id.clone());
+                true
+            } else {
+                present.remove(&part.id)
+            }
+        });
+
+        // Forget the identifier once no items are present downstream anymore
+        if present.is_empty() {
+            self.present.remove(item.id);
+        }
+
+        // Only emit the delta if there's any net change
+        (!parts.is_empty())
+            .then(|| Item::new(item.id.clone(), Some(Delta::from_iter(parts))))
+    }
+
+    /// Returns the descriptor.
+    #[inline]
+    fn descriptor(&self) -> Descriptor {
+        Descriptor::builder() // fmt
+            .property(Property::Flush)
+            .build()
+    }
+}
+
+// ----------------------------------------------------------------------------
+// Tests
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+
+    mod delta_coalesce {
+        use ahash::HashMap;
+        use zrx_scheduler::effect::Item;
+
+        use crate::stream::operator::delta_coalesce::DeltaCoalesce;
+        use crate::stream::operator::testing::{deltas, handle};
+        use crate::stream::value::Delta;
+
+        #[test]
+        fn cancels_insertion_and_deletion() {
+            let mut coalesce = DeltaCoalesce { present: HashMap::default() };
+            let delta =
+                Delta::from([Item::new("a", Some(1)), Item::new("a", None)]);
+            let outputs = handle(&mut coalesce, Item::new(&"x", &delta));
+            assert!(deltas::<_, i32>(outputs).is_empty());
+        }
+
+        #[test]
+        fn keeps_net_change() {
+            let mut coalesce = DeltaCoalesce { present: HashMap::default() };
+            let delta = Delta::from([
+                Item::new("a", Some(1)),
+                Item::new("b", Some(2)),
+                Item::new("a", Some(3)),
+            ]);
+            let outputs = handle(&mut coalesce, Item::new(&"x", &delta));
+            assert_eq!(
+                deltas(outputs),
+                [("x", vec![("a", Some(3)), ("b", Some(2))])]
+            );
+            let delta = Delta::from([
+                Item::new("a", None),
+                Item::new("c", Some(4)),
+                Item::new("c", None),
+            ]);
+            let outputs = handle(&mut coalesce, Item::new(&"x", &delta));
+            assert_eq!(deltas::<_, i32>(outputs), [("x", vec![("a", None)])]);
+        }
+
+        #[test]
+        fn tracks_items_per_identifier() {
+            let mut coalesce = DeltaCoalesce { present: HashMap::default() };
+            let delta = Delta::from([Item::new("a", Some(1))]);
+            let outputs = handle(&mut coalesce, Item::new(&"x", &delta));
+            assert_eq!(deltas(outputs), [("x", vec![("a", Some(1))])]);
+
+            // Ensure deletions are dropped if only present for another delta
+            let delta = Delta::from([Item::new("a", None::<i32>)]);
+            let outputs = handle(&mut coalesce, Item::new(&"y", &delta));
+            assert!(deltas::<_, i32>(outputs).is_empty());
+
+            // Ensure insertions for another delta don't mask deletions
+            let delta = Delta::from([Item::new("a", Some(2))]);
+            let outputs = handle(&mut coalesce, Item::new(&"y", &delta));
+            assert_eq!(deltas(outputs), [("y", vec![("a", Some(2))])]);
+            let delta = Delta::from([Item::new("a", None::<i32>)]);
+            let outputs = handle(&mut coalesce, Item::new(&"x", &delta));
+            assert_eq!(deltas::<_, i32>(outputs), [("x", vec![("a", None)])]);
+            assert!(coalesce.present.keys().eq([&"y"]));
+        }
+    }
+}
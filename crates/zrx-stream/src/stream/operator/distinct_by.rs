@@ -0,0 +1,173 @@
+// Copyright (c) Zensical LLC <https://zensical.org>
+
+// SPDX-License-Identifier: MIT
+// Third-party contributions licensed under CLA
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+
+// ----------------------------------------------------------------------------
+
+//! Distinct by operator.
+
+use ahash::HashMap;
+use zrx_scheduler::action::descriptor::Property;
+use zrx_scheduler::action::output::IntoOutputs;
+use zrx_scheduler::action::Descriptor;
+use zrx_scheduler::effect::Item;
+use zrx_scheduler::{Id, Value};
+
+use crate::stream::Stream;
+
+use super::{Operator, OperatorExt};
+
+// ----------------------------------------------------------------------------
+// Structs
+// ----------------------------------------------------------------------------
+
+/// Distinct by operator.
+struct DistinctBy<I, K, F> {
+    /// Key projection.
+    function: F,
+    /// Last projected keys by identifier.
+    keys: HashMap<I, K>,
+}
+
+// ----------------------------------------------------------------------------
+// Implementations
+// ----------------------------------------------------------------------------
+
+impl<I, T> Stream<I, T>
+where
+    I: Id,
+    T: Value + Clone,
+{
+    pub fn distinct_by<F, K>(&self, f: F) -> Stream<I, T>
+    where
+        F: Fn(&T) -> K + 'static,
+        K: Eq + 'static,
+    {
+        self.with_operator(DistinctBy {
+            function: f,
+            keys: HashMap::default(),
+        })
+    }
+}
+
+// ----------------------------------------------------------------------------
+// Trait implementations
+// ----------------------------------------------------------------------------
+
+impl<I, T, K, F> Operator<I, T> for DistinctBy<I, K, F>
+where
+    I: Id,
+    T: Value + Clone,
+    F: Fn(&T) -> K,
+    K: Eq,
+{
+    type Item<'a> = Item<&'a I, Option<&'a T>>;
+
+    /// Handles the given item.
+    ///
+    /// The operator keeps the last projected key for each identifier, and only
+    /// emits an item if its key differs from the last one, so changes that
+    /// don't affect the key, e.g., of metadata, are suppressed. Deletions are
+    /// always emitted, and forget the key, so re-insertions are emitted, too.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(level = "debug", skip_all, fields(id = %item.id))
+    )]
+    fn handle(&mut self, item: Self::Item<'_>) -> impl IntoOutputs<I> {
+        if let Some(data) = item.data {
+            let key = (self.function)(data);
+            if self.keys.get(item.id) == Some(&key) {
+                return None;
+            }
+            self.keys.insert(item.id.clone(), key);
+        } else {
+            self.keys.remove(item.id);
+        }
+        Some(item.into_owned())
+    }
+
+    /// Returns the descriptor.
+    #[inline]
+    fn descriptor(&self) -> Descriptor {
+        Descriptor::builder()
+            .property(Property::Stable)
+            .property(Property::Flush)
+            .build()
+    }
+}
+
+// ----------------------------------------------------------------------------
+// Tests
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+
+    mod distinct_by {
+        use ahash::HashMap;
+        use zrx_scheduler::effect::Item;
+
+        use crate::stream::operator::distinct_by::DistinctBy;
+        use crate::stream::operator::testing;
+
+        type Entry = (&'static str, u32);
+
+        fn handle<F>(
+            distinct: &mut DistinctBy<&'static str, &'static str, F>,
+            id: &'static str, data: Option<Entry>,
+        ) -> Vec<Option<Entry>>
+        where
+            F: Fn(&Entry) -> &'static str,
+        {
+            let item = Item::new(&id, data.as_ref());
+            let outputs = testing::handle(distinct, item);
+            testing::items(outputs)
+                .into_iter()
+                .map(|(_, data)| data)
+                .collect()
+        }
+
+        #[test]
+        fn suppresses_unchanged_keys() {
+            let mut distinct = DistinctBy {
+                function: |entry: &Entry| entry.0,
+                keys: HashMap::default(),
+            };
+            let outputs = handle(&mut distinct, "a", Some(("x", 1)));
+            assert_eq!(outputs, [Some(("x", 1))]);
+
+            // Ensure changes to the value that keep the key aren't emitted
+            let outputs = handle(&mut distinct, "a", Some(("x", 2)));
+            assert!(outputs.is_empty());
+
+            // Ensure changes to the key are emitted
+            let outputs = handle(&mut distinct, "a", Some(("y", 2)));
+            assert_eq!(outputs, [Some(("y", 2))]);
+
+            // Ensure removals always pass, and forget the key
+            assert_eq!(handle(&mut distinct, "a", None), [None]);
+            assert_eq!(handle(&mut distinct, "a", None), [None]);
+            let outputs = handle(&mut distinct, "a", Some(("y", 2)));
+            assert_eq!(outputs, [Some(("y", 2))]);
+        }
+    }
+}
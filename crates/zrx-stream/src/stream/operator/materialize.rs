@@ -0,0 +1,170 @@
+// Copyright (c) Zensical LLC <https://zensical.org>
+
+// SPDX-License-Identifier: MIT
+// Third-party contributions licensed under CLA
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+
+// ----------------------------------------------------------------------------
+
+//! Materialize operator.
+
+use ahash::HashMap;
+use std::sync::{Arc, Mutex, PoisonError};
+use zrx_scheduler::action::descriptor::Property;
+use zrx_scheduler::action::output::IntoOutputs;
+use zrx_scheduler::action::Descriptor;
+use zrx_scheduler::effect::Item;
+use zrx_scheduler::{Id, Value};
+
+use crate::stream::Stream;
+
+use super::{Operator, OperatorExt};
+
+// ----------------------------------------------------------------------------
+// Structs
+// ----------------------------------------------------------------------------
+
+/// Materialize operator.
+struct Materialize<I, T> {
+    /// Current collection, shared with the handle.
+    items: Arc<Mutex<HashMap<I, T>>>,
+}
+
+/// Materialized stream.
+///
+/// This handle is returned by [`Stream::materialize`], and allows to pull the
+/// current contents of a stream synchronously, e.g., for request/response
+/// access over reactive state, instead of reacting to each change.
+#[derive(Clone, Debug)]
+pub struct Materialized<I, T> {
+    /// Current collection, shared with the operator.
+    items: Arc<Mutex<HashMap<I, T>>>,
+}
+
+// ----------------------------------------------------------------------------
+// Implementations
+// ----------------------------------------------------------------------------
+
+impl<I, T> Stream<I, T>
+where
+    I: Id,
+    T: Value + Clone,
+{
+    pub fn materialize(&self) -> Materialized<I, T> {
+        let items = Arc::new(Mutex::new(HashMap::default()));
+        let _: Stream<I, T> =
+            self.with_operator(Materialize { items: Arc::clone(&items) });
+        Materialized { items }
+    }
+}
+
+impl<I, T> Materialized<I, T>
+where
+    I: Id,
+    T: Value + Clone,
+{
+    /// Returns a snapshot of the current contents of the stream.
+    ///
+    /// The snapshot is a copy, which implements [`Collection`][], so it's not
+    /// affected by items that the stream receives after it was taken.
+    ///
+    /// [`Collection`]: crate::stream::value::Collection
+    pub fn collect_current(&self) -> HashMap<I, T> {
+        let items = self.items.lock();
+        items.unwrap_or_else(PoisonError::into_inner).clone()
+    }
+}
+
+// ----------------------------------------------------------------------------
+// Trait implementations
+// ----------------------------------------------------------------------------
+
+impl<I, T> Operator<I, T> for Materialize<I, T>
+where
+    I: Id,
+    T: Value + Clone,
+{
+    type Item<'a> = Item<&'a I, Option<&'a T>>;
+
+    /// Handles the given item.
+    ///
+    /// Insertions and updates are written to the shared collection, deletions
+    /// remove the item from it, so that it always reflects the net contents of
+    /// the stream. Nothing is emitted, as the collection is pulled on demand.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(level = "debug", skip_all, fields(id = %item.id))
+    )]
+    fn handle(&mut self, item: Self::Item<'_>) -> impl IntoOutputs<I> {
+        let mut items =
+            self.items.lock().unwrap_or_else(PoisonError::into_inner);
+        match item.data {
+            Some(data) => items.insert(item.id.clone(), data.clone()),
+            None => items.remove(item.id),
+        };
+    }
+
+    /// Returns the descriptor.
+    #[inline]
+    fn descriptor(&self) -> Descriptor {
+        Descriptor::builder() // fmt
+            .property(Property::Flush)
+            .build()
+    }
+}
+
+// ----------------------------------------------------------------------------
+// Tests
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+
+    mod collect_current {
+        use ahash::HashMap;
+        use std::sync::{Arc, Mutex};
+        use zrx_scheduler::effect::Item;
+
+        use crate::stream::operator::materialize::{Materialize, Materialized};
+        use crate::stream::operator::Operator;
+
+        #[test]
+        fn reflects_net_contents() {
+            let items = Arc::new(Mutex::new(HashMap::default()));
+            let mut materialize = Materialize { items: Arc::clone(&items) };
+            let materialized = Materialized { items };
+            for (id, data) in [
+                ("a", Some(1)),
+                ("b", Some(2)),
+                ("c", Some(3)),
+                ("a", Some(4)),
+                ("b", None),
+            ] {
+                materialize.handle(Item::new(&id, data.as_ref()));
+            }
+
+            // Ensure snapshot contains net contents
+            let snapshot = materialized.collect_current();
+            let mut items: Vec<_> = snapshot.into_iter().collect();
+            items.sort_unstable();
+            assert_eq!(items, [("a", 4), ("c", 3)]);
+        }
+    }
+}
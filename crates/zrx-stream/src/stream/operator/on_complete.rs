@@ -0,0 +1,198 @@
+// Copyright (c) Zensical LLC <https://zensical.org>
+
+// SPDX-License-Identifier: MIT
+// Third-party contributions licensed under CLA
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+
+// ----------------------------------------------------------------------------
+
+//! On complete operator.
+
+use zrx_scheduler::action::descriptor::{Interest, Property};
+use zrx_scheduler::action::output::IntoOutputs;
+use zrx_scheduler::action::Descriptor;
+use zrx_scheduler::effect::{Item, Signal};
+use zrx_scheduler::{Id, Value};
+
+use crate::stream::value::Delta;
+use crate::stream::Stream;
+
+use super::{Operator, OperatorExt};
+
+// ----------------------------------------------------------------------------
+// Structs
+// ----------------------------------------------------------------------------
+
+/// On complete operator.
+struct OnComplete<F> {
+    /// Operator function, taken when invoked.
+    function: Option<F>,
+}
+
+// ----------------------------------------------------------------------------
+// Implementations
+// ----------------------------------------------------------------------------
+
+impl<I, T> Stream<I, Delta<I, T>>
+where
+    I: Id,
+    T: Value + Clone,
+{
+    pub fn on_complete<F>(&self, f: F) -> Stream<I, Delta<I, T>>
+    where
+        F: FnOnce() + 'static,
+    {
+        self.with_operator(OnComplete { function: Some(f) })
+    }
+}
+
+// ----------------------------------------------------------------------------
+// Trait implementations
+// ----------------------------------------------------------------------------
+
+impl<I, T, F> Operator<I, Delta<I, T>> for OnComplete<F>
+where
+    I: Id,
+    T: Value + Clone,
+    F: FnOnce(),
+{
+    type Item<'a> = Item<&'a I, &'a Delta<I, T>>;
+
+    /// Handles the given item.
+    ///
+    /// All deltas are forwarded unchanged, as this operator only hooks into
+    /// the completion of the stream.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(level = "debug", skip_all, fields(id = %item.id))
+    )]
+    fn handle(&mut self, item: Self::Item<'_>) -> impl IntoOutputs<I> {
+        Item::new(item.id.clone(), Some(item.data.clone()))
+    }
+
+    /// Notifies the operator of a signal.
+    ///
+    /// Once all items were processed, and no further items are expected, the
+    /// function is invoked, which happens exactly once, as it's taken.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(level = "debug", skip_all)
+    )]
+    fn notify(&mut self, signal: Signal<I>) -> impl IntoOutputs<I> {
+        if let Signal::Complete = signal {
+            if let Some(function) = self.function.take() {
+                function();
+            }
+        }
+    }
+
+    /// Returns the descriptor.
+    #[inline]
+    fn descriptor(&self) -> Descriptor {
+        Descriptor::builder()
+            .property(Property::Pure)
+            .property(Property::Flush)
+            .interest(Interest::Complete)
+            .build()
+    }
+}
+
+// ----------------------------------------------------------------------------
+// Tests
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+
+    mod on_complete {
+        use std::cell::Cell;
+        use std::rc::Rc;
+        use std::sync::{Arc, Mutex};
+        use zrx_scheduler::action::output::IntoOutputs;
+        use zrx_scheduler::effect::{Item, Signal};
+
+        use crate::stream::operator::on_complete::OnComplete;
+        use crate::stream::operator::{testing, Operator};
+        use crate::stream::value::Delta;
+        use crate::stream::workspace::Workspace;
+
+        #[test]
+        fn fires_once_after_all_deltas() {
+            let count = Rc::new(Cell::new(0));
+            let mut operator = OnComplete {
+                function: Some({
+                    let count = Rc::clone(&count);
+                    move || count.set(count.get() + 1)
+                }),
+            };
+
+            // Forward all deltas without invoking the function
+            for data in 0..3 {
+                let delta = Delta::from([Item::new("a", Some(data))]);
+                let item = Item::new(&"step", &delta);
+                let report = operator.handle(item).into_outputs().unwrap();
+                assert_eq!(report.data.len(), 1);
+            }
+            assert_eq!(count.get(), 0);
+
+            // Signal completion repeatedly
+            for _ in 0..2 {
+                let signal = Signal::<&str>::Complete;
+                Operator::<_, Delta<_, i32>>::notify(&mut operator, signal)
+                    .into_outputs()
+                    .unwrap();
+            }
+            assert_eq!(count.get(), 1);
+        }
+
+        #[test]
+        fn fires_once_after_all_deltas_with_scheduler() {
+            let workspace = Workspace::<&str>::new();
+            let workflow = workspace.add_workflow();
+            let events = Arc::new(Mutex::new(Vec::new()));
+            workflow
+                .add_source::<Delta<&str, i32>>()
+                .on_complete({
+                    let events = Arc::clone(&events);
+                    move || events.lock().unwrap().push(None)
+                })
+                .for_each({
+                    let events = Arc::clone(&events);
+                    move |delta: &Delta<&'static str, i32>| {
+                        let iter = delta.iter().filter_map(|item| item.data);
+                        events.lock().unwrap().extend(iter.map(Some));
+                    }
+                });
+
+            // Ensure the function is invoked once after all deltas were handled
+            testing::run(
+                workspace,
+                [("x", 0), ("y", 1), ("z", 2)].map(|(id, data)| {
+                    let delta = Delta::from([Item::new("a", Some(data))]);
+                    (id, Some(delta))
+                }),
+            );
+            let mut events = events.lock().unwrap().clone();
+            assert_eq!(events.pop(), Some(None));
+            events.sort_unstable();
+            assert_eq!(events, [Some(0), Some(1), Some(2)]);
+        }
+    }
+}
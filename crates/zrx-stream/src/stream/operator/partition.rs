@@ -0,0 +1,339 @@
+// Copyright (c) Zensical LLC <https://zensical.org>
+
+// SPDX-License-Identifier: MIT
+// Third-party contributions licensed under CLA
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+
+// ----------------------------------------------------------------------------
+
+//! Partition operator.
+
+use ahash::HashMap;
+use zrx_scheduler::action::descriptor::Property;
+use zrx_scheduler::action::output::IntoOutputs;
+use zrx_scheduler::action::{Descriptor, Report, Result};
+use zrx_scheduler::effect::Item;
+use zrx_scheduler::{Id, Value};
+use zrx_store::StoreMutRef;
+
+use crate::stream::function::FilterFn;
+use crate::stream::value::Delta;
+use crate::stream::Stream;
+
+use super::{Operator, OperatorExt};
+
+// ----------------------------------------------------------------------------
+// Structs
+// ----------------------------------------------------------------------------
+
+/// Partition operator.
+struct Partition<I, F> {
+    /// Operator function.
+    function: F,
+    /// Sides of present items, `true` for matching items, by identifier.
+    sides: HashMap<I, HashMap<I, bool>>,
+}
+
+/// Partition side operator.
+struct Side {
+    /// Whether to select matching items.
+    matching: bool,
+}
+
+// ----------------------------------------------------------------------------
+// Implementations
+// ----------------------------------------------------------------------------
+
+impl<I, T> Stream<I, Delta<I, T>>
+where
+    I: Id,
+    T: Value + Clone,
+{
+    #[allow(clippy::type_complexity)]
+    pub fn partition<F>(
+        &self, f: F,
+    ) -> (Stream<I, Delta<I, T>>, Stream<I, Delta<I, T>>)
+    where
+        F: FilterFn<I, T>,
+    {
+        let stream: Stream<I, Sides<I, T>> = self.with_operator(Partition {
+            function: f,
+            sides: HashMap::default(),
+        });
+
+        // Split the partitioned stream into both of its sides
+        (
+            stream.with_operator(Side { matching: true }),
+            stream.with_operator(Side { matching: false }),
+        )
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+impl<I, F> Partition<I, F>
+where
+    I: Id,
+{
+    /// Partitions the items of the given delta into matching and non-matching
+    /// items, emitting removals for items that moved to the other side.
+    fn partition<T>(
+        &mut self, id: &I, delta: &Delta<I, T>,
+    ) -> Result<(Parts<I, T>, Parts<I, T>)>
+    where
+        T: Value + Clone,
+        F: FilterFn<I, T>,
+    {
+        let mut report = Report::new(());
+        let mut matching = Vec::new();
+        let mut rest = Vec::new();
+        let sides = self.sides.get_or_insert_default(id);
+        for part in delta {
+            // Determine the side of the item, where deletions have none, and
+            // record it, so we know where the item is present downstream
+            let (prior, side) = if let Some(data) = &part.data {
+                let temp = self.function.execute(&part.id, data)?;
+                let side = report.merge(temp);
+                (sides.insert(part.id.clone(), side), Some(side))
+            } else {
+                (sides.remove(&part.id), None)
+            };
+
+            // If the item was present on the other side, or was deleted, emit
+            // a removal on the side it was present on, which is the crux of
+            // partitioning, as updates might flip the predicate
+            if let Some(prior) = prior {
+                if side != Some(prior) {
+                    let item = Item::new(part.id.clone(), None);
+                    if prior { &mut matching } else { &mut rest }.push(item);
+                }
+            }
+
+            // Emit insertions or updates on the side the item is present on
+            if let Some(side) = side {
+                let item = part.clone();
+                if side { &mut matching } else { &mut rest }.push(item);
+            }
+        }
+
+        // Forget the identifier once no items are present anymore
+        if sides.is_empty() {
+            self.sides.remove(id);
+        }
+
+        // Return both sides
+        Ok(report.map(|()| (matching, rest)))
+    }
+}
+
+// ----------------------------------------------------------------------------
+// Trait implementations
+// ----------------------------------------------------------------------------
+
+impl<I, T, F> Operator<I, Delta<I, T>> for Partition<I, F>
+where
+    I: Id,
+    T: Value + Clone,
+    F: FilterFn<I, T>,
+{
+    type Item<'a> = Item<&'a I, &'a Delta<I, T>>;
+
+    /// Handles the given item.
+    ///
+    /// Each item of the delta is routed to exactly one side, depending on the
+    /// predicate. Since updates might flip the predicate, the operator keeps
+    /// track of the side each item is present on, emitting a removal on the
+    /// prior side and an insertion on the other. Deletions are only emitted on
+    /// the side the item is present on. If nothing changed, nothing is emitted.
+    /// Sides are tracked separately for each identifier of a delta.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(level = "debug", skip_all, fields(id = %item.id))
+    )]
+    fn handle(&mut self, item: Self::Item<'_>) -> impl IntoOutputs<I> {
+        self.partition(item.id, item.data).map(|report| {
+            report.map(|(matching, rest)| {
+                (!matching.is_empty() || !rest.is_empty()).then(|| {
+                    let data =
+                        (Delta::from_iter(matching), Delta::from_iter(rest));
+                    Item::new(item.id.clone(), Some(data))
+                })
+            })
+        })
+    }
+
+    /// Returns the descriptor.
+    #[inline]
+    fn descriptor(&self) -> Descriptor {
+        Descriptor::builder() // fmt
+            .property(Property::Flush)
+            .build()
+    }
+}
+
+impl<I, T> Operator<I, Sides<I, T>> for Side
+where
+    I: Id,
+    T: Value + Clone,
+{
+    type Item<'a> = Item<&'a I, &'a Sides<I, T>>;
+
+    /// Handles the given item.
+    ///
+    /// This operator selects one side of a partitioned delta, and only emits
+    /// it if it's not empty, so each side only sees its own changes.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(level = "debug", skip_all, fields(id = %item.id))
+    )]
+    fn handle(&mut self, item: Self::Item<'_>) -> impl IntoOutputs<I> {
+        let (matching, rest) = item.data;
+        let delta = if self.matching { matching } else { rest };
+        (delta.iter().next().is_some())
+            .then(|| Item::new(item.id.clone(), Some(delta.clone())))
+    }
+
+    /// Returns the descriptor.
+    #[inline]
+    fn descriptor(&self) -> Descriptor {
+        Descriptor::builder()
+            .property(Property::Pure)
+            .property(Property::Stable)
+            .build()
+    }
+}
+
+// ----------------------------------------------------------------------------
+// Type aliases
+// ----------------------------------------------------------------------------
+
+/// Partitioned delta, with matching and non-matching items.
+type Sides<I, T> = (Delta<I, T>, Delta<I, T>);
+
+/// Partitioned items of one side.
+type Parts<I, T> = Vec<Item<I, Option<T>>>;
+
+// ----------------------------------------------------------------------------
+// Tests
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+
+    mod partition {
+        use ahash::HashMap;
+        use zrx_scheduler::effect::Item;
+
+        use crate::stream::operator::partition::Partition;
+        use crate::stream::operator::testing;
+        use crate::stream::value::Delta;
+
+        type Part = (&'static str, Option<i32>);
+
+        fn handle<F>(
+            partition: &mut Partition<&'static str, F>, id: &&'static str,
+            delta: &Delta<&'static str, i32>,
+        ) -> Option<(Vec<Part>, Vec<Part>)>
+        where
+            F: Fn(&i32) -> bool + Send + 'static,
+        {
+            let outputs = testing::handle(partition, Item::new(id, delta));
+            let mut iter = testing::items(outputs).into_iter().map(
+                |(_, data): (_, Option<(Delta<_, i32>, Delta<_, i32>)>)| {
+                    let (matching, rest) = data.unwrap();
+                    let parts = |delta: Delta<&'static str, i32>| {
+                        let iter = delta.into_iter();
+                        iter.map(|part| (part.id, part.data)).collect()
+                    };
+                    (parts(matching), parts(rest))
+                },
+            );
+            iter.next()
+        }
+
+        #[test]
+        fn moves_item_on_flip() {
+            let mut partition = Partition {
+                function: |&n: &i32| n % 2 == 0,
+                sides: HashMap::default(),
+            };
+
+            // Ensure each item is routed to exactly one side
+            let delta =
+                Delta::from([Item::new("a", Some(2)), Item::new("b", Some(3))]);
+            assert_eq!(
+                handle(&mut partition, &"x", &delta),
+                Some((vec![("a", Some(2))], vec![("b", Some(3))]))
+            );
+
+            // Ensure updates flipping the predicate move the item
+            let delta = Delta::from([Item::new("a", Some(5))]);
+            assert_eq!(
+                handle(&mut partition, &"x", &delta),
+                Some((vec![("a", None)], vec![("a", Some(5))]))
+            );
+
+            // Ensure updates keeping the predicate stay on the same side
+            let delta = Delta::from([Item::new("a", Some(7))]);
+            assert_eq!(
+                handle(&mut partition, &"x", &delta),
+                Some((vec![], vec![("a", Some(7))]))
+            );
+
+            // Ensure deletions are only emitted on the present side
+            let delta =
+                Delta::from([Item::new("a", None), Item::new("c", None)]);
+            assert_eq!(
+                handle(&mut partition, &"x", &delta),
+                Some((vec![], vec![("a", None)]))
+            );
+            assert!(partition.sides[&"x"].keys().eq([&"b"]));
+        }
+
+        #[test]
+        fn tracks_sides_per_identifier() {
+            let mut partition = Partition {
+                function: |&n: &i32| n % 2 == 0,
+                sides: HashMap::default(),
+            };
+            let delta = Delta::from([Item::new("a", Some(2))]);
+            assert_eq!(
+                handle(&mut partition, &"x", &delta),
+                Some((vec![("a", Some(2))], vec![]))
+            );
+
+            // Ensure items of another delta don't flip sides
+            let delta = Delta::from([Item::new("a", Some(3))]);
+            assert_eq!(
+                handle(&mut partition, &"y", &delta),
+                Some((vec![], vec![("a", Some(3))]))
+            );
+
+            // Ensure deletions are only emitted for the same delta
+            let delta = Delta::from([Item::new("a", None)]);
+            assert_eq!(
+                handle(&mut partition, &"y", &delta),
+                Some((vec![], vec![("a", None)]))
+            );
+            assert_eq!(handle(&mut partition, &"y", &delta), None);
+            assert!(partition.sides.keys().eq([&"x"]));
+        }
+    }
+}
@@ -0,0 +1,255 @@
+// Copyright (c) Zensical LLC <https://zensical.org>
+
+// SPDX-License-Identifier: MIT
+// Third-party contributions licensed under CLA
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+
+// ----------------------------------------------------------------------------
+
+//! Replay operator.
+
+use ahash::HashMap;
+use std::fmt;
+use std::sync::{Arc, Mutex, PoisonError};
+use zrx_scheduler::action::descriptor::Property;
+use zrx_scheduler::action::output::IntoOutputs;
+use zrx_scheduler::action::Descriptor;
+use zrx_scheduler::effect::Item;
+use zrx_scheduler::{Id, Value};
+
+use crate::stream::Stream;
+
+use super::{Operator, OperatorExt};
+
+// ----------------------------------------------------------------------------
+// Structs
+// ----------------------------------------------------------------------------
+
+/// Replay operator.
+struct Replay<I, T> {
+    /// Current collection and subscribers, shared with the handle.
+    state: Arc<Mutex<State<I, T>>>,
+}
+
+/// Replayed stream.
+///
+/// This handle is returned by [`Stream::replay`], and allows to attach sinks
+/// while the scheduler is running. Each sink first receives the current items
+/// of the stream as insertions, and then all subsequent changes, so sinks that
+/// are attached late don't miss any prior state.
+#[derive(Clone)]
+pub struct Replayed<I, T> {
+    /// Current collection and subscribers, shared with the operator.
+    state: Arc<Mutex<State<I, T>>>,
+}
+
+/// Replay state.
+struct State<I, T> {
+    /// Current collection.
+    items: HashMap<I, T>,
+    /// Subscribers in order of subscription.
+    subscribers: Vec<Subscriber<I, T>>,
+}
+
+// ----------------------------------------------------------------------------
+// Implementations
+// ----------------------------------------------------------------------------
+
+impl<I, T> Stream<I, T>
+where
+    I: Id,
+    T: Value + Clone,
+{
+    pub fn replay(&self) -> Replayed<I, T> {
+        let state = Arc::new(Mutex::new(State {
+            items: HashMap::default(),
+            subscribers: Vec::new(),
+        }));
+        let _: Stream<I, T> =
+            self.with_operator(Replay { state: Arc::clone(&state) });
+        Replayed { state }
+    }
+}
+
+impl<I, T> Replayed<I, T>
+where
+    I: Id,
+    T: Value + Clone,
+{
+    /// Attaches the given function as a sink to the stream.
+    ///
+    /// The function is invoked with all current items of the stream as
+    /// insertions right away, and then with every subsequent insertion, update
+    /// and deletion. Replaying and attaching happen atomically, so no change is
+    /// missed or received twice, even if the scheduler is running.
+    pub fn subscribe<F>(&self, mut f: F)
+    where
+        F: FnMut(&I, Option<&T>) + Send + 'static,
+    {
+        let mut state =
+            self.state.lock().unwrap_or_else(PoisonError::into_inner);
+        for (id, data) in &state.items {
+            f(id, Some(data));
+        }
+        state.subscribers.push(Box::new(f));
+    }
+}
+
+// ----------------------------------------------------------------------------
+// Trait implementations
+// ----------------------------------------------------------------------------
+
+impl<I, T> Operator<I, T> for Replay<I, T>
+where
+    I: Id,
+    T: Value + Clone,
+{
+    type Item<'a> = Item<&'a I, Option<&'a T>>;
+
+    /// Handles the given item.
+    ///
+    /// Insertions and updates are written to the shared collection, deletions
+    /// remove the item from it, and all subscribers are notified of the change.
+    /// Deletions of unknown items are dropped, as no subscriber has seen them.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(level = "debug", skip_all, fields(id = %item.id))
+    )]
+    fn handle(&mut self, item: Self::Item<'_>) -> impl IntoOutputs<I> {
+        let mut state =
+            self.state.lock().unwrap_or_else(PoisonError::into_inner);
+        let changed = match item.data {
+            Some(data) => {
+                state.items.insert(item.id.clone(), data.clone());
+                true
+            }
+            None => state.items.remove(item.id).is_some(),
+        };
+
+        // Notify all subscribers of the change
+        if changed {
+            for subscriber in &mut state.subscribers {
+                subscriber(item.id, item.data);
+            }
+        }
+    }
+
+    /// Returns the descriptor.
+    #[inline]
+    fn descriptor(&self) -> Descriptor {
+        Descriptor::builder() // fmt
+            .property(Property::Flush)
+            .build()
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+impl<I, T> fmt::Debug for Replayed<I, T>
+where
+    I: fmt::Debug,
+    T: fmt::Debug,
+{
+    /// Formats the replayed stream for debugging.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let state = self.state.lock().unwrap_or_else(PoisonError::into_inner);
+        f.debug_struct("Replayed")
+            .field("items", &state.items)
+            .field("subscribers", &state.subscribers.len())
+            .finish()
+    }
+}
+
+// ----------------------------------------------------------------------------
+// Type aliases
+// ----------------------------------------------------------------------------
+
+/// Subscriber of a replayed stream.
+type Subscriber<I, T> = Box<dyn FnMut(&I, Option<&T>) + Send>;
+
+// ----------------------------------------------------------------------------
+// Tests
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+
+    mod subscribe {
+        use ahash::HashMap;
+        use std::sync::{Arc, Mutex};
+        use zrx_scheduler::effect::Item;
+
+        use crate::stream::operator::replay::{Replay, Replayed, State};
+        use crate::stream::operator::testing::{self, handle};
+        use crate::stream::workspace::Workspace;
+
+        /// Items received by a subscriber.
+        type Received = Arc<Mutex<Vec<(&'static str, Option<i32>)>>>;
+
+        /// Subscribes to the given stream, collecting all received items.
+        fn collect(replayed: &Replayed<&'static str, i32>) -> Received {
+            let items = Received::default();
+            replayed.subscribe({
+                let items = Arc::clone(&items);
+                move |id: &&'static str, data: Option<&i32>| {
+                    items.lock().unwrap().push((*id, data.copied()));
+                }
+            });
+            items
+        }
+
+        #[test]
+        fn replays_items_to_late_subscriber() {
+            let workspace = Workspace::<&str>::new();
+            let workflow = workspace.add_workflow();
+            let replayed = workflow.add_source::<i32>().replay();
+            testing::run(
+                workspace,
+                [("a", Some(1)), ("b", Some(2)), ("a", Some(3))],
+            );
+
+            // Ensure subscriber attached afterwards receives current items
+            let mut items = collect(&replayed).lock().unwrap().clone();
+            items.sort_unstable();
+            assert_eq!(items, [("a", Some(3)), ("b", Some(2))]);
+        }
+
+        #[test]
+        fn forwards_changes_after_replay() {
+            let state = Arc::new(Mutex::new(State {
+                items: HashMap::default(),
+                subscribers: Vec::new(),
+            }));
+            let mut replay = Replay { state: Arc::clone(&state) };
+            let replayed = Replayed { state };
+            handle(&mut replay, Item::new(&"a", Some(&1)));
+
+            // Ensure changes are forwarded after replayed items
+            let items = collect(&replayed);
+            handle(&mut replay, Item::new(&"b", Some(&2)));
+            handle(&mut replay, Item::new(&"a", None));
+            handle(&mut replay, Item::new(&"c", None));
+            assert_eq!(
+                *items.lock().unwrap(),
+                [("a", Some(1)), ("b", Some(2)), ("a", None)]
+            );
+        }
+    }
+}
@@ -82,6 +82,23 @@ where
             store: Indexed::with_order(move |a, b| f(a).cmp(&f(b))),
         })
     }
+
+    /// Sorts items by the key returned by the given function.
+    ///
+    /// In contrast to [`Stream::sort_by`], items with equal keys are ordered
+    /// by their values, so their positions don't depend on the order in which
+    /// they were received, which makes them deterministic across runs.
+    pub fn sort_by_key<F, K>(&self, f: F) -> Stream<I, Position<T>>
+    where
+        F: Fn(&T) -> K + 'static,
+        K: Ord,
+    {
+        self.with_operator(Sort {
+            store: Indexed::with_order(move |a, b| {
+                f(a).cmp(&f(b)).then_with(|| a.cmp(b))
+            }),
+        })
+    }
 }
 
 // ----------------------------------------------------------------------------
@@ -155,3 +172,46 @@ where
             .build()
     }
 }
+
+// ----------------------------------------------------------------------------
+// Tests
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+
+    mod sort_by_key {
+        use std::collections::BTreeMap;
+        use std::sync::{Arc, Mutex};
+
+        use crate::stream::operator::testing;
+        use crate::stream::value::Position;
+        use crate::stream::workspace::Workspace;
+
+        #[test]
+        fn handles_out_of_order_insertions() {
+            let workspace = Workspace::<&str>::new();
+            let workflow = workspace.add_workflow();
+            let positions = Arc::new(Mutex::new(BTreeMap::new()));
+            workflow
+                .add_source::<i32>()
+                .sort_by_key(|value: &i32| value.abs())
+                .for_each({
+                    let positions = Arc::clone(&positions);
+                    move |position: &Position<i32>| {
+                        let mut positions = positions.lock().unwrap();
+                        positions.insert(position.index, position.value);
+                    }
+                });
+
+            // Ensure positions reflect sorted order, with ties broken by value
+            testing::run(
+                workspace,
+                [("a", Some(30)), ("b", Some(10)), ("c", Some(-10))],
+            );
+            let positions = positions.lock().unwrap();
+            let values: Vec<_> = positions.values().copied().collect();
+            assert_eq!(values, [-10, 10, 30]);
+        }
+    }
+}
@@ -0,0 +1,165 @@
+// Copyright (c) Zensical LLC <https://zensical.org>
+
+// SPDX-License-Identifier: MIT
+// Third-party contributions licensed under CLA
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+
+// ----------------------------------------------------------------------------
+
+//! Start with operator.
+
+use ahash::HashSet;
+use zrx_scheduler::action::descriptor::Property;
+use zrx_scheduler::action::output::IntoOutputs;
+use zrx_scheduler::action::Descriptor;
+use zrx_scheduler::effect::Item;
+use zrx_scheduler::{Id, Value};
+
+use crate::stream::value::Delta;
+use crate::stream::Stream;
+
+use super::{Operator, OperatorExt};
+
+// ----------------------------------------------------------------------------
+// Structs
+// ----------------------------------------------------------------------------
+
+/// Start with operator.
+struct StartWith<I, T> {
+    /// Initial items.
+    items: Vec<(I, T)>,
+    /// Identifiers of seeded deltas.
+    seeded: HashSet<I>,
+}
+
+// ----------------------------------------------------------------------------
+// Implementations
+// ----------------------------------------------------------------------------
+
+impl<I, T> Stream<I, Delta<I, T>>
+where
+    I: Id,
+    T: Value + Clone,
+{
+    pub fn start_with<Iter>(&self, items: Iter) -> Stream<I, Delta<I, T>>
+    where
+        Iter: IntoIterator<Item = (I, T)>,
+    {
+        self.with_operator(StartWith {
+            items: items.into_iter().collect(),
+            seeded: HashSet::default(),
+        })
+    }
+}
+
+// ----------------------------------------------------------------------------
+// Trait implementations
+// ----------------------------------------------------------------------------
+
+impl<I, T> Operator<I, Delta<I, T>> for StartWith<I, T>
+where
+    I: Id,
+    T: Value + Clone,
+{
+    type Item<'a> = Item<&'a I, &'a Delta<I, T>>;
+
+    /// Handles the given item.
+    ///
+    /// The initial items are emitted as insertions exactly once per delta
+    /// identifier, prepended to its first upstream delta, so they're always
+    /// observed before any of the upstream items. Upstream items replace the
+    /// initial items with the same identifier, so that every identifier only
+    /// occurs once in a delta. All subsequent deltas are forwarded unchanged.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(level = "debug", skip_all, fields(id = %item.id))
+    )]
+    fn handle(&mut self, item: Self::Item<'_>) -> impl IntoOutputs<I> {
+        if !self.seeded.insert(item.id.clone()) {
+            return Item::new(item.id.clone(), Some(item.data.clone()));
+        }
+
+        // Drop initial items that are replaced by upstream items, and prepend
+        // the remaining ones to the upstream delta
+        let ids: HashSet<_> = item.data.iter().map(|part| &part.id).collect();
+        let delta = self
+            .items
+            .iter()
+            .filter(|(id, _)| !ids.contains(id))
+            .map(|(id, data)| Item::new(id.clone(), Some(data.clone())))
+            .chain(item.data.iter().cloned())
+            .collect();
+        Item::new(item.id.clone(), Some(delta))
+    }
+
+    /// Returns the descriptor.
+    #[inline]
+    fn descriptor(&self) -> Descriptor {
+        Descriptor::builder() // fmt
+            .property(Property::Flush)
+            .build()
+    }
+}
+
+// ----------------------------------------------------------------------------
+// Tests
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+
+    mod start_with {
+        use ahash::HashSet;
+        use zrx_scheduler::effect::Item;
+
+        use crate::stream::operator::start_with::StartWith;
+        use crate::stream::operator::testing::{deltas, handle};
+        use crate::stream::value::Delta;
+
+        #[test]
+        fn emits_seeds_before_upstream() {
+            let mut operator = StartWith {
+                items: vec![("a", 0), ("b", 0)],
+                seeded: HashSet::default(),
+            };
+
+            // Ensure seeds are prepended, unless replaced by upstream items
+            let delta = Delta::from([Item::new("a", Some(1))]);
+            let outputs = handle(&mut operator, Item::new(&"x", &delta));
+            assert_eq!(
+                deltas(outputs),
+                [("x", vec![("b", Some(0)), ("a", Some(1))])]
+            );
+
+            // Ensure subsequent deltas are forwarded unchanged
+            let delta = Delta::from([Item::new("b", None)]);
+            let outputs = handle(&mut operator, Item::new(&"x", &delta));
+            assert_eq!(deltas::<_, i32>(outputs), [("x", vec![("b", None)])]);
+
+            // Ensure seeds are prepended to the first delta of each identifier
+            let delta = Delta::from([Item::new("c", Some(2))]);
+            let outputs = handle(&mut operator, Item::new(&"y", &delta));
+            assert_eq!(
+                deltas(outputs),
+                [("y", vec![("a", Some(0)), ("b", Some(0)), ("c", Some(2))])]
+            );
+        }
+    }
+}
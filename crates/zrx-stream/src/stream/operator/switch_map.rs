@@ -0,0 +1,231 @@
+// Copyright (c) Zensical LLC <https://zensical.org>
+
+// SPDX-License-Identifier: MIT
+// Third-party contributions licensed under CLA
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+
+// ----------------------------------------------------------------------------
+
+//! Switch map operator.
+
+use ahash::HashSet;
+use std::mem;
+use zrx_scheduler::action::output::IntoOutputs;
+use zrx_scheduler::action::Descriptor;
+use zrx_scheduler::effect::Item;
+use zrx_scheduler::{Id, Value};
+
+use crate::stream::value::Delta;
+use crate::stream::Stream;
+
+use super::{Operator, OperatorExt};
+
+// ----------------------------------------------------------------------------
+// Structs
+// ----------------------------------------------------------------------------
+
+/// Switch map operator.
+struct SwitchMap<I, F> {
+    /// Operator function.
+    function: F,
+    /// Identifier of the item the inner items were created from.
+    source: Option<I>,
+    /// Identifiers of the inner items.
+    inner: Vec<I>,
+}
+
+// ----------------------------------------------------------------------------
+// Implementations
+// ----------------------------------------------------------------------------
+
+impl<I, T> Stream<I, T>
+where
+    I: Id,
+    T: Value + Clone,
+{
+    pub fn switch_map<U, F, S>(&self, f: F) -> Stream<I, Delta<I, U>>
+    where
+        U: Value,
+        F: Fn(&I, &T) -> S + 'static,
+        S: IntoIterator<Item = (I, U)>,
+    {
+        self.with_operator(SwitchMap {
+            function: f,
+            source: None,
+            inner: Vec::new(),
+        })
+    }
+}
+
+// ----------------------------------------------------------------------------
+// Trait implementations
+// ----------------------------------------------------------------------------
+
+impl<I, T, U, F, S> Operator<I, T> for SwitchMap<I, F>
+where
+    I: Id,
+    T: Value + Clone,
+    U: Value,
+    F: Fn(&I, &T) -> S,
+    S: IntoIterator<Item = (I, U)>,
+{
+    type Item<'a> = Item<&'a I, Option<&'a T>>;
+
+    /// Handles the given item.
+    ///
+    /// Each incoming item is mapped to a collection of inner items with the
+    /// operator function, which supersedes the inner items of the previous
+    /// item, so only the inner items of the latest item are retained. Like
+    /// with grouping, the inner items are emitted as a delta for the item they
+    /// were created from, which is why superseded inner items are removed with
+    /// a deletion for the prior item, followed by an insertion for the latest
+    /// item. If the latest item is deleted, its inner items are removed.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(level = "debug", skip_all, fields(id = %item.id))
+    )]
+    fn handle(&mut self, item: Self::Item<'_>) -> impl IntoOutputs<I> {
+        let mut outputs = Vec::new();
+        if let Some(data) = item.data {
+            let inner = (self.function)(item.id, data)
+                .into_iter()
+                .collect::<Vec<_>>();
+
+            // If the inner items were created from the same item, only remove
+            // those that are not part of the new inner items, but if they were
+            // created from another item, remove all of them from that item
+            let prior = mem::take(&mut self.inner);
+            let mut delta = Vec::new();
+            if self.source.as_ref() == Some(item.id) {
+                let keep =
+                    inner.iter().map(|(id, _)| id).collect::<HashSet<_>>();
+                delta.extend(
+                    prior
+                        .into_iter()
+                        .filter(|id| !keep.contains(id))
+                        .map(|id| Item::new(id, None)),
+                );
+            } else if let Some(source) = self.source.take() {
+                let removals = prior.into_iter().map(|id| Item::new(id, None));
+                outputs.push(Item::new(source, Some(removals.collect())));
+            }
+
+            // Remember inner items, and emit them as insertions
+            self.source = Some(item.id.clone());
+            self.inner = inner.iter().map(|(id, _)| id.clone()).collect();
+            delta.extend(
+                inner
+                    .into_iter()
+                    .map(|(id, data)| Item::new(id, Some(data))),
+            );
+            outputs.push(Item::new(
+                item.id.clone(),
+                Some(Delta::from_iter(delta)),
+            ));
+        } else if self.source.as_ref() == Some(item.id) {
+            // If the latest item is deleted, remove all of its inner items
+            self.source = None;
+            let removals = mem::take(&mut self.inner)
+                .into_iter()
+                .map(|id| Item::new(id, None));
+            outputs.push(Item::new(item.id.clone(), Some(removals.collect())));
+        }
+
+        // Return deltas of items
+        outputs
+    }
+
+    /// Returns the descriptor.
+    #[inline]
+    fn descriptor(&self) -> Descriptor {
+        Descriptor::default()
+    }
+}
+
+// ----------------------------------------------------------------------------
+// Tests
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+
+    mod switch_map {
+        use zrx_scheduler::effect::Item;
+
+        use crate::stream::operator::switch_map::SwitchMap;
+        use crate::stream::operator::testing;
+
+        type Outputs = Vec<(&'static str, Vec<(&'static str, Option<i32>)>)>;
+
+        fn handle<F>(
+            operator: &mut SwitchMap<&'static str, F>, id: &'static str,
+            data: Option<i32>,
+        ) -> Outputs
+        where
+            F: Fn(&&'static str, &i32) -> Vec<(&'static str, i32)>,
+        {
+            let item = Item::new(&id, data.as_ref());
+            testing::deltas(testing::handle(operator, item))
+        }
+
+        #[test]
+        fn removes_superseded_inner_items() {
+            let mut operator = SwitchMap {
+                function: |_: &&'static str, data: &i32| match data {
+                    1 => vec![("a", 1), ("b", 1)],
+                    2 => vec![("b", 2), ("c", 2)],
+                    _ => vec![("c", 3)],
+                },
+                source: None,
+                inner: Vec::new(),
+            };
+
+            // Ensure inner items of first item are inserted
+            assert_eq!(
+                handle(&mut operator, "x", Some(1)),
+                [("x", vec![("a", Some(1)), ("b", Some(1))])]
+            );
+
+            // Ensure inner items of prior item are removed
+            assert_eq!(
+                handle(&mut operator, "y", Some(2)),
+                [
+                    ("x", vec![("a", None), ("b", None)]),
+                    ("y", vec![("b", Some(2)), ("c", Some(2))]),
+                ]
+            );
+
+            // Ensure only stale inner items are removed on update
+            assert_eq!(
+                handle(&mut operator, "y", Some(3)),
+                [("y", vec![("b", None), ("c", Some(3))])]
+            );
+
+            // Ensure deletion of superseded item is ignored
+            assert_eq!(handle(&mut operator, "x", None), []);
+
+            // Ensure deletion of latest item removes its inner items
+            assert_eq!(
+                handle(&mut operator, "y", None),
+                [("y", vec![("c", None)])]
+            );
+        }
+    }
+}
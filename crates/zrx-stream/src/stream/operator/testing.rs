@@ -0,0 +1,144 @@
+// Copyright (c) Zensical LLC <https://zensical.org>
+
+// SPDX-License-Identifier: MIT
+// Third-party contributions licensed under CLA
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+
+// ----------------------------------------------------------------------------
+
+//! Operator test helpers.
+
+use std::any::Any;
+use std::cell::Cell;
+use std::time::{Duration, Instant};
+use zrx_scheduler::action::output::IntoOutputs;
+use zrx_scheduler::action::Output;
+use zrx_scheduler::effect::Item;
+use zrx_scheduler::{Id, Scheduler, Value};
+
+use crate::stream::value::Delta;
+use crate::stream::workspace::Workspace;
+
+use super::Operator;
+
+// ----------------------------------------------------------------------------
+// Statics
+// ----------------------------------------------------------------------------
+
+thread_local! {
+    /// Current instant of the test clock.
+    static NOW: Cell<Option<Instant>> = const { Cell::new(None) };
+}
+
+// ----------------------------------------------------------------------------
+// Functions
+// ----------------------------------------------------------------------------
+
+/// Handles the given item and returns the outputs of the operator.
+pub fn handle<I, T, O>(operator: &mut O, item: O::Item<'_>) -> Vec<Output<I>>
+where
+    O: Operator<I, T>,
+{
+    let report = operator.handle(item).into_outputs().unwrap();
+    report.data.into_iter().collect()
+}
+
+/// Returns the items of the given outputs, downcasting their data.
+pub fn items<I, T>(outputs: Vec<Output<I>>) -> Vec<(I, Option<T>)>
+where
+    T: Any,
+{
+    outputs
+        .into_iter()
+        .map(|output| match output {
+            Output::Item(Item { id, data }) => {
+                (id, data.map(|data| data.downcast().unwrap()))
+            }
+            _ => unreachable!(),
+        })
+        .collect()
+}
+
+/// Returns the deltas of the given outputs, flattening them into items.
+pub fn deltas<I, T>(outputs: Vec<Output<I>>) -> Vec<(I, Parts<I, T>)>
+where
+    I: 'static,
+    T: Any,
+{
+    items::<I, Delta<I, T>>(outputs)
+        .into_iter()
+        .map(|(id, delta)| {
+            let delta = delta.unwrap().into_iter();
+            (id, delta.map(|item| (item.id, item.data)).collect())
+        })
+        .collect()
+}
+
+/// Runs the given workspace with the given items until all were processed.
+///
+/// All items are submitted to a single session, which is dropped right away,
+/// so the scheduler completes once all items, tasks and timers were handled.
+pub fn run<I, T, S>(workspace: Workspace<I>, items: S)
+where
+    I: Id,
+    T: Value,
+    S: IntoIterator<Item = (I, Option<T>)>,
+{
+    let mut scheduler = Scheduler::new(workspace.into_builder().build());
+    let session = scheduler.session::<T>().unwrap();
+    for (id, data) in items {
+        match data {
+            Some(data) => session.insert(id, data).unwrap(),
+            None => session.remove(id).unwrap(),
+        }
+    }
+    drop(session);
+
+    // Run the scheduler until all items were processed
+    let deadline = Instant::now() + Duration::from_secs(10);
+    while !scheduler.is_empty() {
+        assert!(Instant::now() < deadline, "scheduler timed out");
+        scheduler.tick_timeout(Duration::from_millis(10));
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+/// Returns the current instant of the test clock.
+pub fn now() -> Instant {
+    NOW.with(|now| {
+        let instant = now.get().unwrap_or_else(Instant::now);
+        now.set(Some(instant));
+        instant
+    })
+}
+
+/// Advances the test clock by the given number of milliseconds.
+pub fn advance(millis: u64) {
+    let next = now() + Duration::from_millis(millis);
+    NOW.with(|now| now.set(Some(next)));
+}
+
+// ----------------------------------------------------------------------------
+// Type aliases
+// ----------------------------------------------------------------------------
+
+/// Identifiers and data of the items of a delta.
+pub type Parts<I, T> = Vec<(I, Option<T>)>;
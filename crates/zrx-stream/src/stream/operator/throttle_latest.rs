@@ -0,0 +1,206 @@
+// Copyright (c) Zensical LLC <https://zensical.org>
+
+// SPDX-License-Identifier: MIT
+// Third-party contributions licensed under CLA
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+
+// ----------------------------------------------------------------------------
+
+//! Throttle latest operator.
+
+use ahash::HashMap;
+use std::time::{Duration, Instant};
+use zrx_scheduler::action::descriptor::Property;
+use zrx_scheduler::action::output::IntoOutputs;
+use zrx_scheduler::action::Descriptor;
+use zrx_scheduler::effect::timer::IntoDuration;
+use zrx_scheduler::effect::{Item, Timer};
+use zrx_scheduler::{outputs, Id, Value};
+
+use crate::stream::Stream;
+
+use super::{Operator, OperatorExt};
+
+// ----------------------------------------------------------------------------
+// Structs
+// ----------------------------------------------------------------------------
+
+/// Throttle latest operator.
+struct ThrottleLatest<I> {
+    /// Window duration.
+    duration: Duration,
+    /// Window deadlines by identifier.
+    windows: HashMap<I, Instant>,
+    /// Clock.
+    clock: fn() -> Instant,
+}
+
+// ----------------------------------------------------------------------------
+// Implementations
+// ----------------------------------------------------------------------------
+
+impl<I, T> Stream<I, T>
+where
+    I: Id,
+    T: Value + Clone,
+{
+    pub fn throttle_latest<D>(&self, window: D) -> Stream<I, T>
+    where
+        D: IntoDuration,
+    {
+        self.with_operator(ThrottleLatest {
+            duration: window.into_duration(),
+            windows: HashMap::default(),
+            clock: Instant::now,
+        })
+    }
+}
+
+// ----------------------------------------------------------------------------
+// Trait implementations
+// ----------------------------------------------------------------------------
+
+impl<I, T> Operator<I, T> for ThrottleLatest<I>
+where
+    I: Id,
+    T: Value + Clone,
+{
+    type Item<'a> = Item<&'a I, Option<&'a T>>;
+
+    /// Handles the given item.
+    ///
+    /// The first item for an identifier opens a window, and all items for the
+    /// same identifier that arrive within the window replace the outputs of the
+    /// timer, but keep its deadline, so only the latest item is emitted once
+    /// the window ends. Deletions are emitted immediately, clearing the timer,
+    /// as emitting a stale item after a deletion would resurrect it.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(level = "debug", skip_all, fields(id = %item.id))
+    )]
+    fn handle(&mut self, item: Self::Item<'_>) -> impl IntoOutputs<I> {
+        if item.data.is_some() {
+            let now = (self.clock)();
+            let deadline = self
+                .windows
+                .entry(item.id.clone())
+                .and_modify(|deadline| {
+                    if *deadline <= now {
+                        *deadline = now + self.duration;
+                    }
+                })
+                .or_insert(now + self.duration);
+
+            // Set timer, or replace the outputs of the active timer
+            outputs![Timer::set(*deadline, Some(outputs![item.into_owned()]))]
+        } else {
+            self.windows.remove(item.id);
+            outputs![Timer::clear(), Item::new(item.id.clone(), None::<T>)]
+        }
+    }
+
+    /// Returns the descriptor.
+    #[inline]
+    fn descriptor(&self) -> Descriptor {
+        Descriptor::builder()
+            .property(Property::Stable)
+            .property(Property::Flush)
+            .build()
+    }
+}
+
+// ----------------------------------------------------------------------------
+// Tests
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+
+    mod throttle_latest {
+        use ahash::HashMap;
+        use std::time::Duration;
+        use zrx_scheduler::action::Output;
+        use zrx_scheduler::effect::{Item, Timer};
+
+        use crate::stream::operator::testing::{advance, handle, items, now};
+        use crate::stream::operator::throttle_latest::ThrottleLatest;
+
+        #[test]
+        fn collapses_updates_to_latest_at_window_end() {
+            let mut throttle = ThrottleLatest {
+                duration: Duration::from_millis(100),
+                windows: HashMap::default(),
+                clock: now,
+            };
+            let start = now();
+
+            // Merge timers like the scheduler does, keeping the deadline of the
+            // first timer, but replacing its outputs with the latest ones
+            let mut timer = None;
+            for (data, millis) in [(1, 0), (2, 30), (3, 60)] {
+                advance(millis);
+                let item = Item::new(&"a", Some(&data));
+                for output in handle::<_, i32, _>(&mut throttle, item) {
+                    let Output::Timer(Timer::Set { deadline, data }) = output
+                    else {
+                        unreachable!()
+                    };
+                    assert_eq!(deadline, start + Duration::from_millis(100));
+                    timer = Some((deadline, data));
+                }
+            }
+
+            // Ensure only the latest item is emitted at window end
+            let (_, data) = timer.unwrap();
+            let outputs = data.unwrap().into_iter().collect();
+            assert_eq!(items(outputs), [("a", Some(3))]);
+
+            // Ensure a new window is opened after the window ended
+            advance(100);
+            let item = Item::new(&"a", Some(&4));
+            let outputs = handle::<_, i32, _>(&mut throttle, item);
+            assert!(matches!(
+                outputs[..],
+                [Output::Timer(Timer::Set { deadline, .. })]
+                    if deadline == start + Duration::from_millis(290)
+            ));
+        }
+
+        #[test]
+        fn flushes_deletions_immediately() {
+            let mut throttle = ThrottleLatest {
+                duration: Duration::from_millis(100),
+                windows: HashMap::default(),
+                clock: now,
+            };
+            handle::<_, i32, _>(&mut throttle, Item::new(&"a", Some(&1)));
+            let item = Item::new(&"a", None);
+            let outputs = handle::<_, i32, _>(&mut throttle, item);
+            assert!(matches!(
+                outputs[..],
+                [
+                    Output::Timer(Timer::Clear),
+                    Output::Item(Item { id: "a", data: None })
+                ]
+            ));
+            assert!(throttle.windows.is_empty());
+        }
+    }
+}
@@ -0,0 +1,264 @@
+// Copyright (c) Zensical LLC <https://zensical.org>
+
+// SPDX-License-Identifier: MIT
+// Third-party contributions licensed under CLA
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+
+// ----------------------------------------------------------------------------
+
+//! Tumbling window operator.
+
+use std::collections::BTreeMap;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use zrx_scheduler::action::descriptor::Property;
+use zrx_scheduler::action::output::IntoOutputs;
+use zrx_scheduler::action::Descriptor;
+use zrx_scheduler::effect::timer::IntoDuration;
+use zrx_scheduler::effect::{Item, Task, Timer};
+use zrx_scheduler::{outputs, Id, Value};
+
+use crate::stream::value::Chunk;
+use crate::stream::Stream;
+
+use super::{Operator, OperatorExt};
+
+// ----------------------------------------------------------------------------
+// Structs
+// ----------------------------------------------------------------------------
+
+/// Tumbling window operator.
+struct TumblingWindow<I, T> {
+    /// Identifier.
+    id: I,
+    /// Window duration.
+    duration: Duration,
+    /// Items buffered in the current window, if any.
+    window: Arc<Mutex<Option<BTreeMap<I, T>>>>,
+    /// Clock.
+    clock: fn() -> Instant,
+}
+
+// ----------------------------------------------------------------------------
+// Implementations
+// ----------------------------------------------------------------------------
+
+impl<I, T> Stream<I, T>
+where
+    I: Id,
+    T: Value + Clone,
+{
+    pub fn tumbling_window<D>(&self, id: I, window: D) -> Stream<I, Chunk<I, T>>
+    where
+        D: IntoDuration,
+    {
+        self.with_operator(TumblingWindow {
+            id,
+            duration: window.into_duration(),
+            window: Arc::default(),
+            clock: Instant::now,
+        })
+    }
+}
+
+// ----------------------------------------------------------------------------
+// Trait implementations
+// ----------------------------------------------------------------------------
+
+impl<I, T> Operator<I, T> for TumblingWindow<I, T>
+where
+    I: Id,
+    T: Value + Clone,
+{
+    type Item<'a> = Item<&'a I, Option<&'a T>>;
+
+    /// Handles the given item.
+    ///
+    /// The first item after a window ended opens a new window, and sets a
+    /// single timer for it, which flushes the window once it ends. All items
+    /// that arrive within the window are buffered, with deletions removing
+    /// items from the buffer, so the buffered items are emitted exactly once
+    /// as a chunk for the identifier of the operator. Since the buffer is
+    /// shared with the timer, it's only converted into a chunk when flushed.
+    /// Deletions outside of a window are ignored.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(level = "debug", skip_all, fields(id = %item.id))
+    )]
+    fn handle(&mut self, item: Self::Item<'_>) -> impl IntoOutputs<I> {
+        let mut window = self.window.lock().expect("invariant");
+
+        // Update buffer of current window, if any
+        if let Some(items) = window.as_mut() {
+            if let Some(data) = item.data {
+                items.insert(item.id.clone(), data.clone());
+            } else {
+                items.remove(item.id);
+            }
+            return None;
+        }
+
+        // Open a new window, unless the item is a deletion
+        let data = item.data?;
+        *window = Some(BTreeMap::from([(item.id.clone(), data.clone())]));
+
+        // Set timer that takes the buffer and emits it as a chunk, so the
+        // next item opens a new window
+        let task = Task::new({
+            let window = Arc::clone(&self.window);
+            let id = self.id.clone();
+            move || {
+                let items = window.lock().expect("invariant").take();
+                let chunk: Chunk<I, T> = items
+                    .into_iter()
+                    .flatten()
+                    .map(|(id, data)| Item::new(id, data))
+                    .collect();
+                Item::new(id, Some(chunk))
+            }
+        });
+        let deadline = (self.clock)() + self.duration;
+        Some(Timer::set(deadline, Some(outputs![task])))
+    }
+
+    /// Returns the descriptor.
+    #[inline]
+    fn descriptor(&self) -> Descriptor {
+        Descriptor::builder() // fmt
+            .property(Property::Flush)
+            .build()
+    }
+}
+
+// ----------------------------------------------------------------------------
+// Tests
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+
+    mod tumbling_window {
+        use std::sync::{Arc, Mutex};
+        use std::time::Duration;
+        use zrx_scheduler::action::Output;
+        use zrx_scheduler::effect::{Item, Timer};
+
+        use crate::stream::operator::testing::{self, advance, items, now};
+        use crate::stream::operator::tumbling_window::TumblingWindow;
+        use crate::stream::value::Chunk;
+        use crate::stream::workspace::Workspace;
+
+        type Window = Vec<(&'static str, Vec<(&'static str, i32)>)>;
+
+        fn handle(
+            window: &mut TumblingWindow<&'static str, i32>, id: &'static str,
+            data: Option<i32>,
+        ) -> Vec<Output<&'static str>> {
+            testing::handle(window, Item::new(&id, data.as_ref()))
+        }
+
+        fn flush(outputs: Vec<Output<&'static str>>) -> Window {
+            let [Output::Timer(Timer::Set { data: Some(data), .. })] =
+                &mut outputs.into_iter().collect::<Vec<_>>()[..]
+            else {
+                unreachable!()
+            };
+            let outputs = std::mem::take(data).into_iter().map(|output| {
+                let Output::Task(task) = output else {
+                    unreachable!()
+                };
+                task.execute().unwrap().data
+            });
+            items::<_, Chunk<&str, i32>>(outputs.flatten().collect())
+                .into_iter()
+                .map(|(id, chunk)| {
+                    let iter = chunk.unwrap().into_iter();
+                    (id, iter.map(|item| (item.id, item.data)).collect())
+                })
+                .collect()
+        }
+
+        #[test]
+        fn emits_once_per_window() {
+            let mut window = TumblingWindow {
+                id: "window",
+                duration: Duration::from_millis(100),
+                window: Arc::default(),
+                clock: now,
+            };
+            let start = now();
+
+            // Ensure only the first item of a window sets a timer
+            let timer = handle(&mut window, "a", Some(1));
+            assert!(matches!(
+                timer[..],
+                [Output::Timer(Timer::Set { deadline, .. })]
+                    if deadline == start + Duration::from_millis(100)
+            ));
+            for (id, data, millis) in [
+                ("b", Some(2), 30),
+                ("a", Some(3), 30),
+                ("c", Some(4), 30),
+                ("c", None, 0),
+            ] {
+                advance(millis);
+                assert!(handle(&mut window, id, data).is_empty());
+            }
+
+            // Ensure the window is emitted with buffered contents when flushed
+            assert_eq!(flush(timer), [("window", vec![("a", 3), ("b", 2)])]);
+
+            // Ensure deletions outside of a window are ignored
+            advance(20);
+            assert!(handle(&mut window, "a", None).is_empty());
+
+            // Ensure the next item opens a new window
+            let timer = handle(&mut window, "b", Some(5));
+            advance(50);
+            assert!(handle(&mut window, "a", Some(6)).is_empty());
+            assert_eq!(flush(timer), [("window", vec![("a", 6), ("b", 5)])]);
+        }
+
+        #[test]
+        fn emits_once_per_window_with_scheduler() {
+            let workspace = Workspace::<&str>::new();
+            let workflow = workspace.add_workflow();
+            let windows = Arc::new(Mutex::new(Vec::new()));
+            workflow
+                .add_source::<i32>()
+                .tumbling_window("window", Duration::from_millis(50))
+                .for_each({
+                    let windows = Arc::clone(&windows);
+                    move |chunk: &Chunk<&'static str, i32>| {
+                        let iter =
+                            chunk.iter().map(|item| (item.id, item.data));
+                        windows.lock().unwrap().push(iter.collect::<Vec<_>>());
+                    }
+                });
+
+            // Ensure items submitted at once are emitted as a single window
+            testing::run(
+                workspace,
+                [("a", Some(1)), ("b", Some(2)), ("a", Some(3))],
+            );
+            assert_eq!(*windows.lock().unwrap(), [vec![("a", 3), ("b", 2)]]);
+        }
+    }
+}
@@ -0,0 +1,224 @@
+// Copyright (c) Zensical LLC <https://zensical.org>
+
+// SPDX-License-Identifier: MIT
+// Third-party contributions licensed under CLA
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+
+// ----------------------------------------------------------------------------
+
+//! With latest from operator.
+
+use ahash::{HashMap, HashSet};
+use std::marker::PhantomData;
+use zrx_scheduler::action::output::IntoOutputs;
+use zrx_scheduler::action::Descriptor;
+use zrx_scheduler::effect::Item;
+use zrx_scheduler::{Id, Value};
+
+use crate::stream::value::Delta;
+use crate::stream::Stream;
+
+use super::Operator;
+
+// ----------------------------------------------------------------------------
+// Structs
+// ----------------------------------------------------------------------------
+
+/// With latest from operator.
+struct WithLatestFrom<I, T, U> {
+    /// Latest values of the other stream.
+    latest: HashMap<I, U>,
+    /// Identifiers of emitted items.
+    present: HashSet<I>,
+    /// Type marker.
+    marker: PhantomData<T>,
+}
+
+// ----------------------------------------------------------------------------
+// Implementations
+// ----------------------------------------------------------------------------
+
+impl<I, T> Stream<I, T>
+where
+    I: Id,
+    T: Value + Clone,
+{
+    pub fn with_latest_from<U>(
+        &self, other: &Stream<I, Delta<I, U>>,
+    ) -> Stream<I, (T, Option<U>)>
+    where
+        U: Value + Clone,
+    {
+        self.workflow.add_operator(
+            [self.id, other.id],
+            WithLatestFrom::<I, T, U> {
+                latest: HashMap::default(),
+                present: HashSet::default(),
+                marker: PhantomData,
+            },
+        )
+    }
+}
+
+// ----------------------------------------------------------------------------
+// Trait implementations
+// ----------------------------------------------------------------------------
+
+impl<I, T, U> Operator<I, T> for WithLatestFrom<I, T, U>
+where
+    I: Id,
+    T: Value + Clone,
+    U: Value + Clone,
+{
+    type Item<'a> = Item<&'a I, (Option<&'a T>, Option<&'a Delta<I, U>>)>;
+
+    /// Handles the given item.
+    ///
+    /// Deltas of the other stream are applied to a cache that keeps the latest
+    /// value for each identifier, but don't emit anything on their own. Items
+    /// of this stream are emitted together with the cached value for their
+    /// identifier, if any, so changes of the other stream are only reflected
+    /// once the item is emitted again. Deletions of emitted items are always
+    /// forwarded, regardless of whether the other stream changed as well.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(level = "debug", skip_all, fields(id = %item.id))
+    )]
+    fn handle(&mut self, item: Self::Item<'_>) -> impl IntoOutputs<I> {
+        let (data, delta) = item.data;
+        if let Some(delta) = delta {
+            for inner in delta {
+                if let Some(value) = &inner.data {
+                    self.latest.insert(inner.id.clone(), value.clone());
+                } else {
+                    self.latest.remove(&inner.id);
+                }
+            }
+        }
+
+        // Attach the latest value of the other stream to the item, or forward
+        // the deletion, if the item was emitted before
+        if let Some(data) = data {
+            let latest = self.latest.get(item.id).cloned();
+            self.present.insert(item.id.clone());
+            Some(Item::new(item.id.clone(), Some((data.clone(), latest))))
+        } else if self.present.remove(item.id) {
+            Some(Item::new(item.id.clone(), None))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the descriptor.
+    #[inline]
+    fn descriptor(&self) -> Descriptor {
+        Descriptor::default()
+    }
+}
+
+// ----------------------------------------------------------------------------
+// Tests
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+
+    mod with_latest_from {
+        use ahash::{HashMap, HashSet};
+        use std::marker::PhantomData;
+        use zrx_scheduler::effect::Item;
+
+        use crate::stream::operator::testing;
+        use crate::stream::operator::with_latest_from::WithLatestFrom;
+        use crate::stream::value::Delta;
+
+        type Outputs = Vec<(&'static str, Option<(i32, Option<i32>)>)>;
+
+        fn handle(
+            operator: &mut WithLatestFrom<&'static str, i32, i32>,
+            id: &'static str, data: Option<i32>,
+            delta: Option<&Delta<&'static str, i32>>,
+        ) -> Outputs {
+            let item = Item::new(&id, (data.as_ref(), delta));
+            testing::items(testing::handle(operator, item))
+        }
+
+        #[test]
+        fn attaches_latest_value() {
+            let mut operator = WithLatestFrom {
+                latest: HashMap::default(),
+                present: HashSet::default(),
+                marker: PhantomData,
+            };
+            assert_eq!(
+                handle(&mut operator, "a", Some(1), None),
+                [("a", Some((1, None)))]
+            );
+
+            // Ensure updates of the other stream don't emit
+            let delta = Delta::from([
+                Item::new("a", Some(10)),
+                Item::new("b", Some(20)),
+            ]);
+            assert_eq!(handle(&mut operator, "x", None, Some(&delta)), []);
+
+            // Ensure next item reflects updates of the other stream
+            assert_eq!(
+                handle(&mut operator, "a", Some(2), None),
+                [("a", Some((2, Some(10))))]
+            );
+
+            // Ensure deletions of the other stream are reflected
+            let delta = Delta::from([Item::new("a", None)]);
+            assert_eq!(handle(&mut operator, "x", None, Some(&delta)), []);
+            assert_eq!(
+                handle(&mut operator, "a", Some(3), None),
+                [("a", Some((3, None)))]
+            );
+
+            // Ensure deletions of items are forwarded
+            assert_eq!(handle(&mut operator, "a", None, None), [("a", None)]);
+        }
+
+        #[test]
+        fn forwards_deletion_with_other_stream() {
+            let mut operator = WithLatestFrom {
+                latest: HashMap::default(),
+                present: HashSet::default(),
+                marker: PhantomData,
+            };
+            assert_eq!(
+                handle(&mut operator, "a", Some(1), None),
+                [("a", Some((1, None)))]
+            );
+
+            // Ensure deletions are forwarded if the other stream changed
+            let delta = Delta::from([Item::new("a", Some(10))]);
+            assert_eq!(
+                handle(&mut operator, "a", None, Some(&delta)),
+                [("a", None)]
+            );
+
+            // Ensure deletions of items never emitted are dropped
+            assert_eq!(handle(&mut operator, "a", None, Some(&delta)), []);
+            assert_eq!(handle(&mut operator, "b", None, None), []);
+        }
+    }
+}
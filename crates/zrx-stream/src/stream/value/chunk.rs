@@ -28,6 +28,7 @@
 use std::slice::Iter;
 use zrx_scheduler::effect::Item;
 use zrx_scheduler::{Id, Value};
+use zrx_store::{Key, StoreIterable};
 
 // ----------------------------------------------------------------------------
 // Type aliases
@@ -71,6 +72,46 @@ pub struct Chunk<I, T> {
 // ----------------------------------------------------------------------------
 
 impl<I, T> Chunk<I, T> {
+    /// Creates a chunk of items from the contents of a store.
+    ///
+    /// This allows to feed non-reactive state into the stream machinery, e.g.,
+    /// to seed a stream with a snapshot. Items are cloned from the store, and
+    /// are ordered in the iteration order of the store, so the order is only
+    /// deterministic if the store is ordered. As keys of a store are unique,
+    /// the resulting chunk upholds the invariant of unique items.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use zrx_scheduler::effect::Item;
+    /// use zrx_store::StoreMut;
+    /// use zrx_stream::value::Chunk;
+    ///
+    /// // Create store and initial state
+    /// let mut store = HashMap::new();
+    /// store.insert("a", 1);
+    /// store.insert("b", 2);
+    ///
+    /// // Create chunk of items from store
+    /// let chunk = Chunk::from_store(&store);
+    /// let mut items = chunk.into_iter().collect::<Vec<_>>();
+    /// items.sort_by_key(|item| item.id);
+    /// assert_eq!(items, [Item::new("a", 1), Item::new("b", 2)]);
+    /// ```
+    #[must_use]
+    pub fn from_store<S>(store: &S) -> Self
+    where
+        I: Key,
+        T: Clone,
+        S: StoreIterable<I, T>,
+    {
+        store
+            .iter()
+            .map(|(id, data)| Item::new(id.clone(), data.clone()))
+            .collect()
+    }
+
     /// Creates an iterator over the chunk of items.
     ///
     /// # Examples
@@ -28,7 +28,7 @@
 use std::any::Any;
 use std::fmt::Debug;
 use zrx_scheduler::{Id, Value};
-use zrx_store::{Store, StoreIterable, StoreKeys, StoreValues};
+use zrx_store::{Store, StoreCapacity, StoreIterable, StoreKeys, StoreValues};
 
 // ----------------------------------------------------------------------------
 // Traits
@@ -47,6 +47,12 @@ use zrx_store::{Store, StoreIterable, StoreKeys, StoreValues};
 /// of [`Value`] on this trait, and for the blanket implementation. In case more
 /// supertraits are added to [`Value`] in the future, we must add them here as
 /// well, which is however unlikely to happen.
+///
+/// Collections also expose the capacity controls of [`StoreCapacity`], so
+/// operators holding large collections can release memory after bursts. Thus,
+/// only stores with actual capacity, like [`HashMap`][], are collections.
+///
+/// [`HashMap`]: std::collections::HashMap
 pub trait Collection<I, T>: Any + Debug + Send {
     /// Returns a reference to the value identified by the key.
     fn get(&self, id: &I) -> Option<&T>;
@@ -68,6 +74,42 @@ pub trait Collection<I, T>: Any + Debug + Send {
 
     /// Creates a value iterator over the store.
     fn values(&self) -> Values<'_, T>;
+
+    /// Returns the number of items the collection can hold without
+    /// reallocating.
+    fn capacity(&self) -> usize;
+
+    /// Reserves capacity for at least the given number of additional items.
+    fn reserve(&mut self, additional: usize);
+
+    /// Shrinks the capacity of the collection as much as possible.
+    ///
+    /// Operators holding large collections should call this method after
+    /// bursts of removals, in order to release memory during long runs.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ahash::HashMap;
+    /// use zrx_store::StoreMut;
+    /// use zrx_stream::value::Collection;
+    ///
+    /// // Create collection and initial state
+    /// let mut store = HashMap::default();
+    /// for n in 0..1024 {
+    ///     store.insert(n, n);
+    /// }
+    ///
+    /// // Remove most items and release memory
+    /// let capacity = Collection::capacity(&store);
+    /// for n in 0..1000 {
+    ///     store.remove(&n);
+    /// }
+    /// let collection: &mut dyn Collection<i32, i32> = &mut store;
+    /// collection.shrink_to_fit();
+    /// assert!(collection.capacity() < capacity);
+    /// ```
+    fn shrink_to_fit(&mut self);
 }
 
 // ----------------------------------------------------------------------------
@@ -91,6 +133,7 @@ where
     T: Value,
     S: Any + Debug + Send,
     S: Store<I, T> // fmt
+        + StoreCapacity<I, T>
         + StoreIterable<I, T>
         + StoreKeys<I, T>
         + StoreValues<I, T>,
@@ -136,6 +179,25 @@ where
     fn values(&self) -> Values<'_, T> {
         Box::new(StoreValues::values(self))
     }
+
+    /// Returns the number of items the collection can hold without
+    /// reallocating.
+    #[inline]
+    fn capacity(&self) -> usize {
+        StoreCapacity::capacity(self)
+    }
+
+    /// Reserves capacity for at least the given number of additional items.
+    #[inline]
+    fn reserve(&mut self, additional: usize) {
+        StoreCapacity::reserve(self, additional);
+    }
+
+    /// Shrinks the capacity of the collection as much as possible.
+    #[inline]
+    fn shrink_to_fit(&mut self) {
+        StoreCapacity::shrink_to_fit(self);
+    }
 }
 
 // ----------------------------------------------------------------------------
@@ -30,6 +30,10 @@ use std::vec::IntoIter;
 use zrx_scheduler::effect::Item;
 use zrx_scheduler::{Id, Value};
 
+mod batch;
+
+pub use batch::DeltaBatch;
+
 // ----------------------------------------------------------------------------
 // Structs
 // ----------------------------------------------------------------------------
@@ -48,6 +52,11 @@ use zrx_scheduler::{Id, Value};
 /// still hold if this invariant is violated, but performance might be impacted
 /// due to unnecessary re-computations.
 ///
+/// Items in a delta are applied in order. Operators that emit multiple changes
+/// per step should assemble them with a [`DeltaBatch`], which guarantees that
+/// deletions precede insertions, and that each identifier occurs only once, so
+/// the outcome doesn't depend on the order in which the changes were produced.
+///
 /// # Examples
 ///
 /// ```
@@ -0,0 +1,313 @@
+// Copyright (c) Zensical LLC <https://zensical.org>
+
+// SPDX-License-Identifier: MIT
+// Third-party contributions licensed under CLA
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+
+// ----------------------------------------------------------------------------
+
+//! Batch of deltas.
+
+use ahash::HashMap;
+use std::hash::Hash;
+use zrx_scheduler::effect::Item;
+
+use super::Delta;
+
+// ----------------------------------------------------------------------------
+// Structs
+// ----------------------------------------------------------------------------
+
+/// Batch of deltas.
+///
+/// Operators that emit multiple changes per step might produce more than one
+/// change for the same identifier, e.g., a deletion and an insertion, and the
+/// order in which they're produced might depend on iteration order. A batch
+/// collects those changes, and resolves them into a single change for each
+/// identifier when converted, enforcing the ordering contract of [`Delta`]:
+///
+/// - If an identifier is both deleted and inserted, the insertion wins, so
+///   the final state doesn't depend on the order of deletion and insertion.
+/// - If an identifier is inserted multiple times, the last insertion wins, as
+///   it's the one that reflects the latest value of the item.
+/// - Deletions come before insertions, and each group preserves the order in
+///   which the identifiers were first added to the batch.
+///
+/// # Examples
+///
+/// ```
+/// use zrx_scheduler::effect::Item;
+/// use zrx_stream::value::delta::DeltaBatch;
+/// use zrx_stream::value::Delta;
+///
+/// // Create batch of deltas
+/// let mut batch = DeltaBatch::new();
+/// batch.push(Item::new("a", Some(1)));
+/// batch.push(Item::new("b", None));
+/// batch.push(Item::new("a", None));
+/// batch.push(Item::new("b", Some(2)));
+///
+/// // Convert batch into delta
+/// let delta = Delta::from(batch);
+/// let items: Vec<_> = delta.into_iter().collect();
+/// assert_eq!(items, [Item::new("a", Some(1)), Item::new("b", Some(2))]);
+/// ```
+#[derive(Clone, Debug)]
+pub struct DeltaBatch<I, T> {
+    /// Vector of items.
+    inner: Vec<Item<I, Option<T>>>,
+}
+
+// ----------------------------------------------------------------------------
+// Implementations
+// ----------------------------------------------------------------------------
+
+impl<I, T> DeltaBatch<I, T> {
+    /// Creates an empty batch of deltas.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zrx_stream::value::delta::DeltaBatch;
+    ///
+    /// // Create batch of deltas
+    /// let batch = DeltaBatch::new();
+    /// # let _: DeltaBatch<(), ()> = batch;
+    /// ```
+    #[must_use]
+    pub fn new() -> Self {
+        Self { inner: Vec::new() }
+    }
+
+    /// Adds an item to the batch of deltas.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zrx_scheduler::effect::Item;
+    /// use zrx_stream::value::delta::DeltaBatch;
+    ///
+    /// // Create batch of deltas and add item
+    /// let mut batch = DeltaBatch::new();
+    /// batch.push(Item::new("a", Some(1)));
+    /// ```
+    #[inline]
+    pub fn push<U>(&mut self, item: U)
+    where
+        U: Into<Item<I, Option<T>>>,
+    {
+        self.inner.push(item.into());
+    }
+}
+
+#[allow(clippy::must_use_candidate)]
+impl<I, T> DeltaBatch<I, T> {
+    /// Returns the number of items in the batch, including duplicates.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    /// Returns whether the batch is empty.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+}
+
+// ----------------------------------------------------------------------------
+// Trait implementations
+// ----------------------------------------------------------------------------
+
+impl<I, T> From<DeltaBatch<I, T>> for Delta<I, T>
+where
+    I: Clone + Eq + Hash,
+{
+    /// Creates a delta of items from a batch of deltas.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zrx_scheduler::effect::Item;
+    /// use zrx_stream::value::delta::DeltaBatch;
+    /// use zrx_stream::value::Delta;
+    ///
+    /// // Create batch of deltas
+    /// let batch = DeltaBatch::from_iter([
+    ///     Item::new("a", Some(1)),
+    ///     Item::new("a", None),
+    /// ]);
+    ///
+    /// // Convert batch into delta
+    /// let delta = Delta::from(batch);
+    /// ```
+    fn from(batch: DeltaBatch<I, T>) -> Self {
+        let mut positions = HashMap::default();
+        let mut items: Vec<Item<I, Option<T>>> = Vec::new();
+
+        // Replace prior items with the same identifier by the latest item,
+        // unless it's a deletion, as insertions win over deletions, but keep
+        // the position of the first occurrence of the identifier
+        for item in batch.inner {
+            if let Some(&index) = positions.get(&item.id) {
+                if item.data.is_some() {
+                    items[index] = item;
+                }
+            } else {
+                positions.insert(item.id.clone(), items.len());
+                items.push(item);
+            }
+        }
+
+        // Ensure all deletions precede insertions, preserving relative order
+        let (deletions, insertions): (Vec<_>, Vec<_>) =
+            items.into_iter().partition(|item| item.data.is_none());
+        deletions.into_iter().chain(insertions).collect()
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+impl<I, T, U> FromIterator<U> for DeltaBatch<I, T>
+where
+    U: Into<Item<I, Option<T>>>,
+{
+    /// Creates a batch of deltas from an iterator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zrx_scheduler::effect::Item;
+    /// use zrx_stream::value::delta::DeltaBatch;
+    ///
+    /// // Create batch of deltas
+    /// let batch = DeltaBatch::from_iter([
+    ///     Item::new("a", Some(1)),
+    ///     Item::new("b", None),
+    /// ]);
+    /// ```
+    #[inline]
+    fn from_iter<V>(iter: V) -> Self
+    where
+        V: IntoIterator<Item = U>,
+    {
+        Self {
+            inner: iter.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+impl<I, T> Default for DeltaBatch<I, T> {
+    /// Creates an empty batch of deltas.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zrx_stream::value::delta::DeltaBatch;
+    ///
+    /// // Create empty batch of deltas
+    /// let batch = DeltaBatch::default();
+    /// # let _: DeltaBatch<(), ()> = batch;
+    /// ```
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// ----------------------------------------------------------------------------
+// Tests
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+
+    mod from {
+        use std::collections::BTreeMap;
+        use zrx_scheduler::effect::Item;
+
+        use crate::stream::value::delta::DeltaBatch;
+        use crate::stream::value::Delta;
+
+        fn apply(
+            mut state: BTreeMap<&'static str, i32>,
+            items: Vec<Item<&'static str, Option<i32>>>,
+        ) -> BTreeMap<&'static str, i32> {
+            for item in Delta::from(DeltaBatch::from_iter(items)) {
+                match item.data {
+                    Some(data) => state.insert(item.id, data),
+                    None => state.remove(item.id),
+                };
+            }
+            state
+        }
+
+        #[test]
+        fn resolves_insertion_over_deletion() {
+            let state = BTreeMap::from([("a", 1), ("b", 2)]);
+            let expected = BTreeMap::from([("a", 3), ("b", 2)]);
+            assert_eq!(
+                apply(
+                    state.clone(),
+                    vec![Item::new("a", None), Item::new("a", Some(3))]
+                ),
+                expected
+            );
+            assert_eq!(
+                apply(
+                    state,
+                    vec![Item::new("a", Some(3)), Item::new("a", None)]
+                ),
+                expected
+            );
+        }
+
+        #[test]
+        fn resolves_last_insertion_per_identifier() {
+            let state = BTreeMap::from([("a", 1)]);
+            assert_eq!(
+                apply(
+                    state,
+                    vec![
+                        Item::new("a", Some(2)),
+                        Item::new("a", None),
+                        Item::new("a", Some(3)),
+                    ]
+                ),
+                BTreeMap::from([("a", 3)])
+            );
+        }
+
+        #[test]
+        fn orders_deletions_before_insertions() {
+            let batch = DeltaBatch::from_iter([
+                Item::new("a", Some(1)),
+                Item::new("b", None),
+                Item::new("c", Some(3)),
+                Item::new("d", None),
+            ]);
+            let ids: Vec<_> =
+                Delta::from(batch).into_iter().map(|item| item.id).collect();
+            assert_eq!(ids, ["b", "d", "a", "c"]);
+        }
+    }
+}